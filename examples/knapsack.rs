@@ -44,11 +44,13 @@ impl Knapsack {
             vars.push(model.add(var().bin().obj(self.values[i] as f64)));
         }
 
-        let mut capacity_cons = cons().le(self.capacity as f64);
-        for (i, var) in vars.iter().enumerate() {
-            capacity_cons = capacity_cons.coef(var, self.sizes[i] as f64);
-        }
-        model.add(capacity_cons);
+        let weights = self.sizes.iter().map(|&s| s as i64).collect::<Vec<_>>();
+        model.add_cons_knapsack(
+            vars.iter().collect(),
+            &weights,
+            self.capacity as i64,
+            "capacity",
+        );
 
         let solved_model = model.solve();
 
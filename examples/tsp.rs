@@ -1,6 +1,9 @@
 use petgraph::prelude::*;
 use russcip::prelude::*;
-use russcip::{Conshdlr, ConshdlrResult, SCIPConshdlr, Solution, Solving, Variable};
+use russcip::{
+    Conshdlr, ConshdlrResult, Constraint, LockDirection, PropagationResult, SCIPConshdlr, Solution,
+    Solving, Variable,
+};
 use std::collections::HashMap;
 
 /// Find all "subtours" in an undirected graph. A subtour corresponds to a connected component.
@@ -46,57 +49,138 @@ fn solution_to_graph(g: &TspGraph, vars: &VarMap, sol: &Solution) -> UnGraph<(),
     )
 }
 
-/// A constraint handler that enforces the TSP subtour elimination constraint.
-struct SubtourConshdlr {
+/// The data of a subtour elimination constraint: the graph of the TSP and the variables of its edges.
+struct SubtourElimination {
     vars: VarMap,
     graph: TspGraph,
 }
 
+/// A constraint handler that enforces the TSP subtour elimination constraint.
+struct SubtourConshdlr;
+
 impl Conshdlr for SubtourConshdlr {
     fn check(
         &mut self,
         _model: Model<Solving>,
-        _conshdlr: SCIPConshdlr,
+        conshdlr: SCIPConshdlr,
+        conss: &[Constraint],
         solution: &Solution,
     ) -> bool {
-        let subtours = find_subtours(&solution_to_graph(&self.graph, &self.vars, solution));
-        subtours.len() == 1
+        conss.iter().all(|cons| {
+            let sec = conshdlr.cons_data::<SubtourElimination>(cons).unwrap();
+            let subtours = find_subtours(&solution_to_graph(&sec.graph, &sec.vars, solution));
+            subtours.len() == 1
+        })
     }
 
-    fn enforce(&mut self, mut model: Model<Solving>, _conshdlr: SCIPConshdlr) -> ConshdlrResult {
-        let edges_in_lp_sol = self
-            .vars
-            .iter()
-            .filter(|(_edge, v)| model.current_val(v) > 0.5)
-            .collect::<Vec<_>>();
-
-        let sol_graph = UnGraph::from_edges(
-            edges_in_lp_sol
+    fn enforce(
+        &mut self,
+        mut model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        conss: &[Constraint],
+    ) -> ConshdlrResult {
+        let mut result = ConshdlrResult::Feasible;
+        for sec_cons in conss {
+            let sec = conshdlr.cons_data::<SubtourElimination>(sec_cons).unwrap();
+            let edges_in_lp_sol = sec
+                .vars
                 .iter()
-                .map(|(edge, _)| self.graph.edge_endpoints(**edge).unwrap()),
-        );
+                .filter(|(_edge, v)| model.current_val(v) > 0.5)
+                .collect::<Vec<_>>();
+
+            let sol_graph = UnGraph::from_edges(
+                edges_in_lp_sol
+                    .iter()
+                    .map(|(edge, _)| sec.graph.edge_endpoints(**edge).unwrap()),
+            );
 
-        let subtours = find_subtours(&sol_graph);
+            let subtours = find_subtours(&sol_graph);
 
-        if subtours.len() == 1 {
-            return ConshdlrResult::Feasible;
+            if subtours.len() == 1 {
+                continue;
+            }
+
+            // Add a constraint to eliminate each subtour
+            for subtour in &subtours {
+                model.add(
+                    cons().le(subtour.len() as f64 - 1.0).expr(
+                        sec.graph
+                            .edge_indices()
+                            .filter(|edge| {
+                                let (source, target) = sec.graph.edge_endpoints(*edge).unwrap();
+                                subtour.contains(&source) && subtour.contains(&target)
+                            })
+                            .map(|edge| (sec.vars.get(&edge).unwrap(), 1.0)),
+                    ),
+                );
+            }
+            result = ConshdlrResult::ConsAdded;
         }
+        result
+    }
 
-        // Add a constraint to eliminate each subtour
-        for subtour in &subtours {
-            model.add(
-                cons().le(subtour.len() as f64 - 1.0).expr(
-                    self.graph
-                        .edge_indices()
-                        .filter(|edge| {
-                            let (source, target) = self.graph.edge_endpoints(*edge).unwrap();
-                            subtour.contains(&source) && subtour.contains(&target)
-                        })
-                        .map(|edge| (self.vars.get(&edge).unwrap(), 1.0)),
-                ),
-            );
+    /// Forbids all edges that would close a subtour with the edges already fixed to one.
+    fn propagate(
+        &mut self,
+        mut model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        conss: &[Constraint],
+    ) -> PropagationResult {
+        let mut result = PropagationResult::DidNotFind;
+        let node = model.focus_node();
+        for cons in conss {
+            let sec = conshdlr.cons_data::<SubtourElimination>(cons).unwrap();
+            let transformed = sec
+                .vars
+                .iter()
+                .filter_map(|(edge, var)| var.transformed().map(|var| (*edge, var)))
+                .collect::<Vec<_>>();
+
+            let mut fixed_graph: UnGraph<(), ()> = UnGraph::default();
+            for _ in sec.graph.node_indices() {
+                fixed_graph.add_node(());
+            }
+            for (edge, var) in &transformed {
+                if var.lb_local() > 0.5 {
+                    let (source, target) = sec.graph.edge_endpoints(*edge).unwrap();
+                    fixed_graph.add_edge(source, target, ());
+                }
+            }
+
+            let mut component = vec![0; sec.graph.node_count()];
+            let subtours = find_subtours(&fixed_graph);
+            for (i, subtour) in subtours.iter().enumerate() {
+                for node in subtour {
+                    component[node.index()] = i;
+                }
+            }
+
+            for (edge, var) in &transformed {
+                if var.lb_local() > 0.5 || var.ub_local() < 0.5 {
+                    continue;
+                }
+                let (source, target) = sec.graph.edge_endpoints(*edge).unwrap();
+                let c = component[source.index()];
+                if c == component[target.index()] && subtours[c].len() < sec.graph.node_count() {
+                    model.set_ub_node(&node, var, 0.0);
+                    result = PropagationResult::ReducedDom;
+                }
+            }
         }
-        ConshdlrResult::ConsAdded
+        result
+    }
+
+    fn lock(
+        &mut self,
+        _model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        cons: &Constraint,
+    ) -> Vec<(Variable, LockDirection)> {
+        let sec = conshdlr.cons_data::<SubtourElimination>(cons).unwrap();
+        sec.vars
+            .values()
+            .map(|var| (var.clone(), LockDirection::Both))
+            .collect()
     }
 }
 
@@ -130,16 +214,19 @@ fn solve_tsp(graph: TspGraph) -> Result<TspResult, Status> {
         model.add(c);
     }
 
-    let conshdlr = SubtourConshdlr {
-        vars: vars.clone(),
-        graph: graph.clone(),
-    };
-    model.include_conshdlr(
-        "SEC",
-        "Subtour Elimination Constraint",
-        -1,
-        -1,
-        Box::new(conshdlr),
+    let sec = model.add(
+        conshdlr(SubtourConshdlr)
+            .name("SEC")
+            .desc("Subtour Elimination Constraint")
+            .needs_cons(true),
+    );
+    model.add_cons_with_data(
+        &sec,
+        "subtour_elimination",
+        SubtourElimination {
+            vars: vars.clone(),
+            graph: graph.clone(),
+        },
     );
 
     let solved = model.solve();
@@ -0,0 +1,315 @@
+use crate::{Model, Solution, Solving, ffi};
+
+/// A trait for defining custom Benders' decompositions that create and solve their subproblems
+/// themselves.
+///
+/// The master problem is the model the decomposition is included in, an auxiliary variable per
+/// subproblem is added to it by SCIP to estimate the subproblem's objective. Whenever a master
+/// solution has to be checked or enforced, each subproblem is solved with
+/// [`solve_subproblem`](Benders::solve_subproblem). If the objective of a subproblem exceeds the
+/// value of its auxiliary variable, or the subproblem is infeasible, the [`BendersCut`]s of the
+/// decomposition are called to cut off the master solution.
+///
+/// Subproblems that are given as separate models can be added with
+/// [`Model::add_benders_subproblems`] instead, which lets SCIP solve them and generate the
+/// classical optimality and feasibility cuts.
+pub trait Benders {
+    /// Creates the subproblem with the given number. This is called once per subproblem when
+    /// the solving process starts, defaults to doing nothing.
+    ///
+    /// # Arguments
+    /// * `benders` - the internal Benders' decomposition object.
+    /// * `probnumber` - the number of the subproblem to create.
+    fn create_subproblem(&mut self, _benders: SCIPBenders, _probnumber: usize) {}
+
+    /// Solves a subproblem for a solution of the master problem. The subproblems are treated
+    /// as convex.
+    ///
+    /// # Arguments
+    /// * `model` - the master model in `Solving` stage.
+    /// * `benders` - the internal Benders' decomposition object.
+    /// * `sol` - the master solution to solve the subproblem for.
+    /// * `probnumber` - the number of the subproblem to solve.
+    /// * `objective` - the objective value of the subproblem should be stored here.
+    ///
+    /// # Returns
+    /// * `BendersSubproblemResult` indicating the outcome of solving the subproblem.
+    fn solve_subproblem(
+        &mut self,
+        model: Model<Solving>,
+        benders: SCIPBenders,
+        sol: &Solution,
+        probnumber: usize,
+        objective: &mut f64,
+    ) -> BendersSubproblemResult;
+}
+
+/// A trait for defining custom Benders' decomposition cuts.
+///
+/// Benders' cuts are called after the subproblems were solved for a master solution that is
+/// not optimal or not feasible for them. They add constraints to the master problem, e.g.
+/// with [`ProblemOrSolving::add_cons`](crate::ProblemOrSolving::add_cons), that cut off the
+/// master solution.
+pub trait BendersCut {
+    /// Executes the Benders' cut for a subproblem.
+    ///
+    /// # Arguments
+    /// * `model` - the master model in `Solving` stage.
+    /// * `benders` - the Benders' decomposition the cut belongs to.
+    /// * `sol` - the master solution to cut off.
+    /// * `probnumber` - the number of the subproblem the cut is generated for.
+    /// * `enfo_type` - the kind of master solution that is enforced.
+    ///
+    /// # Returns
+    /// * `BendersCutResult` indicating whether a cut was added.
+    fn exec(
+        &mut self,
+        model: Model<Solving>,
+        benders: SCIPBenders,
+        sol: &Solution,
+        probnumber: usize,
+        enfo_type: BendersEnfoType,
+    ) -> BendersCutResult;
+}
+
+/// The result of solving a Benders' subproblem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BendersSubproblemResult {
+    /// The subproblem is feasible and its objective value was stored.
+    Feasible,
+    /// The subproblem is infeasible.
+    Infeasible,
+    /// The subproblem is unbounded.
+    Unbounded,
+    /// The subproblem was not solved.
+    DidNotRun,
+}
+
+impl From<BendersSubproblemResult> for ffi::SCIP_Result {
+    fn from(result: BendersSubproblemResult) -> Self {
+        match result {
+            BendersSubproblemResult::Feasible => ffi::SCIP_Result_SCIP_FEASIBLE,
+            BendersSubproblemResult::Infeasible => ffi::SCIP_Result_SCIP_INFEASIBLE,
+            BendersSubproblemResult::Unbounded => ffi::SCIP_Result_SCIP_UNBOUNDED,
+            BendersSubproblemResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+        }
+    }
+}
+
+/// The result of a Benders' cut call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BendersCutResult {
+    /// A constraint was added to the master problem.
+    ConsAdded,
+    /// A cutting plane was added to the LP of the master problem.
+    Separated,
+    /// The master solution is feasible for the subproblem, no cut is necessary.
+    Feasible,
+    /// No cut could be found.
+    DidNotFind,
+    /// The cut was skipped.
+    DidNotRun,
+}
+
+impl From<BendersCutResult> for ffi::SCIP_Result {
+    fn from(result: BendersCutResult) -> Self {
+        match result {
+            BendersCutResult::ConsAdded => ffi::SCIP_Result_SCIP_CONSADDED,
+            BendersCutResult::Separated => ffi::SCIP_Result_SCIP_SEPARATED,
+            BendersCutResult::Feasible => ffi::SCIP_Result_SCIP_FEASIBLE,
+            BendersCutResult::DidNotFind => ffi::SCIP_Result_SCIP_DIDNOTFIND,
+            BendersCutResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+        }
+    }
+}
+
+/// The kind of master solution a Benders' decomposition is called for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BendersEnfoType {
+    /// An LP solution is enforced.
+    Lp,
+    /// A relaxation solution is enforced.
+    Relax,
+    /// A pseudo solution is enforced.
+    Pseudo,
+    /// A solution is checked for feasibility.
+    Check,
+}
+
+impl From<ffi::SCIP_BENDERSENFOTYPE> for BendersEnfoType {
+    fn from(enfo_type: ffi::SCIP_BENDERSENFOTYPE) -> Self {
+        match enfo_type {
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_LP => BendersEnfoType::Lp,
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_RELAX => BendersEnfoType::Relax,
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_PSEUDO => BendersEnfoType::Pseudo,
+            ffi::SCIP_BendersEnfoType_SCIP_BENDERSENFOTYPE_CHECK => BendersEnfoType::Check,
+            _ => panic!("Unknown Benders enforcement type {enfo_type}"),
+        }
+    }
+}
+
+/// A wrapper struct for the internal ffi::SCIP_BENDERS
+#[derive(Debug)]
+pub struct SCIPBenders {
+    pub(crate) raw: *mut ffi::SCIP_BENDERS,
+}
+
+impl SCIPBenders {
+    /// Returns the internal raw pointer of the Benders' decomposition.
+    pub fn inner(&self) -> *mut ffi::SCIP_BENDERS {
+        self.raw
+    }
+
+    /// Returns the name of the Benders' decomposition.
+    pub fn name(&self) -> String {
+        unsafe {
+            let name = ffi::SCIPbendersGetName(self.raw);
+            std::ffi::CStr::from_ptr(name).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the description of the Benders' decomposition.
+    pub fn desc(&self) -> String {
+        unsafe {
+            let desc = ffi::SCIPbendersGetDesc(self.raw);
+            std::ffi::CStr::from_ptr(desc).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the priority of the Benders' decomposition.
+    pub fn priority(&self) -> i32 {
+        unsafe { ffi::SCIPbendersGetPriority(self.raw) }
+    }
+
+    /// Returns the number of subproblems of the Benders' decomposition.
+    pub fn n_subproblems(&self) -> usize {
+        unsafe { ffi::SCIPbendersGetNSubproblems(self.raw) as usize }
+    }
+
+    /// Returns the objective value of a subproblem from its last solve.
+    pub fn subproblem_obj_val(&self, probnumber: usize) -> f64 {
+        assert!(probnumber < self.n_subproblems());
+        unsafe { ffi::SCIPbendersGetSubproblemObjval(self.raw, probnumber as i32) }
+    }
+
+    /// Returns the number of times the Benders' decomposition was called.
+    pub fn n_calls(&self) -> usize {
+        unsafe { ffi::SCIPbendersGetNCalls(self.raw) as usize }
+    }
+
+    /// Returns the number of cuts found by the Benders' decomposition.
+    pub fn n_cuts_found(&self) -> usize {
+        unsafe { ffi::SCIPbendersGetNCutsFound(self.raw) as usize }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ProblemCreated, Variable, minimal_model};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// The master problem `min x + recourse` with `x` in `[0, 10]`.
+    fn master() -> (Model<ProblemCreated>, Variable) {
+        let mut model = Model::default().hide_output().minimize();
+        let x = model.add(var().name("x").cont(0.0..=10.0).obj(1.0));
+        (model, x)
+    }
+
+    #[test]
+    fn default_benders_with_subproblem_model() {
+        let (mut model, _) = master();
+
+        // the recourse `min 2y` with `y >= 5 - x`, sharing `x` with the master by its name
+        let mut sub = minimal_model().minimize();
+        let x = sub.add(var().name("x").cont(0.0..=10.0));
+        let y = sub.add(var().name("y").cont(0.0..=f64::INFINITY).obj(2.0));
+        sub.add(cons().coef(&x, 1.0).coef(&y, 1.0).ge(5.0));
+
+        let benders = model.add_benders_subproblems(vec![sub]);
+        assert_eq!(benders.n_subproblems(), 1);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 5.0).abs() < 1e-6);
+    }
+
+    /// Solves the recourse `min 2y` with `y >= 5 - x` in closed form.
+    struct ClosedFormRecourse {
+        x: Variable,
+        solves: Rc<Cell<usize>>,
+    }
+
+    impl Benders for ClosedFormRecourse {
+        fn solve_subproblem(
+            &mut self,
+            _model: Model<Solving>,
+            benders: SCIPBenders,
+            sol: &Solution,
+            probnumber: usize,
+            objective: &mut f64,
+        ) -> BendersSubproblemResult {
+            assert_eq!(benders.name(), "recourse");
+            assert_eq!(probnumber, 0);
+            self.solves.set(self.solves.get() + 1);
+            *objective = 2.0 * (5.0 - sol.val(&self.x)).max(0.0);
+            BendersSubproblemResult::Feasible
+        }
+    }
+
+    /// Adds the optimality cut `theta >= 10 - 2x`.
+    struct RecourseCut {
+        x: Variable,
+    }
+
+    impl BendersCut for RecourseCut {
+        fn exec(
+            &mut self,
+            mut model: Model<Solving>,
+            benders: SCIPBenders,
+            sol: &Solution,
+            probnumber: usize,
+            _enfo_type: BendersEnfoType,
+        ) -> BendersCutResult {
+            let theta = model.benders_auxiliary_var(&benders, probnumber);
+            if sol.val(&theta) >= benders.subproblem_obj_val(probnumber) - 1e-6 {
+                return BendersCutResult::Feasible;
+            }
+            // the cut is added to the transformed problem, so it needs the transformed `x`
+            let x = Variable {
+                raw: model.scip.transformed_var_ptr(&self.x).unwrap(),
+                scip: model.scip.clone(),
+            };
+            model.add_cons(
+                vec![&theta, &x],
+                &[1.0, 2.0],
+                10.0,
+                f64::INFINITY,
+                "recourse_cut",
+            );
+            BendersCutResult::ConsAdded
+        }
+    }
+
+    #[test]
+    fn custom_benders_with_cut() {
+        let solves = Rc::new(Cell::new(0));
+
+        let (mut model, x) = master();
+        let benders = model.add(
+            benders(ClosedFormRecourse {
+                x: x.clone(),
+                solves: solves.clone(),
+            })
+            .name("recourse"),
+        );
+        model.add(benderscut(&benders, RecourseCut { x }).name("recourse_cut"));
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 5.0).abs() < 1e-6);
+        assert!(solves.get() > 0);
+    }
+}
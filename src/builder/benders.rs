@@ -0,0 +1,231 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{Benders, BendersCut, Model, ProblemCreated, SCIPBenders};
+
+/// A builder for easily creating Benders' decompositions. It can be created using the `benders` function.
+pub struct BendersBuilder<B: Benders> {
+    name: Option<String>,
+    desc: Option<String>,
+    priority: i32,
+    n_subproblems: usize,
+    cut_lp: bool,
+    cut_pseudo: bool,
+    cut_relax: bool,
+    share_aux_vars: bool,
+    benders: B,
+}
+
+impl<B: Benders> BendersBuilder<B> {
+    /// Creates a new `BendersBuilder` with the given Benders' decomposition.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `priority`: 0
+    /// - `n_subproblems`: 1
+    /// - `cut_lp`: true
+    /// - `cut_pseudo`: true
+    /// - `cut_relax`: false
+    /// - `share_aux_vars`: false
+    pub fn new(benders: B) -> Self {
+        BendersBuilder {
+            name: None,
+            desc: None,
+            priority: 0,
+            n_subproblems: 1,
+            cut_lp: true,
+            cut_pseudo: true,
+            cut_relax: false,
+            share_aux_vars: false,
+            benders,
+        }
+    }
+
+    /// Sets the name of the Benders' decomposition.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the Benders' decomposition.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the priority of the Benders' decomposition.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the number of subproblems of the Benders' decomposition.
+    pub fn n_subproblems(mut self, n_subproblems: usize) -> Self {
+        self.n_subproblems = n_subproblems;
+        self
+    }
+
+    /// Sets whether cuts are generated for LP solutions.
+    pub fn cut_lp(mut self, cut_lp: bool) -> Self {
+        self.cut_lp = cut_lp;
+        self
+    }
+
+    /// Sets whether cuts are generated for pseudo solutions.
+    pub fn cut_pseudo(mut self, cut_pseudo: bool) -> Self {
+        self.cut_pseudo = cut_pseudo;
+        self
+    }
+
+    /// Sets whether cuts are generated for relaxation solutions.
+    pub fn cut_relax(mut self, cut_relax: bool) -> Self {
+        self.cut_relax = cut_relax;
+        self
+    }
+
+    /// Sets whether the auxiliary variables of the highest priority Benders' decomposition are shared.
+    pub fn share_aux_vars(mut self, share_aux_vars: bool) -> Self {
+        self.share_aux_vars = share_aux_vars;
+        self
+    }
+}
+
+/// Creates a new default `BendersBuilder` from a Benders' decomposition.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{Benders, BendersSubproblemResult, SCIPBenders, Solution};
+///
+/// struct MyBenders;
+/// impl Benders for MyBenders {
+///     fn solve_subproblem(&mut self, model: Model<Solving>, benders: SCIPBenders, sol: &Solution, probnumber: usize, objective: &mut f64) -> BendersSubproblemResult {
+///         BendersSubproblemResult::DidNotRun
+///     }
+/// }
+///
+/// let decomposition = benders(MyBenders).name("My Benders").n_subproblems(2);
+///
+/// let mut model = Model::default();
+/// let benders = model.add(decomposition);
+/// ```
+pub fn benders<B: Benders>(benders: B) -> BendersBuilder<B> {
+    BendersBuilder::new(benders)
+}
+
+impl<B: Benders + 'static> CanBeAddedToModel<ProblemCreated> for BendersBuilder<B> {
+    type Return = SCIPBenders;
+
+    fn add(self, model: &mut Model<ProblemCreated>) -> SCIPBenders {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        model.include_benders(
+            &name,
+            &desc,
+            self.priority,
+            self.n_subproblems,
+            self.cut_lp,
+            self.cut_pseudo,
+            self.cut_relax,
+            self.share_aux_vars,
+            Box::new(self.benders),
+        )
+    }
+}
+
+impl<B: Benders> From<B> for BendersBuilder<B> {
+    fn from(benders: B) -> Self {
+        BendersBuilder::new(benders)
+    }
+}
+
+/// A builder for easily creating Benders' cuts. It can be created using the `benderscut` function.
+pub struct BendersCutBuilder<'a, C: BendersCut> {
+    benders: &'a SCIPBenders,
+    name: Option<String>,
+    desc: Option<String>,
+    priority: i32,
+    is_lp_cut: bool,
+    cut: C,
+}
+
+impl<'a, C: BendersCut> BendersCutBuilder<'a, C> {
+    /// Creates a new `BendersCutBuilder` with the given Benders' cut for a Benders' decomposition.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `priority`: 0
+    /// - `is_lp_cut`: true
+    pub fn new(benders: &'a SCIPBenders, cut: C) -> Self {
+        BendersCutBuilder {
+            benders,
+            name: None,
+            desc: None,
+            priority: 0,
+            is_lp_cut: true,
+            cut,
+        }
+    }
+
+    /// Sets the name of the Benders' cut.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the Benders' cut.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the priority of the Benders' cut, cuts with a higher priority are called first.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets whether the cut is generated from the LP solutions of the subproblems.
+    pub fn is_lp_cut(mut self, is_lp_cut: bool) -> Self {
+        self.is_lp_cut = is_lp_cut;
+        self
+    }
+}
+
+/// Creates a new default `BendersCutBuilder` from a Benders' cut for a Benders' decomposition.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{BendersCut, BendersCutResult, BendersEnfoType, SCIPBenders, Solution};
+///
+/// struct MyCut;
+/// impl BendersCut for MyCut {
+///     fn exec(&mut self, model: Model<Solving>, benders: SCIPBenders, sol: &Solution, probnumber: usize, enfo_type: BendersEnfoType) -> BendersCutResult {
+///         BendersCutResult::DidNotRun
+///     }
+/// }
+///
+/// let mut model = Model::default();
+/// let sub = Model::default();
+/// let benders = model.add_benders_subproblems(vec![sub]);
+/// model.add(benderscut(&benders, MyCut).name("My Cut"));
+/// ```
+pub fn benderscut<C: BendersCut>(benders: &SCIPBenders, cut: C) -> BendersCutBuilder<'_, C> {
+    BendersCutBuilder::new(benders, cut)
+}
+
+impl<C: BendersCut + 'static> CanBeAddedToModel<ProblemCreated> for BendersCutBuilder<'_, C> {
+    type Return = ();
+
+    fn add(self, model: &mut Model<ProblemCreated>) {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        model.include_benderscut(
+            self.benders,
+            &name,
+            &desc,
+            self.priority,
+            self.is_lp_cut,
+            Box::new(self.cut),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{ConflictHdlr, Model, ProblemCreated};
+
+/// A builder for easily creating conflict handlers. It can be created using the `conflicthdlr` function.
+pub struct ConflictHdlrBuilder<C: ConflictHdlr> {
+    name: Option<String>,
+    desc: Option<String>,
+    priority: i32,
+    conflicthdlr: C,
+}
+
+impl<C: ConflictHdlr> ConflictHdlrBuilder<C> {
+    /// Creates a new `ConflictHdlrBuilder` with the given conflict handler.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `priority`: 0
+    pub fn new(conflicthdlr: C) -> Self {
+        ConflictHdlrBuilder {
+            name: None,
+            desc: None,
+            priority: 0,
+            conflicthdlr,
+        }
+    }
+
+    /// Sets the name of the conflict handler.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the conflict handler.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the priority of the conflict handler.
+    /// Conflict handlers with a higher priority are called first.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Creates a new default `ConflictHdlrBuilder` from a conflict handler.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{Conflict, ConflictHdlr, ConflictResult, SCIPConflictHdlr};
+///
+/// struct MyConflictHdlr;
+/// impl ConflictHdlr for MyConflictHdlr {
+///     fn exec(&mut self, model: Model<Solving>, conflicthdlr: SCIPConflictHdlr, conflict: &Conflict) -> ConflictResult {
+///         ConflictResult::DidNotRun
+///     }
+/// }
+///
+/// let handler = conflicthdlr(MyConflictHdlr).name("My Conflict Handler");
+///
+/// let mut model = Model::default();
+/// model.add(handler);
+/// ```
+pub fn conflicthdlr<C: ConflictHdlr>(conflicthdlr: C) -> ConflictHdlrBuilder<C> {
+    ConflictHdlrBuilder::new(conflicthdlr)
+}
+
+impl<C: ConflictHdlr + 'static> CanBeAddedToModel<ProblemCreated> for ConflictHdlrBuilder<C> {
+    type Return = ();
+
+    fn add(self, model: &mut Model<ProblemCreated>) {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        model.include_conflicthdlr(&name, &desc, self.priority, Box::new(self.conflicthdlr));
+    }
+}
+
+impl<C: ConflictHdlr> From<C> for ConflictHdlrBuilder<C> {
+    fn from(conflicthdlr: C) -> Self {
+        ConflictHdlrBuilder::new(conflicthdlr)
+    }
+}
@@ -64,6 +64,13 @@ impl<'a> ConsBuilder<'a> {
         self
     }
 
+    /// Creates a constraint of the form `lhs <= expr <= rhs`.
+    pub fn between(mut self, lhs: f64, rhs: f64) -> Self {
+        self.lhs = lhs;
+        self.rhs = rhs;
+        self
+    }
+
     /// Sets the name of the constraint.
     pub fn name(mut self, name: &'a str) -> Self {
         self.name = Some(name);
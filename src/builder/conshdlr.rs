@@ -0,0 +1,209 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{Conshdlr, Model, PresolTiming, ProblemCreated, PropTiming, SCIPConshdlr};
+
+/// A builder for easily creating constraint handlers. It can be created using the `conshdlr` function.
+pub struct ConshdlrBuilder<C: Conshdlr> {
+    name: Option<String>,
+    desc: Option<String>,
+    enfopriority: i32,
+    checkpriority: i32,
+    sepapriority: i32,
+    sepafreq: i32,
+    propfreq: i32,
+    eagerfreq: i32,
+    maxprerounds: i32,
+    delaysepa: bool,
+    delayprop: bool,
+    prop_timing: PropTiming,
+    presol_timing: PresolTiming,
+    needscons: bool,
+    conshdlr: C,
+}
+
+impl<C: Conshdlr> ConshdlrBuilder<C> {
+    /// Creates a new `ConshdlrBuilder` with the given constraint handler.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `enfopriority`: -1 (only integral solutions are enforced)
+    /// - `checkpriority`: -1 (only integral solutions are checked)
+    /// - `sepapriority`: 0
+    /// - `sepafreq`: 1 (separation at every node)
+    /// - `propfreq`: 1 (propagation at every node)
+    /// - `eagerfreq`: 100
+    /// - `maxprerounds`: -1 (no limit on presolving rounds)
+    /// - `delaysepa`: false
+    /// - `delayprop`: false
+    /// - `prop_timing`: `PropTiming::BEFORE_LP`
+    /// - `presol_timing`: `PresolTiming::MEDIUM`
+    /// - `needscons`: false
+    pub fn new(conshdlr: C) -> Self {
+        ConshdlrBuilder {
+            name: None,
+            desc: None,
+            enfopriority: -1,
+            checkpriority: -1,
+            sepapriority: 0,
+            sepafreq: 1,
+            propfreq: 1,
+            eagerfreq: 100,
+            maxprerounds: -1,
+            delaysepa: false,
+            delayprop: false,
+            prop_timing: PropTiming::BEFORE_LP,
+            presol_timing: PresolTiming::MEDIUM,
+            needscons: false,
+            conshdlr,
+        }
+    }
+
+    /// Sets the name of the constraint handler.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the constraint handler.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the enforcement priority of the constraint handler.
+    /// The integrality constraint handler has an enforcement priority of 0, handlers with a negative
+    /// priority therefore only have to enforce integral solutions.
+    pub fn enfopriority(mut self, priority: i32) -> Self {
+        self.enfopriority = priority;
+        self
+    }
+
+    /// Sets the checking priority of the constraint handler.
+    /// The integrality constraint handler has a checking priority of 0, handlers with a negative
+    /// priority therefore only have to check integral solutions.
+    pub fn checkpriority(mut self, priority: i32) -> Self {
+        self.checkpriority = priority;
+        self
+    }
+
+    /// Sets the separation priority of the constraint handler.
+    pub fn sepapriority(mut self, priority: i32) -> Self {
+        self.sepapriority = priority;
+        self
+    }
+
+    /// Sets the separation frequency. 1 means at every node, 2 means at every other node and so on, -1 turns off separation.
+    pub fn sepafreq(mut self, freq: i32) -> Self {
+        self.sepafreq = freq;
+        self
+    }
+
+    /// Sets the propagation frequency. 1 means at every node, 2 means at every other node and so on, -1 turns off propagation.
+    pub fn propfreq(mut self, freq: i32) -> Self {
+        self.propfreq = freq;
+        self
+    }
+
+    /// Sets the frequency for using all instead of only the useful constraints in separation,
+    /// propagation and enforcement. -1 means never, 0 means only in the first evaluation.
+    pub fn eagerfreq(mut self, freq: i32) -> Self {
+        self.eagerfreq = freq;
+        self
+    }
+
+    /// Sets the maximal number of presolving rounds the handler participates in, -1 means no limit.
+    pub fn maxprerounds(mut self, maxprerounds: i32) -> Self {
+        self.maxprerounds = maxprerounds;
+        self
+    }
+
+    /// Sets whether separation should be delayed if other separators found cuts.
+    pub fn delaysepa(mut self, delay: bool) -> Self {
+        self.delaysepa = delay;
+        self
+    }
+
+    /// Sets whether propagation should be delayed if other propagators found reductions.
+    pub fn delayprop(mut self, delay: bool) -> Self {
+        self.delayprop = delay;
+        self
+    }
+
+    /// Sets when in the node processing the propagation callback is called.
+    pub fn prop_timing(mut self, timing: PropTiming) -> Self {
+        self.prop_timing = timing;
+        self
+    }
+
+    /// Sets in which presolving rounds the presolving callback is called.
+    pub fn presol_timing(mut self, timing: PresolTiming) -> Self {
+        self.presol_timing = timing;
+        self
+    }
+
+    /// Sets whether the handler should only be called if it has constraints.
+    pub fn needs_cons(mut self, needscons: bool) -> Self {
+        self.needscons = needscons;
+        self
+    }
+}
+
+/// Creates a new default `ConshdlrBuilder` from a constraint handler.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{Conshdlr, ConshdlrResult, Constraint, SCIPConshdlr, Solution, Solving};
+///
+/// struct MyConshdlr;
+/// impl Conshdlr for MyConshdlr {
+///     fn check(&mut self, model: Model<Solving>, conshdlr: SCIPConshdlr, conss: &[Constraint], solution: &Solution) -> bool {
+///         true
+///     }
+///     fn enforce(&mut self, model: Model<Solving>, conshdlr: SCIPConshdlr, conss: &[Constraint]) -> ConshdlrResult {
+///         ConshdlrResult::Feasible
+///     }
+/// }
+///
+/// let mut model = Model::default();
+/// let hdlr = model.add(conshdlr(MyConshdlr).name("My Conshdlr").needs_cons(true));
+/// model.add_cons_with_data(&hdlr, "c1", vec![1, 2, 3]);
+/// ```
+pub fn conshdlr<C: Conshdlr>(conshdlr: C) -> ConshdlrBuilder<C> {
+    ConshdlrBuilder::new(conshdlr)
+}
+
+impl<C: Conshdlr + 'static> CanBeAddedToModel<ProblemCreated> for ConshdlrBuilder<C> {
+    type Return = SCIPConshdlr;
+
+    fn add(self, model: &mut Model<ProblemCreated>) -> SCIPConshdlr {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        let raw = model
+            .scip
+            .include_conshdlr(
+                &name,
+                &desc,
+                self.enfopriority,
+                self.checkpriority,
+                self.sepapriority,
+                self.sepafreq,
+                self.propfreq,
+                self.eagerfreq,
+                self.maxprerounds,
+                self.delaysepa,
+                self.delayprop,
+                self.prop_timing,
+                self.presol_timing,
+                self.needscons,
+                Box::new(self.conshdlr),
+            )
+            .expect("Failed to include constraint handler at state ProblemCreated");
+        SCIPConshdlr { raw }
+    }
+}
+
+impl<C: Conshdlr> From<C> for ConshdlrBuilder<C> {
+    fn from(conshdlr: C) -> Self {
+        ConshdlrBuilder::new(conshdlr)
+    }
+}
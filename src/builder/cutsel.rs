@@ -0,0 +1,84 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{CutSelector, Model, ProblemCreated};
+
+/// A builder for easily creating cut selectors. It can be created using the `cutsel` function.
+pub struct CutSelBuilder<C: CutSelector> {
+    name: Option<String>,
+    desc: Option<String>,
+    priority: i32,
+    cutsel: C,
+}
+
+impl<C: CutSelector> CutSelBuilder<C> {
+    /// Creates a new `CutSelBuilder` with the given cut selector.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `priority`: 10000 (higher than the default `hybrid` cut selector, so that it is used)
+    pub fn new(cutsel: C) -> Self {
+        CutSelBuilder {
+            name: None,
+            desc: None,
+            priority: 10000,
+            cutsel,
+        }
+    }
+
+    /// Sets the name of the cut selector.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the cut selector.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the priority of the cut selector.
+    /// Only the cut selector with the highest priority is used.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Creates a new default `CutSelBuilder` from a cut selector.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{CutSelector, Row};
+///
+/// struct FirstCuts;
+/// impl CutSelector for FirstCuts {
+///     fn select(&mut self, model: Model<Solving>, cuts: &[Row], forced: &[Row], root: bool, max_cuts: usize) -> Vec<usize> {
+///         (0..cuts.len().min(max_cuts)).collect()
+///     }
+/// }
+///
+/// let selector = cutsel(FirstCuts).name("First Cuts");
+///
+/// let mut model = Model::default();
+/// model.add(selector);
+/// ```
+pub fn cutsel<C: CutSelector>(cutsel: C) -> CutSelBuilder<C> {
+    CutSelBuilder::new(cutsel)
+}
+
+impl<C: CutSelector + 'static> CanBeAddedToModel<ProblemCreated> for CutSelBuilder<C> {
+    type Return = ();
+
+    fn add(self, model: &mut Model<ProblemCreated>) {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        model.include_cutsel(&name, &desc, self.priority, Box::new(self.cutsel));
+    }
+}
+
+impl<C: CutSelector> From<C> for CutSelBuilder<C> {
+    fn from(cutsel: C) -> Self {
+        CutSelBuilder::new(cutsel)
+    }
+}
@@ -0,0 +1,197 @@
+use crate::builder::CanBeAddedToModel;
+use crate::builder::cons::{ConsBuilder, cons};
+use crate::{
+    Constraint, Model, ModelStageProblemOrSolving, ModelStageWithProblem, ModelWithProblem,
+    Variable,
+};
+
+/// A builder for creating indicator constraints `bin_var = active_on -> lhs <= expr <= rhs`.
+///
+/// SCIP's indicator constraints are one-sided, so a constraint with two finite sides (e.g. an
+/// equation) is added as two indicator constraints. Each of them has a linear constraint with a
+/// slack variable, which SCIP creates behind the scenes.
+#[derive(Debug)]
+pub struct IndicatorBuilder<'a> {
+    /// Binary variable activating the constraint
+    pub(crate) bin_var: &'a Variable,
+    /// Value of the binary variable on which the constraint is active
+    pub(crate) active_on: bool,
+    /// Linear constraint that holds when the indicator is active
+    pub(crate) cons: ConsBuilder<'a>,
+}
+
+/// Creates a new `IndicatorBuilder` for the given binary variable, active when it is one.
+pub fn indicator(bin_var: &Variable) -> IndicatorBuilder<'_> {
+    IndicatorBuilder {
+        bin_var,
+        active_on: true,
+        cons: cons(),
+    }
+}
+
+impl<'a> IndicatorBuilder<'a> {
+    /// Sets the value of the binary variable on which the constraint is active. When it is
+    /// `false`, the negated binary variable activates the constraint.
+    pub fn active_on(mut self, value: bool) -> Self {
+        self.active_on = value;
+        self
+    }
+
+    /// Sets the linear constraint that holds when the indicator is active, e.g. from a
+    /// [`LinExpr`](crate::LinExpr).
+    pub fn cons(mut self, cons: ConsBuilder<'a>) -> Self {
+        self.cons = cons;
+        self
+    }
+
+    /// Creates a constraint of the form `expr <= val`.
+    pub fn le(mut self, val: f64) -> Self {
+        self.cons = self.cons.le(val);
+        self
+    }
+
+    /// Creates a constraint of the form `val <= expr`.
+    pub fn ge(mut self, val: f64) -> Self {
+        self.cons = self.cons.ge(val);
+        self
+    }
+
+    /// Creates a constraint of the form `expr = val`.
+    pub fn eq(mut self, val: f64) -> Self {
+        self.cons = self.cons.eq(val);
+        self
+    }
+
+    /// Creates a constraint of the form `lhs <= expr <= rhs`.
+    pub fn between(mut self, lhs: f64, rhs: f64) -> Self {
+        self.cons = self.cons.between(lhs, rhs);
+        self
+    }
+
+    /// Sets the name of the constraint.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.cons = self.cons.name(name);
+        self
+    }
+
+    /// Adds a coefficient to the constraint.
+    pub fn coef(mut self, var: &'a Variable, coef: f64) -> Self {
+        self.cons = self.cons.coef(var, coef);
+        self
+    }
+
+    /// Adds multiple coefficients to the constraint.
+    pub fn expr<I>(mut self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a Variable, f64)>,
+    {
+        self.cons = self.cons.expr(iter);
+        self
+    }
+}
+
+impl<S> CanBeAddedToModel<S> for IndicatorBuilder<'_>
+where
+    S: ModelStageProblemOrSolving + ModelStageWithProblem,
+{
+    /// One indicator constraint per finite side of the linear constraint.
+    type Return = Vec<Constraint>;
+    fn add(self, model: &mut Model<S>) -> Self::Return {
+        let ConsBuilder {
+            lhs,
+            rhs,
+            name,
+            coefs,
+            ..
+        } = self.cons;
+        assert!(
+            lhs > f64::NEG_INFINITY || rhs < f64::INFINITY,
+            "An indicator constraint needs a finite side"
+        );
+        let name = name.map(|s| s.to_string()).unwrap_or_else(|| {
+            let n_cons = model.n_conss();
+            format!("cons{n_cons}")
+        });
+        let (vars, coefs): (Vec<_>, Vec<_>) = coefs.into_iter().unzip();
+
+        // (side, whether it is a less-than inequality, suffix of the name)
+        let mut sides = Vec::new();
+        if lhs > f64::NEG_INFINITY {
+            sides.push((lhs, false, "_lhs"));
+        }
+        if rhs < f64::INFINITY {
+            sides.push((rhs, true, "_rhs"));
+        }
+        let two_sided = sides.len() == 2;
+
+        sides
+            .into_iter()
+            .map(|(side, less_than, suffix)| {
+                let name = if two_sided {
+                    format!("{name}{suffix}")
+                } else {
+                    name.clone()
+                };
+                let raw = model
+                    .scip
+                    .create_cons_indicator_generic(
+                        self.bin_var,
+                        &vars,
+                        &coefs,
+                        side,
+                        self.active_on,
+                        less_than,
+                        &name,
+                    )
+                    .expect("Failed to create indicator constraint");
+                Constraint {
+                    raw,
+                    scip: model.scip.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimal_model;
+    use crate::prelude::*;
+
+    #[test]
+    fn indicator_with_both_polarities() {
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add(var().name("x").cont(0.0..=10.0).obj(2.0));
+        let y = model.add(var().name("y").cont(0.0..=10.0).obj(1.0));
+        let z = model.add(var().name("z").bin().obj(-7.0));
+
+        // z = 0 -> x <= 0
+        let off = model.add(indicator(&z).active_on(false).coef(&x, 1.0).le(0.0));
+        // z = 1 -> x >= 3
+        let min_lot = model.add(indicator(&z).coef(&x, 1.0).ge(3.0).name("min_lot"));
+        // z = 1 -> x - y = 2
+        let link = model.add(indicator(&z).cons((&x - &y).eq(2.0)).name("link"));
+
+        assert_eq!(off.len(), 1);
+        assert_eq!(off[0].indicator_active_on(), Some(false));
+        assert_eq!(off[0].indicator_binary_var(), Some(z.clone()));
+        assert_eq!(min_lot.len(), 1);
+        assert_eq!(min_lot[0].name(), "min_lot");
+        assert_eq!(min_lot[0].indicator_active_on(), Some(true));
+        assert_eq!(link.len(), 2);
+        assert_eq!(link[0].name(), "link_lhs");
+        assert_eq!(link[1].name(), "link_rhs");
+
+        let slack = min_lot[0].indicator_slack_var().unwrap();
+        let linear = min_lot[0].indicator_linear_cons().unwrap();
+        assert!(model.vars().contains(&slack));
+        assert!(linear.name().contains("min_lot"));
+        assert_eq!(linear.indicator_slack_var(), None);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        // z = 1, x = 10 and y = 8
+        assert!((solved.obj_val() - 21.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{
+    Constraint, Model, ModelStageProblemOrSolving, ModelStageWithProblem, ModelWithProblem,
+    ProblemOrSolving, Variable,
+};
+
+/// The kind of a logical constraint created by a `LogicConsBuilder`.
+#[derive(Debug)]
+enum LogicKind<'a> {
+    And(&'a Variable),
+    Or(&'a Variable),
+    Xor(bool),
+    Logicor,
+}
+
+/// A builder for creating AND, OR, XOR and logicor constraints over binary variables.
+#[derive(Debug)]
+pub struct LogicConsBuilder<'a> {
+    /// Kind of the constraint
+    kind: LogicKind<'a>,
+    /// (Optional) name of constraint
+    pub(crate) name: Option<&'a str>,
+    /// Operand variables of constraint
+    pub(crate) vars: Vec<&'a Variable>,
+}
+
+/// Creates a builder for the AND constraint `resultant = vars[0] && vars[1] && ...`.
+pub fn cons_and(resultant: &Variable) -> LogicConsBuilder<'_> {
+    LogicConsBuilder::new(LogicKind::And(resultant))
+}
+
+/// Creates a builder for the OR constraint `resultant = vars[0] || vars[1] || ...`.
+pub fn cons_or(resultant: &Variable) -> LogicConsBuilder<'_> {
+    LogicConsBuilder::new(LogicKind::Or(resultant))
+}
+
+/// Creates a builder for the XOR constraint `vars[0] ^ vars[1] ^ ... = rhs`.
+pub fn cons_xor(rhs: bool) -> LogicConsBuilder<'static> {
+    LogicConsBuilder::new(LogicKind::Xor(rhs))
+}
+
+/// Creates a builder for the logicor constraint `vars[0] || vars[1] || ...`.
+pub fn cons_logicor() -> LogicConsBuilder<'static> {
+    LogicConsBuilder::new(LogicKind::Logicor)
+}
+
+impl<'a> LogicConsBuilder<'a> {
+    fn new(kind: LogicKind<'a>) -> Self {
+        LogicConsBuilder {
+            kind,
+            name: None,
+            vars: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the constraint.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Adds an operand variable to the constraint.
+    pub fn var(mut self, var: &'a Variable) -> Self {
+        self.vars.push(var);
+        self
+    }
+
+    /// Adds multiple operand variables to the constraint.
+    pub fn vars<I>(mut self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Variable>,
+    {
+        self.vars.extend(iter);
+        self
+    }
+}
+
+impl<S> CanBeAddedToModel<S> for LogicConsBuilder<'_>
+where
+    S: ModelStageProblemOrSolving + ModelStageWithProblem,
+{
+    type Return = Constraint;
+    fn add(self, model: &mut Model<S>) -> Self::Return {
+        let name = self.name.map(|s| s.to_string()).unwrap_or_else(|| {
+            let n_cons = model.n_conss();
+            format!("cons{n_cons}")
+        });
+        match self.kind {
+            LogicKind::And(resultant) => model.add_cons_and(resultant, self.vars, &name),
+            LogicKind::Or(resultant) => model.add_cons_or(resultant, self.vars, &name),
+            LogicKind::Xor(rhs) => model.add_cons_xor(rhs, self.vars, &name),
+            LogicKind::Logicor => model.add_cons_logicor(self.vars, &name),
+        }
+    }
+}
+
+/// A builder for creating pseudoboolean constraints
+/// `lhs <= sum(coef * var) + sum(coef * prod(vars)) <= rhs` over binary variables.
+#[derive(Debug)]
+pub struct PseudoBooleanBuilder<'a> {
+    /// Left-hand side of constraint
+    pub(crate) lhs: f64,
+    /// Right-hand side of constraint
+    pub(crate) rhs: f64,
+    /// (Optional) name of constraint
+    pub(crate) name: Option<&'a str>,
+    /// Linear terms of constraint
+    pub(crate) coefs: Vec<(&'a Variable, f64)>,
+    /// Products of variables of constraint
+    pub(crate) and_terms: Vec<(Vec<&'a Variable>, f64)>,
+}
+
+/// Creates a new default `PseudoBooleanBuilder`.
+pub fn cons_pseudoboolean() -> PseudoBooleanBuilder<'static> {
+    PseudoBooleanBuilder {
+        lhs: f64::NEG_INFINITY,
+        rhs: f64::INFINITY,
+        name: None,
+        coefs: Vec::new(),
+        and_terms: Vec::new(),
+    }
+}
+
+impl<'a> PseudoBooleanBuilder<'a> {
+    /// Creates a constraint of the form `expr <= val`.
+    pub fn le(mut self, val: f64) -> Self {
+        self.lhs = f64::NEG_INFINITY;
+        self.rhs = val;
+        self
+    }
+
+    /// Creates a constraint of the form `val <= expr`.
+    pub fn ge(mut self, val: f64) -> Self {
+        self.lhs = val;
+        self.rhs = f64::INFINITY;
+        self
+    }
+
+    /// Creates a constraint of the form `expr = val`.
+    pub fn eq(mut self, val: f64) -> Self {
+        self.lhs = val;
+        self.rhs = val;
+        self
+    }
+
+    /// Creates a constraint of the form `lhs <= expr <= rhs`.
+    pub fn between(mut self, lhs: f64, rhs: f64) -> Self {
+        self.lhs = lhs;
+        self.rhs = rhs;
+        self
+    }
+
+    /// Sets the name of the constraint.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Adds a linear term to the constraint.
+    pub fn coef(mut self, var: &'a Variable, coef: f64) -> Self {
+        self.coefs.push((var, coef));
+        self
+    }
+
+    /// Adds a product of variables with the given coefficient to the constraint.
+    pub fn term(mut self, vars: Vec<&'a Variable>, coef: f64) -> Self {
+        self.and_terms.push((vars, coef));
+        self
+    }
+}
+
+impl<S> CanBeAddedToModel<S> for PseudoBooleanBuilder<'_>
+where
+    S: ModelStageProblemOrSolving + ModelStageWithProblem,
+{
+    type Return = Constraint;
+    fn add(self, model: &mut Model<S>) -> Self::Return {
+        let (vars, coefs): (Vec<_>, Vec<_>) = self.coefs.into_iter().unzip();
+        let name = self.name.map(|s| s.to_string()).unwrap_or_else(|| {
+            let n_cons = model.n_conss();
+            format!("cons{n_cons}")
+        });
+        model.add_cons_pseudoboolean(vars, &coefs, self.and_terms, self.lhs, self.rhs, &name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::minimal_model;
+    use crate::prelude::*;
+
+    #[test]
+    fn configuration_with_logical_constraints() {
+        let mut model = minimal_model().hide_output().maximize();
+        let a = model.add(var().bin().name("a"));
+        let b = model.add(var().bin().name("b"));
+        let c = model.add(var().bin().name("c").obj(-1.0));
+        let d = model.add(var().bin().name("d").obj(1.0));
+        let r = model.add(var().bin().name("r").obj(10.0));
+        let s = model.add(var().bin().name("s").obj(-1.0));
+
+        let and = model.add(cons_and(&r).vars([&a, &b]).name("and"));
+        let or = model.add_cons_or(&s, vec![&c, &d], "or");
+        let xor = model.add(cons_xor(true).var(&a).var(&b).var(&c));
+        let logicor = model.add(cons_logicor().vars([&c, &d]));
+        let pb = model.add(
+            cons_pseudoboolean()
+                .term(vec![&a, &b], 2.0)
+                .coef(&d, 1.0)
+                .le(2.0)
+                .name("pb"),
+        );
+
+        assert_eq!(and.name(), "and");
+        assert_eq!(and.resultant(), Some(r.clone()));
+        assert_eq!(and.operands(), Some(vec![a.clone(), b.clone()]));
+        assert_eq!(or.resultant(), Some(s.clone()));
+        assert_eq!(xor.xor_rhs(), Some(true));
+        assert_eq!(xor.operands().unwrap().len(), 3);
+        assert!(and.xor_int_var().is_none());
+        assert_eq!(logicor.operands(), Some(vec![c.clone(), d.clone()]));
+        assert_eq!(logicor.resultant(), None);
+        let infinity = unsafe { crate::ffi::SCIPinfinity(model.scip.raw) };
+        assert!(pb.pseudoboolean_lhs().unwrap() <= -infinity);
+        assert_eq!(pb.pseudoboolean_rhs(), Some(2.0));
+        assert_eq!(
+            pb.pseudoboolean_linear_terms(),
+            Some(vec![(d.clone(), 1.0)])
+        );
+        let and_terms = pb.pseudoboolean_and_terms().unwrap();
+        assert_eq!(and_terms.len(), 1);
+        assert_eq!(and_terms[0].1, 2.0);
+        assert_eq!(and_terms[0].0.len(), 2);
+        assert_eq!(and.pseudoboolean_rhs(), None);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        // a = b = c = r = s = 1 and d = 0, as d = 1 would violate the pseudoboolean constraint
+        assert!((solved.obj_val() - 8.0).abs() < 1e-6);
+        let sol = solved.best_sol().unwrap();
+        assert!(sol.val(&r) > 0.5);
+        assert!(sol.val(&c) > 0.5);
+        assert!(sol.val(&d) < 0.5);
+    }
+}
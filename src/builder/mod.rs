@@ -1,15 +1,35 @@
+/// This module contains `BendersBuilder` and `BendersCutBuilder` for easily creating Benders' decompositions and cuts.
+pub mod benders;
 /// This module contains `BranchRuleBuilder` for easily creating branch rules.
 pub mod branchrule;
+/// This module contains `ConflictHdlrBuilder` for easily creating conflict handlers.
+pub mod conflicthdlr;
 /// This module contains `ConsBuilder` for easily creating constraints.
 pub mod cons;
+/// This module contains `ConshdlrBuilder` for easily creating constraint handlers.
+pub mod conshdlr;
+/// This module contains `CutSelBuilder` for easily creating cut selectors.
+pub mod cutsel;
 /// This module contains `EventHdlrBuilder` for easily creating event handlers.
 pub mod eventhdlr;
 /// This module contains `HeurBuilder` for easily creating heuristics.
 pub mod heur;
+/// This module contains `IndicatorBuilder` for easily creating indicator constraints.
+pub mod indicator;
+/// This module contains `LogicConsBuilder` and `PseudoBooleanBuilder` for easily creating logical constraints.
+pub mod logic;
 /// This module contains `NodeSelBuilder` for easily creating node selectors.
 pub mod nodesel;
+/// This module contains `PresolBuilder` for easily creating presolvers.
+pub mod presol;
 /// This module contains `PricerBuilder` for easily creating pricers.
 pub mod pricer;
+/// This module contains `PropBuilder` for easily creating propagators.
+pub mod prop;
+/// This module contains `ReaderBuilder` for easily creating file readers.
+pub mod reader;
+/// This module contains `RelaxBuilder` for easily creating relaxators.
+pub mod relax;
 pub(crate) mod row;
 /// This module contains `SepaBuilder` for easily creating separators.
 pub mod sepa;
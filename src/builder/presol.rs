@@ -0,0 +1,111 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{Model, PresolTiming, Presolver, ProblemCreated};
+
+/// A builder for easily creating presolvers. It can be created using the `presol` function.
+pub struct PresolBuilder<P: Presolver> {
+    name: Option<String>,
+    desc: Option<String>,
+    priority: i32,
+    maxrounds: i32,
+    timing: PresolTiming,
+    presol: P,
+}
+
+impl<P: Presolver> PresolBuilder<P> {
+    /// Creates a new `PresolBuilder` with the given presolver.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `priority`: 0
+    /// - `maxrounds`: -1 (no limit)
+    /// - `timing`: `PresolTiming::MEDIUM`
+    pub fn new(presol: P) -> Self {
+        PresolBuilder {
+            name: None,
+            desc: None,
+            priority: 0,
+            maxrounds: -1,
+            timing: PresolTiming::MEDIUM,
+            presol,
+        }
+    }
+
+    /// Sets the name of the presolver.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the presolver.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the priority of the presolver.
+    /// Presolvers with a higher priority are called first.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the maximal number of presolving rounds the presolver participates in, -1 means no limit.
+    pub fn maxrounds(mut self, maxrounds: i32) -> Self {
+        self.maxrounds = maxrounds;
+        self
+    }
+
+    /// Sets the presolving rounds in which the presolver is called.
+    pub fn timing(mut self, timing: PresolTiming) -> Self {
+        self.timing = timing;
+        self
+    }
+}
+
+/// Creates a new default `PresolBuilder` from a presolver.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{PresolTiming, PresolveChanges, PresolveResult, Presolver, SCIPPresolver, Solving};
+///
+/// struct MyPresolver;
+/// impl Presolver for MyPresolver {
+///     fn execute(&mut self, model: Model<Solving>, presol: SCIPPresolver, timing: PresolTiming, changes: &mut PresolveChanges) -> PresolveResult {
+///         PresolveResult::DidNotFind
+///     }
+/// }
+///
+/// let presolver = presol(MyPresolver)
+///     .name("My Presolver")
+///     .timing(PresolTiming::FAST | PresolTiming::MEDIUM);
+///
+/// let mut model = Model::default();
+/// model.add(presolver);
+/// ```
+pub fn presol<P: Presolver>(presol: P) -> PresolBuilder<P> {
+    PresolBuilder::new(presol)
+}
+
+impl<P: Presolver + 'static> CanBeAddedToModel<ProblemCreated> for PresolBuilder<P> {
+    type Return = ();
+
+    fn add(self, model: &mut Model<ProblemCreated>) {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        model.include_presol(
+            &name,
+            &desc,
+            self.priority,
+            self.maxrounds,
+            self.timing,
+            Box::new(self.presol),
+        );
+    }
+}
+
+impl<P: Presolver> From<P> for PresolBuilder<P> {
+    fn from(presol: P) -> Self {
+        PresolBuilder::new(presol)
+    }
+}
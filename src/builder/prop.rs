@@ -0,0 +1,151 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{Model, PresolTiming, ProblemCreated, PropTiming, Propagator};
+
+/// A builder for easily creating propagators. It can be created using the `prop` function.
+pub struct PropBuilder<P: Propagator> {
+    name: Option<String>,
+    desc: Option<String>,
+    priority: i32,
+    freq: i32,
+    delay: bool,
+    timing: PropTiming,
+    presol_priority: i32,
+    presol_maxrounds: i32,
+    presol_timing: PresolTiming,
+    prop: P,
+}
+
+impl<P: Propagator> PropBuilder<P> {
+    /// Creates a new `PropBuilder` with the given propagator.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `priority`: 0
+    /// - `freq`: 1 (called at every node)
+    /// - `delay`: false
+    /// - `timing`: `PropTiming::BEFORE_LP`
+    /// - `presol_priority`: 0
+    /// - `presol_maxrounds`: -1 (no limit)
+    /// - `presol_timing`: `PresolTiming::NONE` (the propagator does not presolve)
+    pub fn new(prop: P) -> Self {
+        PropBuilder {
+            name: None,
+            desc: None,
+            priority: 0,
+            freq: 1,
+            delay: false,
+            timing: PropTiming::BEFORE_LP,
+            presol_priority: 0,
+            presol_maxrounds: -1,
+            presol_timing: PresolTiming::NONE,
+            prop,
+        }
+    }
+
+    /// Sets the name of the propagator.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the propagator.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the priority of the propagator.
+    /// Propagators with a higher priority are called first.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the frequency of the propagator. 1 means at every node, 2 means at every other node and so on, -1 turns off the propagator in the tree.
+    pub fn freq(mut self, freq: i32) -> Self {
+        self.freq = freq;
+        self
+    }
+
+    /// Sets whether the propagator should be delayed if other propagators found reductions.
+    pub fn delay(mut self, delay: bool) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the points in the node processing at which the propagator is called.
+    pub fn timing(mut self, timing: PropTiming) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Sets the presolving priority of the propagator.
+    pub fn presol_priority(mut self, priority: i32) -> Self {
+        self.presol_priority = priority;
+        self
+    }
+
+    /// Sets the maximal number of presolving rounds the propagator participates in, -1 means no limit.
+    pub fn presol_maxrounds(mut self, maxrounds: i32) -> Self {
+        self.presol_maxrounds = maxrounds;
+        self
+    }
+
+    /// Sets the presolving rounds in which [`Propagator::presolve`] is called.
+    pub fn presol_timing(mut self, timing: PresolTiming) -> Self {
+        self.presol_timing = timing;
+        self
+    }
+}
+
+/// Creates a new default `PropBuilder` from a propagator.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{PropTiming, PropagationResult, Propagator, SCIPPropagator, Solving};
+///
+/// struct MyProp;
+/// impl Propagator for MyProp {
+///     fn propagate(&mut self, model: Model<Solving>, prop: SCIPPropagator, timing: PropTiming) -> PropagationResult {
+///         PropagationResult::DidNotFind
+///     }
+/// }
+///
+/// let propagator = prop(MyProp)
+///     .name("My Propagator")
+///     .timing(PropTiming::BEFORE_LP | PropTiming::AFTER_LP_LOOP);
+///
+/// let mut model = Model::default();
+/// model.add(propagator);
+/// ```
+pub fn prop<P: Propagator>(prop: P) -> PropBuilder<P> {
+    PropBuilder::new(prop)
+}
+
+impl<P: Propagator + 'static> CanBeAddedToModel<ProblemCreated> for PropBuilder<P> {
+    type Return = ();
+
+    fn add(self, model: &mut Model<ProblemCreated>) {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        model.include_prop(
+            &name,
+            &desc,
+            self.priority,
+            self.freq,
+            self.delay,
+            self.timing,
+            self.presol_priority,
+            self.presol_maxrounds,
+            self.presol_timing,
+            Box::new(self.prop),
+        );
+    }
+}
+
+impl<P: Propagator> From<P> for PropBuilder<P> {
+    fn from(prop: P) -> Self {
+        PropBuilder::new(prop)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{Model, PluginsIncluded, ProblemCreated, Reader, SCIPReader};
+
+/// A builder for easily creating file readers. It can be created using the `reader` function.
+pub struct ReaderBuilder<R: Reader> {
+    name: Option<String>,
+    desc: Option<String>,
+    extension: Option<String>,
+    reader: R,
+}
+
+impl<R: Reader> ReaderBuilder<R> {
+    /// Creates a new `ReaderBuilder` with the given reader.
+    ///
+    /// # Defaults
+    /// - `name`: the extension
+    /// - `desc`: empty string
+    /// - `extension`: empty string, it should always be set
+    pub fn new(reader: R) -> Self {
+        ReaderBuilder {
+            name: None,
+            desc: None,
+            extension: None,
+            reader,
+        }
+    }
+
+    /// Sets the name of the reader.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the reader.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the file extension (without the leading dot) the reader is responsible for.
+    pub fn extension(mut self, extension: &str) -> Self {
+        self.extension = Some(extension.to_string());
+        self
+    }
+}
+
+/// Creates a new default `ReaderBuilder` from a reader.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{PluginsIncluded, Reader, ReaderResult, Retcode};
+///
+/// struct MyReader;
+/// impl Reader for MyReader {
+///     fn read(&mut self, model: Model<PluginsIncluded>, path: &str) -> Result<ReaderResult, Retcode> {
+///         let mut model = model.create_prob(path);
+///         model.add(var().bin().obj(1.0).name("x"));
+///         Ok(ReaderResult::Success)
+///     }
+/// }
+///
+/// let my_reader = reader(MyReader).extension("myext").desc("My file format");
+///
+/// let mut model = Model::default();
+/// model.add(my_reader);
+/// ```
+pub fn reader<R: Reader>(reader: R) -> ReaderBuilder<R> {
+    ReaderBuilder::new(reader)
+}
+
+fn include_reader<R: Reader + 'static, S>(
+    builder: ReaderBuilder<R>,
+    model: &mut Model<S>,
+) -> SCIPReader {
+    let extension = builder.extension.unwrap_or_else(|| "".into());
+    let name = builder.name.unwrap_or_else(|| extension.clone());
+    let desc = builder.desc.unwrap_or_else(|| "".into());
+    model.include_reader(&name, &desc, &extension, Box::new(builder.reader))
+}
+
+impl<R: Reader + 'static> CanBeAddedToModel<PluginsIncluded> for ReaderBuilder<R> {
+    type Return = SCIPReader;
+
+    fn add(self, model: &mut Model<PluginsIncluded>) -> SCIPReader {
+        include_reader(self, model)
+    }
+}
+
+impl<R: Reader + 'static> CanBeAddedToModel<ProblemCreated> for ReaderBuilder<R> {
+    type Return = SCIPReader;
+
+    fn add(self, model: &mut Model<ProblemCreated>) -> SCIPReader {
+        include_reader(self, model)
+    }
+}
+
+impl<R: Reader> From<R> for ReaderBuilder<R> {
+    fn from(reader: R) -> Self {
+        ReaderBuilder::new(reader)
+    }
+}
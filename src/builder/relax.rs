@@ -0,0 +1,93 @@
+use crate::builder::CanBeAddedToModel;
+use crate::{Model, ProblemCreated, Relaxator};
+
+/// A builder for easily creating relaxators. It can be created using the `relax` function.
+pub struct RelaxBuilder<R: Relaxator> {
+    name: Option<String>,
+    desc: Option<String>,
+    priority: i32,
+    freq: i32,
+    relax: R,
+}
+
+impl<R: Relaxator> RelaxBuilder<R> {
+    /// Creates a new `RelaxBuilder` with the given relaxator.
+    ///
+    /// # Defaults
+    /// - `name`: empty string
+    /// - `desc`: empty string
+    /// - `priority`: 0
+    /// - `freq`: 1
+    pub fn new(relax: R) -> Self {
+        RelaxBuilder {
+            name: None,
+            desc: None,
+            priority: 0,
+            freq: 1,
+            relax,
+        }
+    }
+
+    /// Sets the name of the relaxator.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the relaxator.
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = Some(desc.to_string());
+        self
+    }
+
+    /// Sets the priority of the relaxator.
+    /// Relaxators with a non-negative priority are called before the LP is solved, the others after it.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the frequency for calling the relaxator, -1 means never and 0 only at the root.
+    pub fn freq(mut self, freq: i32) -> Self {
+        self.freq = freq;
+        self
+    }
+}
+
+/// Creates a new default `RelaxBuilder` from a relaxator.
+/// This function allows you to write:
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{RelaxResult, Relaxator, SCIPRelaxator};
+///
+/// struct MyRelaxator;
+/// impl Relaxator for MyRelaxator {
+///     fn solve(&mut self, model: Model<Solving>, relax: SCIPRelaxator, lower_bound: &mut f64) -> RelaxResult {
+///         RelaxResult::DidNotRun
+///     }
+/// }
+///
+/// let relaxator = relax(MyRelaxator).name("My Relaxator").priority(-1);
+///
+/// let mut model = Model::default();
+/// model.add(relaxator);
+/// ```
+pub fn relax<R: Relaxator>(relax: R) -> RelaxBuilder<R> {
+    RelaxBuilder::new(relax)
+}
+
+impl<R: Relaxator + 'static> CanBeAddedToModel<ProblemCreated> for RelaxBuilder<R> {
+    type Return = ();
+
+    fn add(self, model: &mut Model<ProblemCreated>) {
+        let name = self.name.unwrap_or_else(|| "".into());
+        let desc = self.desc.unwrap_or_else(|| "".into());
+        model.include_relax(&name, &desc, self.priority, self.freq, Box::new(self.relax));
+    }
+}
+
+impl<R: Relaxator> From<R> for RelaxBuilder<R> {
+    fn from(relax: R) -> Self {
+        RelaxBuilder::new(relax)
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{BoundType, Model, Solving, Variable, ffi};
+
+/// A trait for defining custom conflict handlers.
+///
+/// Conflict handlers are called whenever SCIP's conflict analysis found a conflict, i.e. a set
+/// of bound changes that cannot hold at the same time. They can store the conflict or add a
+/// constraint for it with [`Model::add_conflict_cons`].
+pub trait ConflictHdlr {
+    /// Executes the conflict handler.
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance in `Solving` stage.
+    /// * `conflicthdlr` - the internal conflict handler object.
+    /// * `conflict` - the conflict that was found.
+    ///
+    /// # Returns
+    /// * `ConflictResult` indicating whether a constraint was added for the conflict.
+    fn exec(
+        &mut self,
+        model: Model<Solving>,
+        conflicthdlr: SCIPConflictHdlr,
+        conflict: &Conflict,
+    ) -> ConflictResult;
+}
+
+/// The result of a conflict handler call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResult {
+    /// A constraint was added for the conflict.
+    Success,
+    /// The conflict handler did not add a constraint for the conflict.
+    DidNotFind,
+    /// The conflict handler was skipped.
+    DidNotRun,
+}
+
+impl From<ConflictResult> for ffi::SCIP_Result {
+    fn from(result: ConflictResult) -> Self {
+        match result {
+            ConflictResult::Success => ffi::SCIP_Result_SCIP_CONSADDED,
+            ConflictResult::DidNotFind => ffi::SCIP_Result_SCIP_DIDNOTFIND,
+            ConflictResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+        }
+    }
+}
+
+/// The kind of analysis that produced a conflict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictType {
+    /// The origin of the conflict is unknown.
+    Unknown,
+    /// The conflict results from an infeasible propagation.
+    Propagation,
+    /// The conflict results from an infeasible LP relaxation.
+    InfeasibleLp,
+    /// The conflict results from an LP relaxation exceeding the cutoff bound.
+    BoundExceeding,
+    /// The conflict results from an alternative infeasibility proof.
+    AltInfeasibleProof,
+    /// The conflict results from an alternative proof of exceeding the cutoff bound.
+    AltBoundProof,
+}
+
+impl From<ffi::SCIP_CONFTYPE> for ConflictType {
+    fn from(conftype: ffi::SCIP_CONFTYPE) -> Self {
+        match conftype {
+            ffi::SCIP_ConflictType_SCIP_CONFTYPE_PROPAGATION => ConflictType::Propagation,
+            ffi::SCIP_ConflictType_SCIP_CONFTYPE_INFEASLP => ConflictType::InfeasibleLp,
+            ffi::SCIP_ConflictType_SCIP_CONFTYPE_BNDEXCEEDING => ConflictType::BoundExceeding,
+            ffi::SCIP_ConflictType_SCIP_CONFTYPE_ALTINFPROOF => ConflictType::AltInfeasibleProof,
+            ffi::SCIP_ConflictType_SCIP_CONFTYPE_ALTBNDPROOF => ConflictType::AltBoundProof,
+            _ => ConflictType::Unknown,
+        }
+    }
+}
+
+impl From<ConflictType> for ffi::SCIP_CONFTYPE {
+    fn from(conftype: ConflictType) -> Self {
+        match conftype {
+            ConflictType::Unknown => ffi::SCIP_ConflictType_SCIP_CONFTYPE_UNKNOWN,
+            ConflictType::Propagation => ffi::SCIP_ConflictType_SCIP_CONFTYPE_PROPAGATION,
+            ConflictType::InfeasibleLp => ffi::SCIP_ConflictType_SCIP_CONFTYPE_INFEASLP,
+            ConflictType::BoundExceeding => ffi::SCIP_ConflictType_SCIP_CONFTYPE_BNDEXCEEDING,
+            ConflictType::AltInfeasibleProof => ffi::SCIP_ConflictType_SCIP_CONFTYPE_ALTINFPROOF,
+            ConflictType::AltBoundProof => ffi::SCIP_ConflictType_SCIP_CONFTYPE_ALTBNDPROOF,
+        }
+    }
+}
+
+/// A conflict found by SCIP's conflict analysis.
+#[derive(Debug)]
+pub struct Conflict {
+    /// The bound changes of the conflict, which cannot all hold at the same time. An entry
+    /// `(var, BoundType::Lower, b)` stands for `var >= b`, and `(var, BoundType::Upper, b)`
+    /// for `var <= b`. The variables belong to the transformed problem.
+    pub bound_changes: Vec<(Variable, BoundType, f64)>,
+    /// The depth of the node from which on the conflict is valid, 0 means it is valid globally.
+    pub valid_depth: usize,
+    /// The kind of analysis that produced the conflict.
+    pub conflict_type: ConflictType,
+    /// Whether the cutoff bound was involved in finding the conflict.
+    pub cutoff_involved: bool,
+    /// Whether a constraint for the conflict should be separated.
+    pub separate: bool,
+    /// Whether a constraint for the conflict is only valid locally.
+    pub local: bool,
+    /// Whether a constraint for the conflict should be added to the LP dynamically.
+    pub dynamic: bool,
+    /// Whether a constraint for the conflict should be removable from the LP.
+    pub removable: bool,
+    /// Whether another conflict handler already added a constraint for the conflict.
+    pub resolved: bool,
+    pub(crate) node: *mut ffi::SCIP_NODE,
+    pub(crate) valid_node: *mut ffi::SCIP_NODE,
+}
+
+/// A wrapper struct for the internal ffi::SCIP_CONFLICTHDLR
+#[derive(Debug)]
+pub struct SCIPConflictHdlr {
+    pub(crate) raw: *mut ffi::SCIP_CONFLICTHDLR,
+}
+
+impl SCIPConflictHdlr {
+    /// Returns the internal raw pointer of the conflict handler.
+    pub fn inner(&self) -> *mut ffi::SCIP_CONFLICTHDLR {
+        self.raw
+    }
+
+    /// Returns the name of the conflict handler.
+    pub fn name(&self) -> String {
+        unsafe {
+            let name = ffi::SCIPconflicthdlrGetName(self.raw);
+            std::ffi::CStr::from_ptr(name).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the description of the conflict handler.
+    pub fn desc(&self) -> String {
+        unsafe {
+            let desc = ffi::SCIPconflicthdlrGetDesc(self.raw);
+            std::ffi::CStr::from_ptr(desc).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the priority of the conflict handler.
+    pub fn priority(&self) -> i32 {
+        unsafe { ffi::SCIPconflicthdlrGetPriority(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::cons::ConsBuilder;
+    use crate::prelude::*;
+    use crate::{VarType, minimal_model};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Nogood = Vec<(String, BoundType, f64)>;
+
+    /// Stores the conflicts as `(name, bound type, bound)` and adds them as clauses.
+    struct NogoodCollector {
+        nogoods: Rc<RefCell<Vec<Nogood>>>,
+    }
+
+    impl ConflictHdlr for NogoodCollector {
+        fn exec(
+            &mut self,
+            mut model: Model<Solving>,
+            conflicthdlr: SCIPConflictHdlr,
+            conflict: &Conflict,
+        ) -> ConflictResult {
+            assert_eq!(conflicthdlr.name(), "nogoods");
+            assert!(!conflict.bound_changes.is_empty());
+
+            self.nogoods.borrow_mut().push(
+                conflict
+                    .bound_changes
+                    .iter()
+                    .map(|(var, bound_type, bound)| (var.name(), *bound_type, *bound))
+                    .collect(),
+            );
+
+            if conflict.resolved
+                || conflict
+                    .bound_changes
+                    .iter()
+                    .any(|(var, _, _)| var.var_type() != VarType::Binary)
+            {
+                return ConflictResult::DidNotRun;
+            }
+
+            // at least one of the bound changes has to be violated
+            let mut clause = ConsBuilder::default();
+            let mut n_lower = 0.0;
+            for (var, bound_type, _) in &conflict.bound_changes {
+                match bound_type {
+                    BoundType::Lower => {
+                        clause = clause.coef(var, -1.0);
+                        n_lower += 1.0;
+                    }
+                    BoundType::Upper => clause = clause.coef(var, 1.0),
+                }
+            }
+            model
+                .add_conflict_cons(conflict, &clause.ge(1.0 - n_lower))
+                .unwrap();
+            ConflictResult::Success
+        }
+    }
+
+    #[test]
+    fn collect_pigeonhole_nogoods() {
+        let nogoods = Rc::new(RefCell::new(vec![]));
+
+        let mut model = minimal_model();
+        let n_holes = 4;
+        let x: Vec<Vec<Variable>> = (0..=n_holes)
+            .map(|_| (0..n_holes).map(|_| model.add(var().bin())).collect())
+            .collect();
+        for pigeon in &x {
+            model.add(pigeon.iter().fold(cons(), |c, v| c.coef(v, 1.0)).eq(1.0));
+        }
+        for hole in 0..n_holes {
+            model.add(x.iter().fold(cons(), |c, p| c.coef(&p[hole], 1.0)).le(1.0));
+        }
+        model.add(
+            conflicthdlr(NogoodCollector {
+                nogoods: nogoods.clone(),
+            })
+            .name("nogoods"),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Infeasible);
+        assert!(!nogoods.borrow().is_empty());
+    }
+}
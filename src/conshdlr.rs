@@ -1,31 +1,182 @@
-use crate::{Model, Retcode, Row, Solution, Solving, ffi, scip_call};
+use crate::{
+    Constraint, Model, PresolveChanges, PresolveResult, PropagationResult, Retcode, Row,
+    SeparationResult, Solution, Solving, Variable, ffi, scip_call,
+};
 use scip_sys::SCIP_ROW;
+use std::any::Any;
 use std::fmt::Debug;
 
 /// A trait for implementing custom constraint handlers.
 ///
+/// Constraint handlers receive the constraints they are responsible for in every callback. Each
+/// of those constraints can carry its own data, created with
+/// [`add_cons_with_data`](crate::ProblemOrSolving::add_cons_with_data) and accessed
+/// with [`SCIPConshdlr::cons_data`]. Handlers that do not need constraints (i.e. that check a
+/// global property of the solution) simply receive an empty slice.
+///
+/// Only `check` and `enforce` are mandatory, all other callbacks have default implementations
+/// that do nothing.
+///
 /// # Example
 /// An example of using a custom constraint handler to enforce subtour elimination constraints in a TSP can be found
 /// [here](https://github.com/scipopt/russcip/blob/main/examples/README.md).
 pub trait Conshdlr {
-    /// Check if the (primal) solution satisfies the constraint.
+    /// Check if the (primal) solution satisfies the constraints.
     ///
     /// # Arguments
     /// * `model` - The current model in solving state.
     /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `conss` - The constraints of this handler that should be checked.
     /// * `solution` - The solution to be checked.
-    fn check(&mut self, model: Model<Solving>, conshdlr: SCIPConshdlr, solution: &Solution)
-    -> bool;
+    fn check(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        conss: &[Constraint],
+        solution: &Solution,
+    ) -> bool;
+
+    /// Enforce the constraints for the current sub-problem's (LP) solution.
+    ///
+    /// # Arguments
+    /// * `model` - The current model in solving state.
+    /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `conss` - The constraints of this handler that should be enforced.
+    ///
+    /// # Returns
+    /// * `ConshdlrResult` - The result of enforcing the constraints.
+    fn enforce(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        conss: &[Constraint],
+    ) -> ConshdlrResult;
+
+    /// Enforce the constraints for the current pseudo solution, i.e. when the LP was not solved
+    /// at the current node. Cutting planes can not be added here, so `ConshdlrResult::Separated`
+    /// must not be returned.
+    ///
+    /// Defaults to calling [`enforce`](Conshdlr::enforce), which is correct as long as `enforce`
+    /// only works with [`Model::current_val`] and does not separate.
+    fn enforce_pseudo(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        conss: &[Constraint],
+    ) -> ConshdlrResult {
+        self.enforce(model, conshdlr, conss)
+    }
+
+    /// Propagate the constraints, i.e. tighten the local bounds of their variables.
+    ///
+    /// # Arguments
+    /// * `model` - The current model in solving state.
+    /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `conss` - The constraints of this handler that should be propagated.
+    ///
+    /// # Returns
+    /// * `PropagationResult` - The result of the propagation, defaults to `DidNotRun`.
+    fn propagate(
+        &mut self,
+        _model: Model<Solving>,
+        _conshdlr: SCIPConshdlr,
+        _conss: &[Constraint],
+    ) -> PropagationResult {
+        PropagationResult::DidNotRun
+    }
+
+    /// Presolve the constraints.
+    ///
+    /// # Arguments
+    /// * `model` - The current model.
+    /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `conss` - The constraints of this handler.
+    /// * `changes` - The reductions found in this call should be counted here.
+    ///
+    /// # Returns
+    /// * `PresolveResult` - The result of the presolving round, defaults to `DidNotRun`.
+    fn presolve(
+        &mut self,
+        _model: Model<Solving>,
+        _conshdlr: SCIPConshdlr,
+        _conss: &[Constraint],
+        _changes: &mut PresolveChanges,
+    ) -> PresolveResult {
+        PresolveResult::DidNotRun
+    }
 
-    /// Enforce the constraint for the current sub-problem's (LP) solution.
+    /// Separate the current LP solution, e.g. by adding cutting planes.
     ///
     /// # Arguments
     /// * `model` - The current model in solving state.
     /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `conss` - The constraints of this handler that should be separated.
     ///
     /// # Returns
-    /// * `ConshdlrResult` - The result of enforcing the constraint.
-    fn enforce(&mut self, model: Model<Solving>, conshdlr: SCIPConshdlr) -> ConshdlrResult;
+    /// * `SeparationResult` - The result of the separation, defaults to `DidNotRun`.
+    fn separate_lp(
+        &mut self,
+        _model: Model<Solving>,
+        _conshdlr: SCIPConshdlr,
+        _conss: &[Constraint],
+    ) -> SeparationResult {
+        SeparationResult::DidNotRun
+    }
+
+    /// Separate the given primal solution, e.g. by adding cutting planes.
+    ///
+    /// # Arguments
+    /// * `model` - The current model in solving state.
+    /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `conss` - The constraints of this handler that should be separated.
+    /// * `solution` - The solution to be separated.
+    ///
+    /// # Returns
+    /// * `SeparationResult` - The result of the separation, defaults to `DidNotRun`.
+    fn separate_sol(
+        &mut self,
+        _model: Model<Solving>,
+        _conshdlr: SCIPConshdlr,
+        _conss: &[Constraint],
+        _solution: &Solution,
+    ) -> SeparationResult {
+        SeparationResult::DidNotRun
+    }
+
+    /// Returns the variables that are locked by a constraint, together with the direction in which
+    /// rounding them could violate the constraint. SCIP uses the locks to decide which dual
+    /// reductions are valid, so every variable the handler depends on should be locked.
+    ///
+    /// # Arguments
+    /// * `model` - The current model.
+    /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `cons` - The constraint whose variables should be locked.
+    ///
+    /// # Returns
+    /// * The locked variables, defaults to none.
+    fn lock(
+        &mut self,
+        _model: Model<Solving>,
+        _conshdlr: SCIPConshdlr,
+        _cons: &Constraint,
+    ) -> Vec<(Variable, LockDirection)> {
+        vec![]
+    }
+
+    /// Called when a constraint of this handler is deleted, right before its data is dropped.
+    ///
+    /// # Arguments
+    /// * `model` - The current model.
+    /// * `conshdlr` - The internal SCIP constraint handler.
+    /// * `cons` - The constraint being deleted.
+    fn delete(&mut self, _model: Model<Solving>, _conshdlr: SCIPConshdlr, _cons: &Constraint) {}
+
+    /// Creates a copy of the constraint handler, used to include it in copies of the model
+    /// (e.g. sub-SCIPs created by heuristics). If `None` is returned (the default), copies of the
+    /// model are not valid, i.e. their solutions are only used as hints.
+    fn copy(&self) -> Option<Box<dyn Conshdlr>> {
+        None
+    }
 }
 
 /// The result of enforcing a constraint handler.
@@ -44,6 +195,8 @@ pub enum ConshdlrResult {
     SolveLP,
     /// Created a branching.
     Branched,
+    /// The solution is infeasible, but the infeasibility was not resolved (SCIP will branch).
+    Infeasible,
 }
 
 impl From<ConshdlrResult> for ffi::SCIP_Result {
@@ -56,10 +209,22 @@ impl From<ConshdlrResult> for ffi::SCIP_Result {
             ConshdlrResult::Separated => ffi::SCIP_Result_SCIP_SEPARATED,
             ConshdlrResult::SolveLP => ffi::SCIP_Result_SCIP_SOLVELP,
             ConshdlrResult::Branched => ffi::SCIP_Result_SCIP_BRANCHED,
+            ConshdlrResult::Infeasible => ffi::SCIP_Result_SCIP_INFEASIBLE,
         }
     }
 }
 
+/// The direction in which rounding a variable may violate a constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockDirection {
+    /// Decreasing the value of the variable may violate the constraint.
+    Down,
+    /// Increasing the value of the variable may violate the constraint.
+    Up,
+    /// Changing the value of the variable in any direction may violate the constraint.
+    Both,
+}
+
 /// Wrapper for the internal SCIP constraint handler.
 #[derive(Debug)]
 pub struct SCIPConshdlr {
@@ -86,6 +251,21 @@ impl SCIPConshdlr {
         desc.to_str().unwrap().to_string()
     }
 
+    /// Returns the data attached to a constraint of this handler.
+    ///
+    /// Returns `None` if the constraint does not belong to this handler, or if its data is not of
+    /// type `T`. Use interior mutability (e.g. `RefCell`) for data that has to change during the solve.
+    pub fn cons_data<'a, T: 'static>(&self, cons: &'a Constraint) -> Option<&'a T> {
+        if unsafe { ffi::SCIPconsGetHdlr(cons.raw) } != self.raw {
+            return None;
+        }
+        let data = unsafe { ffi::SCIPconsGetData(cons.raw) } as *const Box<dyn Any>;
+        if data.is_null() {
+            return None;
+        }
+        unsafe { (**data).downcast_ref::<T>() }
+    }
+
     /// Creates an empty row for the constraint handler.
     pub fn create_empty_row(
         &self,
@@ -115,7 +295,11 @@ impl SCIPConshdlr {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Status;
+    use crate::builder::cons::ConsBuilder;
+    use crate::prelude::*;
+    use crate::{Status, minimal_model};
+    use std::cell::Cell;
+    use std::rc::Rc;
 
     #[test]
     fn all_inf_conshdlr() {
@@ -126,6 +310,7 @@ mod tests {
                 &mut self,
                 _model: Model<Solving>,
                 _conshdlr: SCIPConshdlr,
+                _conss: &[Constraint],
                 _solution: &Solution,
             ) -> bool {
                 false
@@ -135,6 +320,7 @@ mod tests {
                 &mut self,
                 _model: Model<Solving>,
                 _conshdlr: SCIPConshdlr,
+                _conss: &[Constraint],
             ) -> ConshdlrResult {
                 ConshdlrResult::CutOff
             }
@@ -154,4 +340,175 @@ mod tests {
 
         assert_eq!(solved.status(), Status::Infeasible);
     }
+
+    /// Data of an "at most one of these binaries is set" constraint.
+    struct AtMostOne {
+        vars: Vec<Variable>,
+    }
+
+    struct AtMostOneConshdlr {
+        propagations: Rc<Cell<usize>>,
+    }
+
+    impl Conshdlr for AtMostOneConshdlr {
+        fn check(
+            &mut self,
+            _model: Model<Solving>,
+            conshdlr: SCIPConshdlr,
+            conss: &[Constraint],
+            solution: &Solution,
+        ) -> bool {
+            conss.iter().all(|cons| {
+                let data = conshdlr.cons_data::<AtMostOne>(cons).unwrap();
+                data.vars.iter().map(|v| solution.val(v)).sum::<f64>() <= 1.0 + 1e-6
+            })
+        }
+
+        fn enforce(
+            &mut self,
+            mut model: Model<Solving>,
+            conshdlr: SCIPConshdlr,
+            conss: &[Constraint],
+        ) -> ConshdlrResult {
+            let mut result = ConshdlrResult::Feasible;
+            for cons in conss {
+                let data = conshdlr.cons_data::<AtMostOne>(cons).unwrap();
+                let activity = data.vars.iter().map(|v| model.current_val(v)).sum::<f64>();
+                if activity > 1.0 + 1e-6 {
+                    model.add(cons_builder(&data.vars));
+                    result = ConshdlrResult::ConsAdded;
+                }
+            }
+            result
+        }
+
+        fn propagate(
+            &mut self,
+            mut model: Model<Solving>,
+            conshdlr: SCIPConshdlr,
+            conss: &[Constraint],
+        ) -> PropagationResult {
+            self.propagations.set(self.propagations.get() + 1);
+            let mut result = PropagationResult::DidNotFind;
+            let node = model.focus_node();
+            for cons in conss {
+                let data = conshdlr.cons_data::<AtMostOne>(cons).unwrap();
+                let vars = data
+                    .vars
+                    .iter()
+                    .map(|v| v.transformed().unwrap())
+                    .collect::<Vec<_>>();
+                if vars.iter().filter(|v| v.lb_local() > 0.5).count() != 1 {
+                    continue;
+                }
+                for var in vars
+                    .iter()
+                    .filter(|v| v.lb_local() < 0.5 && v.ub_local() > 0.5)
+                {
+                    model.set_ub_node(&node, var, 0.0);
+                    result = PropagationResult::ReducedDom;
+                }
+            }
+            result
+        }
+
+        fn lock(
+            &mut self,
+            _model: Model<Solving>,
+            conshdlr: SCIPConshdlr,
+            cons: &Constraint,
+        ) -> Vec<(Variable, LockDirection)> {
+            let data = conshdlr.cons_data::<AtMostOne>(cons).unwrap();
+            data.vars
+                .iter()
+                .map(|v| (v.clone(), LockDirection::Up))
+                .collect()
+        }
+    }
+
+    fn cons_builder(vars: &[Variable]) -> ConsBuilder<'_> {
+        cons().le(1.0).expr(vars.iter().map(|v| (v, 1.0)))
+    }
+
+    #[test]
+    fn conshdlr_with_cons_data() {
+        let propagations = Rc::new(Cell::new(0));
+
+        let mut model = minimal_model().maximize();
+        let vars = (0..3)
+            .map(|i| model.add(var().bin().obj(1.0).name(&format!("x{i}"))))
+            .collect::<Vec<_>>();
+
+        let hdlr = model.add(
+            conshdlr(AtMostOneConshdlr {
+                propagations: propagations.clone(),
+            })
+            .name("atmostone")
+            .prop_timing(PropTiming::BEFORE_LP | PropTiming::DURING_LP_LOOP)
+            .presol_timing(PresolTiming::FAST)
+            .needs_cons(true),
+        );
+        assert_eq!(hdlr.name(), "atmostone");
+
+        let cons = model.add_cons_with_data(&hdlr, "c", AtMostOne { vars: vars.clone() });
+        assert!(hdlr.cons_data::<AtMostOne>(&cons).is_some());
+        assert!(hdlr.cons_data::<f64>(&cons).is_none());
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 1.0).abs() < 1e-6);
+        assert!(propagations.get() > 0);
+    }
+
+    #[test]
+    fn cons_data_is_deleted() {
+        struct CountingConshdlr {
+            deletions: Rc<Cell<usize>>,
+        }
+
+        impl Conshdlr for CountingConshdlr {
+            fn check(
+                &mut self,
+                _model: Model<Solving>,
+                _conshdlr: SCIPConshdlr,
+                _conss: &[Constraint],
+                _solution: &Solution,
+            ) -> bool {
+                true
+            }
+
+            fn enforce(
+                &mut self,
+                _model: Model<Solving>,
+                _conshdlr: SCIPConshdlr,
+                _conss: &[Constraint],
+            ) -> ConshdlrResult {
+                ConshdlrResult::Feasible
+            }
+
+            fn delete(
+                &mut self,
+                _model: Model<Solving>,
+                conshdlr: SCIPConshdlr,
+                cons: &Constraint,
+            ) {
+                assert_eq!(conshdlr.cons_data::<usize>(cons), Some(&42));
+                self.deletions.set(self.deletions.get() + 1);
+            }
+        }
+
+        let deletions = Rc::new(Cell::new(0));
+        let mut model = minimal_model();
+        let hdlr = model.add(conshdlr(CountingConshdlr {
+            deletions: deletions.clone(),
+        }));
+        let cons = model.add_cons_with_data(&hdlr, "c", 42usize);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+
+        drop(cons);
+        drop(solved);
+        assert_eq!(deletions.get(), 1);
+    }
 }
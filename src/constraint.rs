@@ -1,5 +1,5 @@
 use crate::scip::ScipPtr;
-use crate::{Row, ffi};
+use crate::{BoundType, Expr, Row, Variable, ffi};
 use std::rc::Rc;
 
 /// A constraint in an optimization problem.
@@ -77,6 +77,121 @@ impl Constraint {
         Some(unsafe { ffi::SCIPgetDualfarkasLinear(self.scip.raw, self.raw) })
     }
 
+    /// Returns the name of the constraint handler of the constraint, e.g. `"linear"`.
+    /// Returns an empty string if the constraint has no constraint handler.
+    pub fn handler_name(&self) -> String {
+        let cons_handler = unsafe { ffi::SCIPconsGetHdlr(self.raw) };
+        if cons_handler.is_null() {
+            return String::new();
+        }
+        let cons_handler_name = unsafe { ffi::SCIPconshdlrGetName(cons_handler) };
+        if cons_handler_name.is_null() {
+            return String::new();
+        }
+        let cons_handler_name = unsafe { std::ffi::CStr::from_ptr(cons_handler_name) };
+        String::from(cons_handler_name.to_str().unwrap())
+    }
+
+    /// Returns the kind of the constraint, determined by its constraint handler.
+    pub fn kind(&self) -> ConsKind {
+        match self.handler_name().as_str() {
+            "linear" => ConsKind::Linear,
+            "setppc" => {
+                let setppc_type = unsafe { ffi::SCIPgetTypeSetppc(self.scip.raw, self.raw) };
+                ConsKind::SetPPC(setppc_type.into())
+            }
+            "knapsack" => ConsKind::Knapsack,
+            "varbound" => ConsKind::Varbound,
+            "logicor" => ConsKind::Logicor,
+            "and" => ConsKind::And,
+            "or" => ConsKind::Or,
+            "xor" => ConsKind::Xor,
+            "bounddisjunction" => ConsKind::BoundDisjunction,
+            "linking" => ConsKind::Linking,
+            "pseudoboolean" => ConsKind::PseudoBoolean,
+            "nonlinear" => {
+                if self.scip.cons_is_quadratic(self.raw).unwrap_or(false) {
+                    ConsKind::Quadratic
+                } else {
+                    ConsKind::Nonlinear
+                }
+            }
+            "indicator" => ConsKind::Indicator,
+            "SOS1" => ConsKind::SOS1,
+            "SOS2" => ConsKind::SOS2,
+            "cardinality" => ConsKind::Cardinality,
+            name => ConsKind::Other(name.to_string()),
+        }
+    }
+
+    /// Returns the variables of the constraint.
+    /// Returns `None` if the constraint handler cannot provide its variables.
+    pub fn vars(&self) -> Option<Vec<Variable>> {
+        let vars = self.scip.cons_vars(self.raw).ok()??;
+        Some(vars.into_iter().map(|raw| self.variable(raw)).collect())
+    }
+
+    /// Returns the coefficients of the variables of the constraint, in the order of [`vars`](Constraint::vars).
+    /// Returns `None` if the constraint cannot be represented as a single linear constraint, e.g. for nonlinear constraints.
+    pub fn coefs(&self) -> Option<Vec<f64>> {
+        self.scip.cons_vals(self.raw).ok()?
+    }
+
+    /// Returns the left-hand side of the constraint.
+    /// Returns `None` if the constraint has no left-hand side, e.g. for SOS1 constraints.
+    pub fn lhs(&self) -> Option<f64> {
+        if self.has_handler("nonlinear") {
+            return Some(unsafe { ffi::SCIPgetLhsNonlinear(self.raw) });
+        }
+        let mut success = 0;
+        let lhs = unsafe { ffi::SCIPconsGetLhs(self.scip.raw, self.raw, &mut success) };
+        (success != 0).then_some(lhs)
+    }
+
+    /// Returns the right-hand side of the constraint.
+    /// Returns `None` if the constraint has no right-hand side, e.g. for SOS1 constraints.
+    pub fn rhs(&self) -> Option<f64> {
+        if self.has_handler("nonlinear") {
+            return Some(unsafe { ffi::SCIPgetRhsNonlinear(self.raw) });
+        }
+        let mut success = 0;
+        let rhs = unsafe { ffi::SCIPconsGetRhs(self.scip.raw, self.raw, &mut success) };
+        (success != 0).then_some(rhs)
+    }
+
+    /// Returns the weights of an SOS1 or SOS2 constraint, in the order of [`vars`](Constraint::vars).
+    /// Returns `None` if the constraint is not an SOS constraint or has no weights.
+    pub fn sos_weights(&self) -> Option<Vec<f64>> {
+        let (n_vars, weights) = unsafe {
+            if self.has_handler("SOS1") {
+                (
+                    ffi::SCIPgetNVarsSOS1(self.scip.raw, self.raw),
+                    ffi::SCIPgetWeightsSOS1(self.scip.raw, self.raw),
+                )
+            } else if self.has_handler("SOS2") {
+                (
+                    ffi::SCIPgetNVarsSOS2(self.scip.raw, self.raw),
+                    ffi::SCIPgetWeightsSOS2(self.scip.raw, self.raw),
+                )
+            } else {
+                return None;
+            }
+        };
+        if weights.is_null() {
+            return None;
+        }
+        Some(unsafe { std::slice::from_raw_parts(weights, n_vars as usize) }.to_vec())
+    }
+
+    /// Returns the maximal number of non-zero variables of a cardinality constraint.
+    /// Returns `None` if the constraint is not a cardinality constraint.
+    pub fn cardinality(&self) -> Option<usize> {
+        if !self.has_handler("cardinality") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetCardvalCardinality(self.scip.raw, self.raw) } as usize)
+    }
+
     /// Returns the modifiable flag of the constraint
     pub fn is_modifiable(&self) -> bool {
         self.scip.cons_is_modifiable(self)
@@ -92,6 +207,345 @@ impl Constraint {
         self.scip.cons_is_separated(self)
     }
 
+    /// Returns the expression of a nonlinear constraint.
+    /// Returns `None` if the constraint is not a nonlinear constraint or its expression uses an
+    /// expression handler that is not supported by [`Expr`].
+    pub fn nonlinear_expr(&self) -> Option<Expr> {
+        let expr = self.scip.cons_nonlinear_expr(self.raw)?;
+        Expr::from_raw(expr, &self.scip)
+    }
+
+    /// Returns the resultant variable of an AND or OR constraint.
+    /// Returns `None` if the constraint is neither an AND nor an OR constraint.
+    pub fn resultant(&self) -> Option<Variable> {
+        let raw = if self.has_handler("and") {
+            unsafe { ffi::SCIPgetResultantAnd(self.scip.raw, self.raw) }
+        } else if self.has_handler("or") {
+            unsafe { ffi::SCIPgetResultantOr(self.scip.raw, self.raw) }
+        } else {
+            return None;
+        };
+        Some(self.variable(raw))
+    }
+
+    /// Returns the operand variables of an AND, OR, XOR or logicor constraint.
+    /// Returns `None` if the constraint is none of these.
+    pub fn operands(&self) -> Option<Vec<Variable>> {
+        let (n_vars, vars) = unsafe {
+            if self.has_handler("and") {
+                (
+                    ffi::SCIPgetNVarsAnd(self.scip.raw, self.raw),
+                    ffi::SCIPgetVarsAnd(self.scip.raw, self.raw),
+                )
+            } else if self.has_handler("or") {
+                (
+                    ffi::SCIPgetNVarsOr(self.scip.raw, self.raw),
+                    ffi::SCIPgetVarsOr(self.scip.raw, self.raw),
+                )
+            } else if self.has_handler("xor") {
+                (
+                    ffi::SCIPgetNVarsXor(self.scip.raw, self.raw),
+                    ffi::SCIPgetVarsXor(self.scip.raw, self.raw),
+                )
+            } else if self.has_handler("logicor") {
+                (
+                    ffi::SCIPgetNVarsLogicor(self.scip.raw, self.raw),
+                    ffi::SCIPgetVarsLogicor(self.scip.raw, self.raw),
+                )
+            } else {
+                return None;
+            }
+        };
+        if n_vars == 0 {
+            return Some(Vec::new());
+        }
+        let vars = unsafe { std::slice::from_raw_parts(vars, n_vars as usize) };
+        Some(vars.iter().map(|&raw| self.variable(raw)).collect())
+    }
+
+    /// Returns the integer variable expressing the parity of an XOR constraint.
+    /// Returns `None` if the constraint is not an XOR constraint or SCIP did not create the integer
+    /// variable (yet), which happens when the LP relaxation of the constraint is built.
+    pub fn xor_int_var(&self) -> Option<Variable> {
+        if !self.has_handler("xor") {
+            return None;
+        }
+        let raw = unsafe { ffi::SCIPgetIntVarXor(self.scip.raw, self.raw) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(self.variable(raw))
+        }
+    }
+
+    /// Returns the right-hand side of an XOR constraint, `true` if the number of variables set
+    /// to one must be odd.
+    /// Returns `None` if the constraint is not an XOR constraint.
+    pub fn xor_rhs(&self) -> Option<bool> {
+        if !self.has_handler("xor") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetRhsXor(self.scip.raw, self.raw) } != 0)
+    }
+
+    /// Returns the left-hand side of a pseudoboolean constraint.
+    /// Returns `None` if the constraint is not a pseudoboolean constraint.
+    pub fn pseudoboolean_lhs(&self) -> Option<f64> {
+        if !self.has_handler("pseudoboolean") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetLhsPseudoboolean(self.scip.raw, self.raw) })
+    }
+
+    /// Returns the right-hand side of a pseudoboolean constraint.
+    /// Returns `None` if the constraint is not a pseudoboolean constraint.
+    pub fn pseudoboolean_rhs(&self) -> Option<f64> {
+        if !self.has_handler("pseudoboolean") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetRhsPseudoboolean(self.scip.raw, self.raw) })
+    }
+
+    /// Returns the linear terms of a pseudoboolean constraint, without its products of variables.
+    /// Returns `None` if the constraint is not a pseudoboolean constraint.
+    pub fn pseudoboolean_linear_terms(&self) -> Option<Vec<(Variable, f64)>> {
+        if !self.has_handler("pseudoboolean") {
+            return None;
+        }
+        let terms = self.scip.pseudoboolean_linear_terms(self.raw).ok()?;
+        Some(
+            terms
+                .into_iter()
+                .map(|(raw, coef)| (self.variable(raw), coef))
+                .collect(),
+        )
+    }
+
+    /// Returns the products of variables of a pseudoboolean constraint with their coefficients.
+    /// Returns `None` if the constraint is not a pseudoboolean constraint.
+    pub fn pseudoboolean_and_terms(&self) -> Option<Vec<(Vec<Variable>, f64)>> {
+        if !self.has_handler("pseudoboolean") {
+            return None;
+        }
+        let terms = self.scip.pseudoboolean_and_terms(self.raw).ok()?;
+        terms
+            .into_iter()
+            .map(|(raw, coef)| {
+                let and_cons = Constraint {
+                    raw,
+                    scip: self.scip.clone(),
+                };
+                Some((and_cons.operands()?, coef))
+            })
+            .collect()
+    }
+
+    /// Returns the capacity of a knapsack constraint.
+    /// Returns `None` if the constraint is not a knapsack constraint.
+    pub fn knapsack_capacity(&self) -> Option<i64> {
+        if !self.has_handler("knapsack") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetCapacityKnapsack(self.scip.raw, self.raw) })
+    }
+
+    /// Returns the items of a knapsack constraint, i.e. its variables with their weights.
+    /// Returns `None` if the constraint is not a knapsack constraint.
+    pub fn knapsack_items(&self) -> Option<Vec<(Variable, i64)>> {
+        if !self.has_handler("knapsack") {
+            return None;
+        }
+        let n_vars = unsafe { ffi::SCIPgetNVarsKnapsack(self.scip.raw, self.raw) } as usize;
+        if n_vars == 0 {
+            return Some(Vec::new());
+        }
+        let (vars, weights) = unsafe {
+            (
+                std::slice::from_raw_parts(
+                    ffi::SCIPgetVarsKnapsack(self.scip.raw, self.raw),
+                    n_vars,
+                ),
+                std::slice::from_raw_parts(
+                    ffi::SCIPgetWeightsKnapsack(self.scip.raw, self.raw),
+                    n_vars,
+                ),
+            )
+        };
+        Some(
+            vars.iter()
+                .zip(weights)
+                .map(|(&raw, &weight)| (self.variable(raw), weight))
+                .collect(),
+        )
+    }
+
+    /// Returns the variable `x` of a variable bound constraint `lhs <= x + c * y <= rhs`.
+    /// Returns `None` if the constraint is not a variable bound constraint.
+    pub fn varbound_var(&self) -> Option<Variable> {
+        if !self.has_handler("varbound") {
+            return None;
+        }
+        Some(self.variable(unsafe { ffi::SCIPgetVarVarbound(self.scip.raw, self.raw) }))
+    }
+
+    /// Returns the bounding variable `y` of a variable bound constraint `lhs <= x + c * y <= rhs`.
+    /// Returns `None` if the constraint is not a variable bound constraint.
+    pub fn varbound_vbd_var(&self) -> Option<Variable> {
+        if !self.has_handler("varbound") {
+            return None;
+        }
+        Some(self.variable(unsafe { ffi::SCIPgetVbdvarVarbound(self.scip.raw, self.raw) }))
+    }
+
+    /// Returns the coefficient `c` of a variable bound constraint `lhs <= x + c * y <= rhs`.
+    /// Returns `None` if the constraint is not a variable bound constraint.
+    pub fn varbound_coef(&self) -> Option<f64> {
+        if !self.has_handler("varbound") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetVbdcoefVarbound(self.scip.raw, self.raw) })
+    }
+
+    /// Returns the left-hand side of a variable bound constraint.
+    /// Returns `None` if the constraint is not a variable bound constraint.
+    pub fn varbound_lhs(&self) -> Option<f64> {
+        if !self.has_handler("varbound") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetLhsVarbound(self.scip.raw, self.raw) })
+    }
+
+    /// Returns the right-hand side of a variable bound constraint.
+    /// Returns `None` if the constraint is not a variable bound constraint.
+    pub fn varbound_rhs(&self) -> Option<f64> {
+        if !self.has_handler("varbound") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetRhsVarbound(self.scip.raw, self.raw) })
+    }
+
+    /// Returns the bound literals of a bound disjunction constraint, `(var, BoundType::Lower, b)`
+    /// stands for `var >= b` and `(var, BoundType::Upper, b)` for `var <= b`.
+    /// Returns `None` if the constraint is not a bound disjunction constraint.
+    pub fn bounddisjunction_literals(&self) -> Option<Vec<(Variable, BoundType, f64)>> {
+        if !self.has_handler("bounddisjunction") {
+            return None;
+        }
+        let n_vars = unsafe { ffi::SCIPgetNVarsBounddisjunction(self.scip.raw, self.raw) } as usize;
+        if n_vars == 0 {
+            return Some(Vec::new());
+        }
+        let (vars, bound_types, bounds) = unsafe {
+            (
+                std::slice::from_raw_parts(
+                    ffi::SCIPgetVarsBounddisjunction(self.scip.raw, self.raw),
+                    n_vars,
+                ),
+                std::slice::from_raw_parts(
+                    ffi::SCIPgetBoundtypesBounddisjunction(self.scip.raw, self.raw),
+                    n_vars,
+                ),
+                std::slice::from_raw_parts(
+                    ffi::SCIPgetBoundsBounddisjunction(self.scip.raw, self.raw),
+                    n_vars,
+                ),
+            )
+        };
+        Some(
+            (0..n_vars)
+                .map(|i| (self.variable(vars[i]), bound_types[i].into(), bounds[i]))
+                .collect(),
+        )
+    }
+
+    /// Returns the linked integer or continuous variable of a linking constraint.
+    /// Returns `None` if the constraint is not a linking constraint.
+    pub fn linking_var(&self) -> Option<Variable> {
+        if !self.has_handler("linking") {
+            return None;
+        }
+        Some(self.variable(unsafe { ffi::SCIPgetLinkvarLinking(self.scip.raw, self.raw) }))
+    }
+
+    /// Returns the binary variables of a linking constraint with the values they select.
+    /// Returns `None` if the constraint is not a linking constraint.
+    pub fn linking_terms(&self) -> Option<Vec<(Variable, f64)>> {
+        if !self.has_handler("linking") {
+            return None;
+        }
+        let terms = self.scip.linking_terms(self.raw).ok()?;
+        Some(
+            terms
+                .into_iter()
+                .map(|(raw, val)| (self.variable(raw), val))
+                .collect(),
+        )
+    }
+
+    /// Returns the binary variable of an indicator constraint, as given when creating it, i.e.
+    /// not negated if the constraint is active on zero.
+    /// Returns `None` if the constraint is not an indicator constraint.
+    pub fn indicator_binary_var(&self) -> Option<Variable> {
+        if !self.has_handler("indicator") {
+            return None;
+        }
+        Some(self.variable(unsafe { ffi::SCIPgetBinaryVarIndicatorGeneric(self.raw) }))
+    }
+
+    /// Returns the value of the binary variable on which an indicator constraint is active.
+    /// Returns `None` if the constraint is not an indicator constraint.
+    pub fn indicator_active_on(&self) -> Option<bool> {
+        if !self.has_handler("indicator") {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetActiveOnIndicator(self.raw) } != 0)
+    }
+
+    /// Returns the slack variable of an indicator constraint, which SCIP adds to its linear
+    /// constraint and fixes to zero when the indicator is active.
+    /// Returns `None` if the constraint is not an indicator constraint.
+    pub fn indicator_slack_var(&self) -> Option<Variable> {
+        if !self.has_handler("indicator") {
+            return None;
+        }
+        let raw = unsafe { ffi::SCIPgetSlackVarIndicator(self.raw) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(self.variable(raw))
+        }
+    }
+
+    /// Returns the linear constraint of an indicator constraint, which includes the slack variable.
+    /// Returns `None` if the constraint is not an indicator constraint.
+    pub fn indicator_linear_cons(&self) -> Option<Constraint> {
+        if !self.has_handler("indicator") {
+            return None;
+        }
+        let raw = unsafe { ffi::SCIPgetLinearConsIndicator(self.raw) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(Constraint {
+                raw,
+                scip: self.scip.clone(),
+            })
+        }
+    }
+
+    /// Returns whether the constraint belongs to the constraint handler with the given name.
+    fn has_handler(&self, name: &str) -> bool {
+        self.handler_name() == name
+    }
+
+    /// Wraps a variable of the model the constraint belongs to.
+    fn variable(&self, raw: *mut ffi::SCIP_VAR) -> Variable {
+        Variable {
+            raw,
+            scip: self.scip.clone(),
+        }
+    }
+
     /// Returns the corresponding transformed constraint.
     /// Returns `None` if the transformed constraint does not exist (yet).
     pub fn transformed(&self) -> Option<Constraint> {
@@ -106,6 +560,73 @@ impl Constraint {
     }
 }
 
+/// The kind of a constraint, determined by its constraint handler. Kind-specific data is
+/// available with the corresponding methods of [`Constraint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsKind {
+    /// A linear constraint, see [`Constraint::vars`], [`Constraint::coefs`], [`Constraint::lhs`]
+    /// and [`Constraint::rhs`].
+    Linear,
+    /// A set partitioning, packing or covering constraint.
+    SetPPC(SetPPCType),
+    /// A knapsack constraint, see [`Constraint::knapsack_items`] and
+    /// [`Constraint::knapsack_capacity`].
+    Knapsack,
+    /// A variable bound constraint, see [`Constraint::varbound_var`] and related methods.
+    Varbound,
+    /// A logicor constraint, see [`Constraint::operands`].
+    Logicor,
+    /// An AND constraint, see [`Constraint::resultant`] and [`Constraint::operands`].
+    And,
+    /// An OR constraint, see [`Constraint::resultant`] and [`Constraint::operands`].
+    Or,
+    /// An XOR constraint, see [`Constraint::xor_rhs`] and [`Constraint::xor_int_var`].
+    Xor,
+    /// A bound disjunction constraint, see [`Constraint::bounddisjunction_literals`].
+    BoundDisjunction,
+    /// A linking constraint, see [`Constraint::linking_var`] and [`Constraint::linking_terms`].
+    Linking,
+    /// A pseudoboolean constraint, see [`Constraint::pseudoboolean_linear_terms`] and
+    /// [`Constraint::pseudoboolean_and_terms`].
+    PseudoBoolean,
+    /// A nonlinear constraint whose expression is quadratic, see [`Constraint::nonlinear_expr`].
+    Quadratic,
+    /// A nonlinear constraint, see [`Constraint::nonlinear_expr`].
+    Nonlinear,
+    /// An indicator constraint, see [`Constraint::indicator_binary_var`] and related methods.
+    Indicator,
+    /// An SOS1 constraint, see [`Constraint::sos_weights`].
+    SOS1,
+    /// An SOS2 constraint, see [`Constraint::sos_weights`].
+    SOS2,
+    /// A cardinality constraint, see [`Constraint::cardinality`].
+    Cardinality,
+    /// A constraint of another constraint handler, with the name of the handler.
+    Other(String),
+}
+
+/// The type of a set partitioning, packing or covering constraint.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SetPPCType {
+    /// A set partitioning constraint `sum(x) = 1`.
+    Partitioning,
+    /// A set packing constraint `sum(x) <= 1`.
+    Packing,
+    /// A set covering constraint `sum(x) >= 1`.
+    Covering,
+}
+
+impl From<ffi::SCIP_SETPPCTYPE> for SetPPCType {
+    fn from(setppc_type: ffi::SCIP_SETPPCTYPE) -> Self {
+        match setppc_type {
+            ffi::SCIP_SetppcType_SCIP_SETPPCTYPE_PARTITIONING => SetPPCType::Partitioning,
+            ffi::SCIP_SetppcType_SCIP_SETPPCTYPE_PACKING => SetPPCType::Packing,
+            ffi::SCIP_SetppcType_SCIP_SETPPCTYPE_COVERING => SetPPCType::Covering,
+            _ => panic!("Unknown SetPPCType {:?}", setppc_type),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{minimal_model, prelude::*};
@@ -154,4 +675,82 @@ mod tests {
         let dual = cons.transformed().unwrap().dual_sol().unwrap();
         assert!(dual + 10.0 < f64::EPSILON);
     }
+
+    #[test]
+    fn test_constraint_structure() {
+        let mut model = minimal_model().hide_output();
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let y = model.add_var(0., 10., 1., "y", VarType::Continuous);
+        let b1 = model.add_var(0., 1., 1., "b1", VarType::Binary);
+        let b2 = model.add_var(0., 1., 1., "b2", VarType::Binary);
+
+        let linear = model.add_cons(vec![&x, &y], &[2., 3.], 1., 5., "linear");
+        assert_eq!(linear.handler_name(), "linear");
+        assert_eq!(linear.kind(), ConsKind::Linear);
+        assert_eq!(linear.vars(), Some(vec![x.clone(), y.clone()]));
+        assert_eq!(linear.coefs(), Some(vec![2., 3.]));
+        assert_eq!(linear.lhs(), Some(1.));
+        assert_eq!(linear.rhs(), Some(5.));
+
+        let set_part = model.add_cons_set_part(vec![&b1, &b2], "set_part");
+        assert_eq!(set_part.kind(), ConsKind::SetPPC(SetPPCType::Partitioning));
+        assert_eq!(set_part.coefs(), Some(vec![1., 1.]));
+
+        let knapsack = model.add_cons_knapsack(vec![&b1, &b2], &[3, 4], 5, "knapsack");
+        assert_eq!(knapsack.kind(), ConsKind::Knapsack);
+        assert_eq!(knapsack.rhs(), Some(5.));
+
+        let quadratic = model.add_cons_quadratic(
+            vec![],
+            &mut [],
+            vec![&x],
+            vec![&y],
+            &mut [1.],
+            0.,
+            4.,
+            "quadratic",
+        );
+        assert_eq!(quadratic.kind(), ConsKind::Quadratic);
+
+        let nonlinear = model.add_cons_nonlinear(&Expr::var(&x).exp(), 1., 2., "nonlinear");
+        assert_eq!(nonlinear.kind(), ConsKind::Nonlinear);
+        assert_eq!(nonlinear.lhs(), Some(1.));
+        assert_eq!(nonlinear.rhs(), Some(2.));
+        assert_eq!(nonlinear.coefs(), None);
+
+        let sos1 = model.add_cons_sos1(vec![&x, &y], Some(&[1., 2.]), "sos1");
+        assert_eq!(sos1.kind(), ConsKind::SOS1);
+        assert_eq!(sos1.sos_weights(), Some(vec![1., 2.]));
+        assert_eq!(sos1.lhs(), None);
+        assert_eq!(linear.sos_weights(), None);
+
+        let cardinality = model.add_cons_cardinality(vec![&x, &y], 1, "cardinality");
+        assert_eq!(cardinality.kind(), ConsKind::Cardinality);
+        assert_eq!(cardinality.cardinality(), Some(1));
+        assert_eq!(linear.cardinality(), None);
+
+        let indicator = model.add(indicator(&b1).coef(&x, 1.).le(3.));
+        assert_eq!(indicator[0].kind(), ConsKind::Indicator);
+    }
+
+    #[test]
+    fn test_constraint_structure_after_read_prob() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        let conss = model.conss();
+        assert_eq!(conss.len(), 2);
+        assert!(conss.iter().all(|c| c.kind() == ConsKind::Linear));
+
+        let c1 = model.find_cons("c1").unwrap();
+        assert_eq!(c1.handler_name(), "linear");
+        assert_eq!(c1.vars().unwrap().len(), 2);
+        assert_eq!(c1.coefs(), Some(vec![2., 1.]));
+        assert_eq!(c1.rhs(), Some(100.));
+        let infinity = unsafe { crate::ffi::SCIPinfinity(model.scip.raw) };
+        assert!(c1.lhs().unwrap() <= -infinity);
+    }
 }
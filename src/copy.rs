@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use crate::{Constraint, Variable, ffi};
+
+/// Options for copying a model with
+/// [`ModelWithProblem::try_copy_with`](crate::ModelWithProblem::try_copy_with).
+///
+/// By default the plugins of the model are copied, the transformed problem is copied with its
+/// global bounds if the solving process started, and no variables are fixed.
+#[derive(Debug, Clone)]
+pub struct CopyOptions {
+    pub(crate) name: Option<String>,
+    pub(crate) original: bool,
+    pub(crate) global: bool,
+    pub(crate) copy_plugins: bool,
+    pub(crate) fixings: Vec<(Variable, f64)>,
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        CopyOptions {
+            name: None,
+            original: false,
+            global: true,
+            copy_plugins: true,
+            fixings: Vec::new(),
+        }
+    }
+}
+
+impl CopyOptions {
+    /// Creates the default copy options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the copied problem, defaults to the name of the source problem.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets whether the original problem is copied instead of the transformed one.
+    ///
+    /// Before the solving process started only the original problem exists, so it is always
+    /// copied then.
+    pub fn original(mut self, original: bool) -> Self {
+        self.original = original;
+        self
+    }
+
+    /// Sets whether the global bounds of the transformed problem are copied, otherwise the
+    /// local bounds of the current node are used. Ignored when the original problem is copied.
+    pub fn global(mut self, global: bool) -> Self {
+        self.global = global;
+        self
+    }
+
+    /// Sets whether the plugins of the model are copied. Otherwise SCIP's default plugins are
+    /// included in the copy, constraints of other constraint handlers are skipped then.
+    ///
+    /// Custom plugins written in Rust cannot be copied by SCIP and are never part of a copy.
+    pub fn copy_plugins(mut self, copy_plugins: bool) -> Self {
+        self.copy_plugins = copy_plugins;
+        self
+    }
+
+    /// Fixes a variable to the given value in the copy, e.g. to restrict a sub-MIP to the
+    /// neighborhood of a solution. The copied variable keeps the value as both of its bounds.
+    ///
+    /// The variable belongs to the source model. When the transformed problem is copied, an
+    /// original variable is replaced by its transformed counterpart.
+    pub fn fix(mut self, var: &Variable, val: f64) -> Self {
+        self.fixings.push((var.clone(), val));
+        self
+    }
+}
+
+/// The mapping from the variables and constraints of a model to the ones of its copy, returned
+/// by [`ModelWithProblem::try_copy`](crate::ModelWithProblem::try_copy).
+#[derive(Debug)]
+pub struct CopyMap {
+    pub(crate) vars: HashMap<*mut ffi::SCIP_VAR, Variable>,
+    pub(crate) conss: HashMap<*mut ffi::SCIP_CONS, Constraint>,
+    pub(crate) valid: bool,
+}
+
+impl CopyMap {
+    /// Returns the copy of the given variable of the source model, or `None` if it was not
+    /// copied.
+    pub fn var(&self, source: &Variable) -> Option<Variable> {
+        self.vars.get(&source.raw).cloned()
+    }
+
+    /// Returns the copy of the given constraint of the source model, or `None` if it was not
+    /// copied.
+    pub fn cons(&self, source: &Constraint) -> Option<Constraint> {
+        self.conss.get(&source.raw).cloned()
+    }
+
+    /// Returns whether the copy is a complete copy of the problem. This is not the case if
+    /// constraints could not be copied, e.g. because their constraint handler does not
+    /// support copying or was not included in the copy.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ModelWithProblem, Solving, minimal_model};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn copy_and_solve_scenario() {
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add(var().name("x").int(0..=10).obj(1.0));
+        let y = model.add(var().name("y").int(0..=10).obj(2.0));
+        let c = model.add(cons().name("c").coef(&x, 1.0).coef(&y, 1.0).le(6.0));
+
+        let (mut scenario, map) = model.try_copy().unwrap();
+        assert!(map.is_valid());
+        assert_eq!(scenario.n_vars(), 2);
+        assert_eq!(scenario.n_conss(), 1);
+        assert_eq!(map.var(&y).unwrap().name(), "y");
+        assert_eq!(map.cons(&c).unwrap().name(), "c");
+
+        // a scenario variant restricting `y`
+        let y_copy = map.var(&y).unwrap();
+        scenario.add_cons(vec![&y_copy], &[1.0], -f64::INFINITY, 2.0, "y_limit");
+        let scenario = scenario.hide_output().solve();
+        assert_eq!(scenario.status(), Status::Optimal);
+        assert!((scenario.obj_val() - 8.0).abs() < 1e-6);
+
+        // the base model is untouched
+        let base = model.solve();
+        assert_eq!(base.status(), Status::Optimal);
+        assert!((base.obj_val() - 12.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn copy_with_fixings() {
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add(var().int(0..=10).obj(1.0));
+        let y = model.add(var().int(0..=10).obj(2.0));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).le(6.0));
+
+        let (sub, map) = model
+            .try_copy_with(CopyOptions::new().name("sub").fix(&y, 1.0))
+            .unwrap();
+        let y_copy = map.var(&y).unwrap();
+        assert_eq!(y_copy.lb(), 1.0);
+        assert_eq!(y_copy.ub(), 1.0);
+        assert!(map.var(&x).is_some());
+
+        let sub = sub.hide_output().solve();
+        assert_eq!(sub.status(), Status::Optimal);
+        assert!((sub.obj_val() - 7.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn drop_copy_and_source_in_both_orders() {
+        for drop_source_first in [true, false] {
+            let mut model = minimal_model().hide_output().maximize();
+            let x = model.add(var().name("x").int(0..=10).obj(1.0));
+            model.add(cons().name("c").coef(&x, 1.0).le(6.0));
+
+            let (copy, map) = model.try_copy().unwrap();
+            let x_copy = map.var(&x).unwrap();
+            if drop_source_first {
+                drop(model);
+                drop(x);
+                let copy = copy.hide_output().solve();
+                assert!((copy.obj_val() - 6.0).abs() < 1e-6);
+                drop(copy);
+                assert_eq!(x_copy.name(), "x");
+            } else {
+                drop(copy);
+                drop(map);
+                assert_eq!(x_copy.name(), "x");
+                let model = model.solve();
+                assert!((model.obj_val() - 6.0).abs() < 1e-6);
+                drop(model);
+                assert_eq!(x.name(), "x");
+            }
+        }
+    }
+
+    /// Solves a copy of the transformed problem as a sub-MIP and checks its objective.
+    struct SubMipHeur {
+        obj_vals: Rc<Cell<Option<f64>>>,
+    }
+
+    impl Heuristic for SubMipHeur {
+        fn execute(
+            &mut self,
+            model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            if self.obj_vals.get().is_some() {
+                return HeurResult::DidNotRun;
+            }
+            let (sub, map) = model.try_copy().unwrap();
+            assert!(map.is_valid());
+            let sub = sub
+                .hide_output()
+                .set_longint_param("limits/nodes", 100)
+                .unwrap()
+                .solve();
+            self.obj_vals.set(Some(sub.obj_val()));
+            HeurResult::NoSolFound
+        }
+    }
+
+    #[test]
+    fn copy_inside_heuristic() {
+        let obj_vals = Rc::new(Cell::new(None));
+
+        let mut model = minimal_model().hide_output().minimize();
+        let x = model.add(var().int(0..=10).obj(1.0));
+        let y = model.add(var().int(0..=10).obj(2.0));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).ge(4.0));
+        model.add(
+            heur(SubMipHeur {
+                obj_vals: obj_vals.clone(),
+            })
+            .name("sub_mip")
+            .timing(HeurTiming::BEFORE_NODE),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((obj_vals.get().unwrap() - 4.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Model, Row, Solving};
+
+/// A trait for defining custom cut selectors.
+///
+/// Cut selectors decide which of the cuts found in a separation round are added to the LP, and
+/// in which order. SCIP calls the cut selector with the highest priority first, the default
+/// `hybrid` cut selector has a priority of 8000.
+pub trait CutSelector {
+    /// Selects the cuts to add to the LP.
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance in `Solving` stage.
+    /// * `cuts` - the cuts to select from.
+    /// * `forced` - the cuts that are added to the LP in any case, e.g. to account for
+    ///   parallelism with them.
+    /// * `root` - whether the cuts are selected at the root node.
+    /// * `max_cuts` - the maximal number of cuts to select.
+    ///
+    /// # Returns
+    /// * The indices into `cuts` of the selected cuts, in the order they should be added to the
+    ///   LP. Only the first `max_cuts` indices are taken into account. An index that is out of
+    ///   range of `cuts` is an error and aborts the solve with [`Retcode::InvalidData`].
+    ///
+    /// [`Retcode::InvalidData`]: crate::Retcode::InvalidData
+    fn select(
+        &mut self,
+        model: Model<Solving>,
+        cuts: &[Row],
+        forced: &[Row],
+        root: bool,
+        max_cuts: usize,
+    ) -> Vec<usize>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::row::RowSource;
+    use crate::prelude::*;
+    use crate::{SCIPSeparator, minimal_model};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Adds a strong and a weak cut for `2x + 2y <= 3` once.
+    struct TwoCutsSeparator {
+        added: bool,
+    }
+
+    impl Separator for TwoCutsSeparator {
+        fn execute_lp(
+            &mut self,
+            mut model: Model<Solving>,
+            sepa: SCIPSeparator,
+        ) -> SeparationResult {
+            if self.added {
+                return SeparationResult::DidNotRun;
+            }
+            self.added = true;
+
+            let vars = model.vars();
+            for (name, rhs) in [("weak", 1.4), ("strong", 1.0)] {
+                let mut cut =
+                    model.add(row().name(name).le(rhs).source(RowSource::Separator(&sepa)));
+                for var in &vars {
+                    cut.set_coeff(var, 1.0);
+                }
+                model.add_cut(cut, false);
+            }
+            SeparationResult::Separated
+        }
+    }
+
+    /// Selects the single most efficacious cut.
+    struct MostEfficacious {
+        selected: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl CutSelector for MostEfficacious {
+        fn select(
+            &mut self,
+            _model: Model<Solving>,
+            cuts: &[Row],
+            forced: &[Row],
+            _root: bool,
+            max_cuts: usize,
+        ) -> Vec<usize> {
+            assert!(forced.is_empty());
+            assert!(max_cuts > 0);
+            let best = (0..cuts.len())
+                .max_by(|&i, &j| cuts[i].efficacy().total_cmp(&cuts[j].efficacy()))
+                .unwrap();
+            self.selected.borrow_mut().push(cuts[best].name());
+            vec![best]
+        }
+    }
+
+    #[test]
+    fn cut_selector_picks_most_efficacious() {
+        let selected = Rc::new(RefCell::new(vec![]));
+
+        let mut model = minimal_model().maximize();
+        let x = model.add(var().bin().obj(1.0));
+        let y = model.add(var().bin().obj(1.0));
+        model.add(cons().coef(&x, 2.0).coef(&y, 2.0).le(3.0));
+        model.add(sepa(TwoCutsSeparator { added: false }).name("two_cuts"));
+        model.add(
+            cutsel(MostEfficacious {
+                selected: selected.clone(),
+            })
+            .name("most_efficacious")
+            .priority(1000000),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 1.0).abs() < 1e-6);
+        assert_eq!(
+            selected.borrow().first().map(String::as_str),
+            Some("strong")
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use std::ffi::CStr;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::rc::Rc;
+
+use crate::scip::ScipPtr;
+use crate::{LinExpr, Variable, ffi};
+
+/// A nonlinear expression tree, mapping to SCIP's expression handlers.
+///
+/// Expressions are built from variables and numbers with the `+`, `-` and `*` operators and the
+/// functions [`pow`](Expr::pow), [`exp`](Expr::exp), [`log`](Expr::log), etc., e.g.
+/// `Expr::from(&x).exp() + 2.0 * Expr::from(&y).log()`. They are added to the model as
+/// nonlinear constraints with
+/// [`ProblemOrSolving::add_cons_nonlinear`](crate::ProblemOrSolving::add_cons_nonlinear).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// A variable.
+    Var(Variable),
+    /// A constant value.
+    Const(f64),
+    /// The weighted sum `sum(coef * expr) + constant`.
+    Sum {
+        /// The coefficients and the summands.
+        terms: Vec<(f64, Expr)>,
+        /// The constant of the sum.
+        constant: f64,
+    },
+    /// The product `coef * prod(factors)`.
+    Product {
+        /// The coefficient of the product.
+        coef: f64,
+        /// The factors of the product.
+        factors: Vec<Expr>,
+    },
+    /// The power `expr^exponent`.
+    Pow(Box<Expr>, f64),
+    /// The signed power `sign(expr) * |expr|^exponent`, the exponent must be at least 1.
+    SignPower(Box<Expr>, f64),
+    /// The exponential function `exp(expr)`.
+    Exp(Box<Expr>),
+    /// The natural logarithm `log(expr)`.
+    Log(Box<Expr>),
+    /// The absolute value `|expr|`.
+    Abs(Box<Expr>),
+    /// The sine function `sin(expr)`.
+    Sin(Box<Expr>),
+    /// The cosine function `cos(expr)`.
+    Cos(Box<Expr>),
+    /// The entropy function `-expr * log(expr)`.
+    Entropy(Box<Expr>),
+}
+
+impl Expr {
+    /// Creates an expression for the given variable.
+    pub fn var(var: &Variable) -> Self {
+        Expr::Var(var.clone())
+    }
+
+    /// Creates a constant expression.
+    pub fn constant(val: f64) -> Self {
+        Expr::Const(val)
+    }
+
+    /// Returns the expression raised to the given power.
+    pub fn pow(self, exponent: f64) -> Self {
+        Expr::Pow(Box::new(self), exponent)
+    }
+
+    /// Returns the signed power `sign(self) * |self|^exponent`, the exponent must be at least 1.
+    pub fn signpower(self, exponent: f64) -> Self {
+        Expr::SignPower(Box::new(self), exponent)
+    }
+
+    /// Returns the exponential of the expression.
+    pub fn exp(self) -> Self {
+        Expr::Exp(Box::new(self))
+    }
+
+    /// Returns the natural logarithm of the expression.
+    pub fn log(self) -> Self {
+        Expr::Log(Box::new(self))
+    }
+
+    /// Returns the absolute value of the expression.
+    pub fn abs(self) -> Self {
+        Expr::Abs(Box::new(self))
+    }
+
+    /// Returns the sine of the expression.
+    pub fn sin(self) -> Self {
+        Expr::Sin(Box::new(self))
+    }
+
+    /// Returns the cosine of the expression.
+    pub fn cos(self) -> Self {
+        Expr::Cos(Box::new(self))
+    }
+
+    /// Returns the entropy `-self * log(self)` of the expression.
+    pub fn entropy(self) -> Self {
+        Expr::Entropy(Box::new(self))
+    }
+
+    /// Returns the direct children of the expression.
+    pub fn children(&self) -> Vec<&Expr> {
+        match self {
+            Expr::Var(_) | Expr::Const(_) => Vec::new(),
+            Expr::Sum { terms, .. } => terms.iter().map(|(_, expr)| expr).collect(),
+            Expr::Product { factors, .. } => factors.iter().collect(),
+            Expr::Pow(child, _)
+            | Expr::SignPower(child, _)
+            | Expr::Exp(child)
+            | Expr::Log(child)
+            | Expr::Abs(child)
+            | Expr::Sin(child)
+            | Expr::Cos(child)
+            | Expr::Entropy(child) => vec![child],
+        }
+    }
+
+    /// Converts a SCIP expression into an expression tree, returns `None` if the expression
+    /// contains an expression handler that is not supported.
+    pub(crate) fn from_raw(raw: *mut ffi::SCIP_EXPR, scip: &Rc<ScipPtr>) -> Option<Expr> {
+        let hdlr = unsafe { ffi::SCIPexprGetHdlr(raw) };
+        let name = unsafe { CStr::from_ptr(ffi::SCIPexprhdlrGetName(hdlr)) };
+        let n_children = unsafe { ffi::SCIPexprGetNChildren(raw) } as usize;
+        let children = if n_children == 0 {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(ffi::SCIPexprGetChildren(raw), n_children) }
+                .iter()
+                .map(|&child| Expr::from_raw(child, scip))
+                .collect::<Option<Vec<_>>>()?
+        };
+        let child = || Box::new(children[0].clone());
+
+        let expr = match name.to_str().unwrap() {
+            "var" => Expr::Var(Variable {
+                raw: unsafe { ffi::SCIPgetVarExprVar(raw) },
+                scip: scip.clone(),
+            }),
+            "val" => Expr::Const(unsafe { ffi::SCIPgetValueExprValue(raw) }),
+            "sum" => {
+                let coefs = if n_children == 0 {
+                    &[]
+                } else {
+                    unsafe { std::slice::from_raw_parts(ffi::SCIPgetCoefsExprSum(raw), n_children) }
+                };
+                Expr::Sum {
+                    terms: coefs.iter().copied().zip(children.clone()).collect(),
+                    constant: unsafe { ffi::SCIPgetConstantExprSum(raw) },
+                }
+            }
+            "prod" => Expr::Product {
+                coef: unsafe { ffi::SCIPgetCoefExprProduct(raw) },
+                factors: children.clone(),
+            },
+            "pow" => Expr::Pow(child(), unsafe { ffi::SCIPgetExponentExprPow(raw) }),
+            "signpower" => Expr::SignPower(child(), unsafe { ffi::SCIPgetExponentExprPow(raw) }),
+            "exp" => Expr::Exp(child()),
+            "log" => Expr::Log(child()),
+            "abs" => Expr::Abs(child()),
+            "sin" => Expr::Sin(child()),
+            "cos" => Expr::Cos(child()),
+            "entropy" => Expr::Entropy(child()),
+            _ => return None,
+        };
+        Some(expr)
+    }
+
+    /// Multiplies the expression with a constant, keeping sums and products flat.
+    fn scale(self, factor: f64) -> Self {
+        match self {
+            Expr::Const(val) => Expr::Const(val * factor),
+            Expr::Sum { terms, constant } => Expr::Sum {
+                terms: terms
+                    .into_iter()
+                    .map(|(coef, expr)| (coef * factor, expr))
+                    .collect(),
+                constant: constant * factor,
+            },
+            Expr::Product { coef, factors } => Expr::Product {
+                coef: coef * factor,
+                factors,
+            },
+            expr => Expr::Sum {
+                terms: vec![(factor, expr)],
+                constant: 0.0,
+            },
+        }
+    }
+}
+
+impl From<&Variable> for Expr {
+    fn from(var: &Variable) -> Self {
+        Expr::var(var)
+    }
+}
+
+impl From<f64> for Expr {
+    fn from(val: f64) -> Self {
+        Expr::Const(val)
+    }
+}
+
+impl From<LinExpr<'_>> for Expr {
+    fn from(expr: LinExpr<'_>) -> Self {
+        Expr::Sum {
+            terms: expr
+                .terms
+                .into_iter()
+                .map(|(var, coef)| (coef, Expr::var(var)))
+                .collect(),
+            constant: expr.constant,
+        }
+    }
+}
+
+impl<T: Into<Expr>> Add<T> for Expr {
+    type Output = Expr;
+
+    fn add(self, rhs: T) -> Self::Output {
+        let (mut terms, mut constant) = match self {
+            Expr::Sum { terms, constant } => (terms, constant),
+            Expr::Const(val) => (Vec::new(), val),
+            expr => (vec![(1.0, expr)], 0.0),
+        };
+        match rhs.into() {
+            Expr::Sum {
+                terms: rhs_terms,
+                constant: rhs_constant,
+            } => {
+                terms.extend(rhs_terms);
+                constant += rhs_constant;
+            }
+            Expr::Const(val) => constant += val,
+            expr => terms.push((1.0, expr)),
+        }
+        Expr::Sum { terms, constant }
+    }
+}
+
+impl<T: Into<Expr>> Sub<T> for Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        self + -rhs.into()
+    }
+}
+
+impl<T: Into<Expr>> Mul<T> for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        match (self, rhs.into()) {
+            (Expr::Const(val), expr) | (expr, Expr::Const(val)) => expr.scale(val),
+            (lhs, rhs) => {
+                let (mut coef, mut factors) = match lhs {
+                    Expr::Product { coef, factors } => (coef, factors),
+                    expr => (1.0, vec![expr]),
+                };
+                match rhs {
+                    Expr::Product {
+                        coef: rhs_coef,
+                        factors: rhs_factors,
+                    } => {
+                        coef *= rhs_coef;
+                        factors.extend(rhs_factors);
+                    }
+                    expr => factors.push(expr),
+                }
+                Expr::Product { coef, factors }
+            }
+        }
+    }
+}
+
+impl Neg for Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Self::Output {
+        self.scale(-1.0)
+    }
+}
+
+impl Add<Expr> for f64 {
+    type Output = Expr;
+
+    fn add(self, rhs: Expr) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub<Expr> for f64 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl Mul<Expr> for f64 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        rhs.scale(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ModelWithProblem, Solving, minimal_model};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn build_expressions() {
+        let mut model = minimal_model().hide_output();
+        let x = model.add(var().cont(0.0..=1.0));
+        let y = model.add(var().cont(0.0..=1.0));
+
+        let expr = 2.0 * Expr::from(&x).exp() - 1.0 + Expr::from(&y).log() * 3.0;
+        assert_eq!(
+            expr,
+            Expr::Sum {
+                terms: vec![(2.0, Expr::var(&x).exp()), (3.0, Expr::var(&y).log())],
+                constant: -1.0,
+            }
+        );
+
+        let expr = Expr::from(&x) * Expr::from(&y).pow(2.0) * 4.0 * Expr::from(&x);
+        assert_eq!(
+            expr,
+            Expr::Product {
+                coef: 4.0,
+                factors: vec![Expr::var(&x), Expr::var(&y).pow(2.0), Expr::var(&x)],
+            }
+        );
+
+        let expr = Expr::from(&x + 2.0 * &y - 1.0);
+        assert_eq!(expr.children(), vec![&Expr::var(&x), &Expr::var(&y)]);
+    }
+
+    #[test]
+    fn blending_with_exp_and_log() {
+        let mut model = minimal_model().hide_output().minimize();
+        let x = model.add(var().name("x").cont(0.0..=3.0).obj(1.0));
+        let y = model.add(var().name("y").cont(1.0..=10.0).obj(1.0));
+
+        let exp_cons =
+            model.add_cons_nonlinear(&Expr::from(&x).exp(), 4.0, f64::INFINITY, "exp_cons");
+        let log_expr = 2.0 * Expr::from(&y).log() - 1.0;
+        model.add_cons_nonlinear(&log_expr, 1.0, f64::INFINITY, "log_cons");
+        let prod_expr = Expr::from(&x) * Expr::from(&y).pow(2.0) + Expr::from(&y).abs();
+        let prod_cons = model.add_cons_nonlinear(&prod_expr, f64::NEG_INFINITY, 100.0, "prod_cons");
+        assert_eq!(model.n_conss(), 3);
+
+        assert_eq!(exp_cons.nonlinear_expr(), Some(Expr::var(&x).exp()));
+        assert_eq!(prod_cons.nonlinear_expr(), Some(prod_expr));
+        let linear = model.add(cons().coef(&x, 1.0).le(3.0));
+        assert_eq!(linear.nonlinear_expr(), None);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        // x = log(4) and y = e
+        let expected = 4.0f64.ln() + 1.0f64.exp();
+        assert!((solved.obj_val() - expected).abs() < 1e-4);
+    }
+
+    /// Adds a redundant nonlinear constraint during solving.
+    struct NonlinearHeur {
+        added: Rc<Cell<bool>>,
+    }
+
+    impl Heuristic for NonlinearHeur {
+        fn execute(
+            &mut self,
+            mut model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            if !self.added.get() {
+                let x = model.vars().into_iter().next().unwrap();
+                let cons = model.add_cons_nonlinear(
+                    &Expr::var(&x).pow(2.0),
+                    f64::NEG_INFINITY,
+                    100.0,
+                    "in_solving",
+                );
+                assert_eq!(cons.name(), "in_solving");
+                self.added.set(true);
+            }
+            HeurResult::DidNotRun
+        }
+    }
+
+    #[test]
+    fn nonlinear_cons_added_in_solving() {
+        let added = Rc::new(Cell::new(false));
+
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add(var().int(0..=10).obj(1.0));
+        let y = model.add(var().int(0..=10).obj(2.0));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).le(6.0));
+        model.add(
+            heur(NonlinearHeur {
+                added: added.clone(),
+            })
+            .timing(HeurTiming::BEFORE_NODE),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!(added.get());
+        assert!((solved.obj_val() - 12.0).abs() < 1e-6);
+    }
+}
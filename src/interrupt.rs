@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{Event, EventMask, Eventhdlr, Model, SCIPEventhdlr, Solving};
+
+/// A handle to interrupt the solving process of a model, e.g. from another thread.
+///
+/// It is obtained with [`Model::interrupt_handle`] before solving. After
+/// [`interrupt`](InterruptHandle::interrupt) was called, the solving process stops at the next
+/// presolving round or node, or once the current LP solve finished, and [`Model::solve`] returns
+/// with [`Status::UserInterrupt`](crate::Status::UserInterrupt). An LP that is being solved is
+/// not interrupted, so a long LP solve delays the interruption until it is done. The request is consumed by the
+/// interruption, so later solves of the model are not interrupted again and an interrupted solve
+/// can be continued with [`Model::solve`] on the solved model.
+#[derive(Debug, Clone)]
+pub struct InterruptHandle {
+    pub(crate) flag: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Requests the solving process to stop.
+    pub fn interrupt(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+
+    /// Withdraws a pending interruption request that did not stop a solving process yet.
+    pub fn reset(&self) {
+        self.flag.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns whether an interruption was requested and is still pending.
+    pub fn is_interrupted(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+}
+
+/// Interrupts the solving process once the flag of an [`InterruptHandle`] is set.
+pub(crate) struct InterruptEventhdlr {
+    pub(crate) flag: Arc<AtomicBool>,
+}
+
+impl Eventhdlr for InterruptEventhdlr {
+    fn get_type(&self) -> EventMask {
+        EventMask::PRESOLVE_ROUND | EventMask::NODE_EVENT | EventMask::LP_EVENT
+    }
+
+    fn execute(&mut self, mut model: Model<Solving>, _eventhdlr: SCIPEventhdlr, _event: Event) {
+        if self.flag.swap(false, Ordering::SeqCst) {
+            model.interrupt_solve();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn interrupt_handle_is_send_sync() {
+        assert_send_sync::<InterruptHandle>();
+    }
+
+    #[test]
+    fn interrupt_from_other_thread() {
+        let mut model = Model::default().hide_output().maximize();
+        let x = model.add(var().int(0..=5).obj(1.0));
+        model.add(cons().coef(&x, 2.0).le(7.0));
+
+        let handle = model.interrupt_handle();
+        assert!(!handle.is_interrupted());
+        let remote = handle.clone();
+        std::thread::spawn(move || remote.interrupt())
+            .join()
+            .unwrap();
+        assert!(handle.is_interrupted());
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::UserInterrupt);
+    }
+
+    /// Lets another thread interrupt the solve when the first node is focused and waits for it.
+    struct InterruptAtFirstNode {
+        focused: std::sync::mpsc::Sender<()>,
+        interrupted: std::sync::mpsc::Receiver<()>,
+        done: bool,
+    }
+
+    impl Eventhdlr for InterruptAtFirstNode {
+        fn get_type(&self) -> EventMask {
+            EventMask::NODE_FOCUSED
+        }
+
+        fn execute(&mut self, _model: Model<Solving>, _eventhdlr: SCIPEventhdlr, _event: Event) {
+            if !self.done {
+                self.done = true;
+                self.focused.send(()).unwrap();
+                self.interrupted.recv().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn interrupt_running_solve() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/p0201.mps")
+            .unwrap();
+
+        let (focused_tx, focused_rx) = std::sync::mpsc::channel();
+        let (interrupted_tx, interrupted_rx) = std::sync::mpsc::channel();
+        model.add(
+            eventhdlr(InterruptAtFirstNode {
+                focused: focused_tx,
+                interrupted: interrupted_rx,
+                done: false,
+            })
+            .name("InterruptAtFirstNode"),
+        );
+
+        let handle = model.interrupt_handle();
+        let remote = handle.clone();
+        let interrupter = std::thread::spawn(move || {
+            focused_rx.recv().unwrap();
+            remote.interrupt();
+            interrupted_tx.send(()).unwrap();
+        });
+
+        let solved = model.solve();
+        interrupter.join().unwrap();
+        assert_eq!(solved.status(), Status::UserInterrupt);
+        assert!(!handle.is_interrupted());
+    }
+
+    #[test]
+    fn interrupt_and_resume() {
+        let mut model = Model::default().hide_output().maximize();
+        let x = model.add(var().int(0..=5).obj(1.0));
+        model.add(cons().coef(&x, 2.0).le(7.0));
+
+        let handle = model.interrupt_handle();
+        handle.interrupt();
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::UserInterrupt);
+        assert!(!handle.is_interrupted());
+
+        let solved = solved.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 3.0).abs() < 1e-6);
+
+        handle.interrupt();
+        handle.reset();
+        assert!(!handle.is_interrupted());
+    }
+
+    #[test]
+    fn unused_interrupt_handle() {
+        let mut model = Model::default().hide_output().maximize();
+        let x = model.add(var().int(0..=5).obj(1.0));
+        model.add(cons().coef(&x, 2.0).le(7.0));
+
+        let _handle = model.interrupt_handle();
+        let _other = model.interrupt_handle();
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+    }
+}
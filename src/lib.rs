@@ -76,6 +76,22 @@ pub use separator::*;
 pub mod nodesel;
 pub use nodesel::*;
 
+/// Contains the `Presolver` trait used to define custom presolvers.
+pub mod presolver;
+pub use presolver::*;
+
+/// Contains the `Propagator` trait used to define custom propagators.
+pub mod propagator;
+pub use propagator::*;
+
+/// Contains the `Reader` trait used to define custom file readers and writers.
+pub mod reader;
+pub use reader::*;
+
+/// Contains the `Relaxator` trait used to define custom relaxation handlers.
+pub mod relaxator;
+pub use relaxator::*;
+
 /// Contains all the traits and structs that are re-exported by default.
 pub mod prelude;
 
@@ -98,7 +114,55 @@ mod diving;
 mod probing;
 
 pub use conshdlr::*;
+
+/// Contains the `ConflictHdlr` trait used to define custom conflict handlers.
+pub mod conflicthdlr;
+pub use conflicthdlr::*;
+
+/// Contains the `CutSelector` trait used to define custom cut selectors.
+pub mod cutsel;
+pub use cutsel::*;
+
+/// Contains the `Benders` and `BendersCut` traits used to define custom Benders' decompositions.
+pub mod benders;
+pub use benders::*;
+
+/// Contains the `MessageHandler` trait used to route the output of SCIP.
+pub mod message;
 pub use diving::*;
+pub use message::*;
+
+/// Contains the `InterruptHandle` struct used to interrupt the solving process from another thread.
+pub mod interrupt;
+pub use interrupt::*;
+
+/// Contains the `Progress` struct passed to progress callbacks and the recorded bound trace.
+pub mod progress;
+pub use progress::*;
+
+/// Contains the `Statistics` struct with the typed solving statistics of a model.
+pub mod statistics;
+pub use statistics::*;
+
+/// Contains the `CopyOptions` and `CopyMap` structs used to copy a model.
+pub mod copy;
+pub use copy::*;
+
+/// Contains the `SubScip` helper used to solve sub-problems inside primal heuristics.
+pub mod subscip;
+pub use subscip::*;
+
+/// Contains the `LinExpr` type used to build linear expressions with operators.
+pub mod linexpr;
+pub use linexpr::*;
+
+/// Contains the `Expr` enum, which represents a nonlinear expression tree.
+pub mod expr;
+pub use expr::*;
+
+/// Contains the `PiecewiseLinear` struct, which represents a piecewise-linear function added to a model.
+pub mod piecewise;
+pub use piecewise::*;
 
 pub use row::*;
 
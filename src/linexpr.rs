@@ -0,0 +1,281 @@
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::Variable;
+use crate::builder::cons::{ConsBuilder, cons};
+
+/// A linear expression `sum(coef * var) + constant`, built with the `+`, `-` and `*` operators
+/// on variables and numbers, e.g. `2.0 * &x + &y - 3.0`.
+///
+/// Linear expressions can be turned into constraints with [`le`](LinExpr::le),
+/// [`ge`](LinExpr::ge), [`eq`](LinExpr::eq) and [`between`](LinExpr::between), the constant is
+/// moved into the sides of the constraint. They can also be used as the objective with
+/// [`Model::set_objective`](crate::Model::set_objective).
+#[derive(Debug, Clone, Default)]
+pub struct LinExpr<'a> {
+    pub(crate) terms: Vec<(&'a Variable, f64)>,
+    pub(crate) constant: f64,
+}
+
+impl<'a> LinExpr<'a> {
+    /// Creates an empty linear expression.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the terms of the expression. A variable can appear in several terms.
+    pub fn terms(&self) -> &[(&'a Variable, f64)] {
+        &self.terms
+    }
+
+    /// Returns the constant of the expression.
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    /// Creates a constraint of the form `expr <= val`.
+    pub fn le(self, val: f64) -> ConsBuilder<'a> {
+        self.between(f64::NEG_INFINITY, val)
+    }
+
+    /// Creates a constraint of the form `val <= expr`.
+    pub fn ge(self, val: f64) -> ConsBuilder<'a> {
+        self.between(val, f64::INFINITY)
+    }
+
+    /// Creates a constraint of the form `expr = val`.
+    pub fn eq(self, val: f64) -> ConsBuilder<'a> {
+        self.between(val, val)
+    }
+
+    /// Creates a constraint of the form `lhs <= expr <= rhs`.
+    pub fn between(self, lhs: f64, rhs: f64) -> ConsBuilder<'a> {
+        cons()
+            .expr(self.terms)
+            .between(lhs - self.constant, rhs - self.constant)
+    }
+}
+
+impl<'a> From<&'a Variable> for LinExpr<'a> {
+    fn from(var: &'a Variable) -> Self {
+        LinExpr {
+            terms: vec![(var, 1.0)],
+            constant: 0.0,
+        }
+    }
+}
+
+impl From<f64> for LinExpr<'_> {
+    fn from(constant: f64) -> Self {
+        LinExpr {
+            terms: Vec::new(),
+            constant,
+        }
+    }
+}
+
+impl<'a, T: Into<LinExpr<'a>>> AddAssign<T> for LinExpr<'a> {
+    fn add_assign(&mut self, rhs: T) {
+        let rhs = rhs.into();
+        self.terms.extend(rhs.terms);
+        self.constant += rhs.constant;
+    }
+}
+
+impl<'a, T: Into<LinExpr<'a>>> SubAssign<T> for LinExpr<'a> {
+    fn sub_assign(&mut self, rhs: T) {
+        *self += -rhs.into();
+    }
+}
+
+impl MulAssign<f64> for LinExpr<'_> {
+    fn mul_assign(&mut self, rhs: f64) {
+        for (_, coef) in &mut self.terms {
+            *coef *= rhs;
+        }
+        self.constant *= rhs;
+    }
+}
+
+impl<'a, T: Into<LinExpr<'a>>> Add<T> for LinExpr<'a> {
+    type Output = LinExpr<'a>;
+
+    fn add(mut self, rhs: T) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<'a, T: Into<LinExpr<'a>>> Sub<T> for LinExpr<'a> {
+    type Output = LinExpr<'a>;
+
+    fn sub(mut self, rhs: T) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<'a> Mul<f64> for LinExpr<'a> {
+    type Output = LinExpr<'a>;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<'a> Neg for LinExpr<'a> {
+    type Output = LinExpr<'a>;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl<'a, T: Into<LinExpr<'a>>> Add<T> for &'a Variable {
+    type Output = LinExpr<'a>;
+
+    fn add(self, rhs: T) -> Self::Output {
+        LinExpr::from(self) + rhs
+    }
+}
+
+impl<'a, T: Into<LinExpr<'a>>> Sub<T> for &'a Variable {
+    type Output = LinExpr<'a>;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        LinExpr::from(self) - rhs
+    }
+}
+
+impl<'a> Mul<f64> for &'a Variable {
+    type Output = LinExpr<'a>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        LinExpr {
+            terms: vec![(self, rhs)],
+            constant: 0.0,
+        }
+    }
+}
+
+impl<'a> Neg for &'a Variable {
+    type Output = LinExpr<'a>;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl<'a> Add<LinExpr<'a>> for f64 {
+    type Output = LinExpr<'a>;
+
+    fn add(self, rhs: LinExpr<'a>) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl<'a> Add<&'a Variable> for f64 {
+    type Output = LinExpr<'a>;
+
+    fn add(self, rhs: &'a Variable) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl<'a> Sub<LinExpr<'a>> for f64 {
+    type Output = LinExpr<'a>;
+
+    fn sub(self, rhs: LinExpr<'a>) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl<'a> Sub<&'a Variable> for f64 {
+    type Output = LinExpr<'a>;
+
+    fn sub(self, rhs: &'a Variable) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl<'a> Mul<LinExpr<'a>> for f64 {
+    type Output = LinExpr<'a>;
+
+    fn mul(self, rhs: LinExpr<'a>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<'a> Mul<&'a Variable> for f64 {
+    type Output = LinExpr<'a>;
+
+    fn mul(self, rhs: &'a Variable) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<'a, T: Into<LinExpr<'a>>> Sum<T> for LinExpr<'a> {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(LinExpr::new(), |sum, term| sum + term)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ModelWithProblem, minimal_model};
+
+    #[test]
+    fn build_expressions() {
+        let mut model = minimal_model().hide_output();
+        let x = model.add(var().cont(0.0..=1.0));
+        let y = model.add(var().cont(0.0..=1.0));
+
+        let expr = 2.0 * &x + &y - 3.0;
+        assert_eq!(expr.terms().len(), 2);
+        assert_eq!(expr.terms()[0], (&x, 2.0));
+        assert_eq!(expr.terms()[1], (&y, 1.0));
+        assert_eq!(expr.constant(), -3.0);
+
+        let expr = 1.0 - (&x - &y * 4.0) * 0.5;
+        assert_eq!(expr.terms(), &[(&x, -0.5), (&y, 2.0)]);
+        assert_eq!(expr.constant(), 1.0);
+
+        let mut expr = LinExpr::from(&x);
+        expr += &y;
+        expr -= 2.0;
+        expr *= 3.0;
+        assert_eq!(expr.terms(), &[(&x, 3.0), (&y, 3.0)]);
+        assert_eq!(expr.constant(), -6.0);
+
+        let cons = (&x + &y + 1.0).between(2.0, 3.0);
+        assert_eq!(cons.lhs, 1.0);
+        assert_eq!(cons.rhs, 2.0);
+        let cons = (&x - 1.0).le(0.0);
+        assert_eq!(cons.lhs, f64::NEG_INFINITY);
+        assert_eq!(cons.rhs, 1.0);
+    }
+
+    #[test]
+    fn model_with_expressions() {
+        let mut model = minimal_model().hide_output().maximize();
+        let vars = (0..3)
+            .map(|_| model.add(var().int(0..=5)))
+            .collect::<Vec<_>>();
+        let (x, y, z) = (&vars[0], &vars[1], &vars[2]);
+
+        model.add(vars.iter().sum::<LinExpr>().le(6.0).name("total"));
+        model.add((2.0 * x - y).ge(-1.0));
+        model.add((x + z + 1.0).eq(3.0));
+        model.set_objective(x + 2.0 * y + 3.0 * z + 10.0);
+        assert_eq!(model.n_conss(), 3);
+        assert_eq!(model.find_cons("total").unwrap().name(), "total");
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        // e.g. x = 2, y = 4 and z = 0
+        assert!((solved.obj_val() - 20.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,171 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+use crate::ffi;
+
+/// A trait for handling the messages SCIP prints, e.g. to route them into a logging framework
+/// instead of the standard output.
+///
+/// A message handler is installed per model with [`Model::set_message_handler`](crate::Model::set_message_handler).
+/// Messages are buffered by SCIP and passed line by line.
+pub trait MessageHandler {
+    /// Handles an informational message, e.g. the solving progress.
+    fn info(&mut self, msg: &str);
+
+    /// Handles a warning message, defaults to [`info`](MessageHandler::info).
+    fn warning(&mut self, msg: &str) {
+        self.info(msg);
+    }
+
+    /// Handles a message of the interactive shell, defaults to [`info`](MessageHandler::info).
+    fn dialog(&mut self, msg: &str) {
+        self.info(msg);
+    }
+
+    /// Handles an error message, defaults to [`warning`](MessageHandler::warning).
+    ///
+    /// SCIP prints error messages through a single process-wide channel, so they only reach
+    /// the handler installed with [`set_error_handler`].
+    fn error(&mut self, msg: &str) {
+        self.warning(msg);
+    }
+}
+
+static ERROR_HANDLER: Mutex<Option<Box<dyn MessageHandler + Send>>> = Mutex::new(None);
+
+/// Installs a handler for the error messages of all SCIP instances in the process, or restores
+/// printing them to the standard error if `None` is given.
+pub fn set_error_handler(handler: Option<Box<dyn MessageHandler + Send>>) {
+    extern "C" fn errorprinting(
+        _data: *mut std::os::raw::c_void,
+        _file: *mut ffi::FILE,
+        msg: *const std::os::raw::c_char,
+    ) {
+        let msg = unsafe { std::ffi::CStr::from_ptr(msg) }.to_string_lossy();
+        if let Ok(mut handler) = ERROR_HANDLER.lock()
+            && let Some(handler) = handler.as_mut()
+        {
+            handler.error(&msg);
+        }
+    }
+
+    let is_some = handler.is_some();
+    *ERROR_HANDLER.lock().unwrap_or_else(|e| e.into_inner()) = handler;
+    unsafe {
+        if is_some {
+            ffi::SCIPmessageSetErrorPrinting(Some(errorprinting), std::ptr::null_mut());
+        } else {
+            ffi::SCIPmessageSetErrorPrintingDefault();
+        }
+    }
+}
+
+/// Collects the messages of a model, see [`Model::capture_output`](crate::Model::capture_output).
+pub(crate) struct OutputCapture {
+    pub(crate) buffer: Rc<RefCell<String>>,
+}
+
+impl MessageHandler for OutputCapture {
+    fn info(&mut self, msg: &str) {
+        self.buffer.borrow_mut().push_str(msg);
+    }
+}
+
+/// A message handler that forwards SCIP's messages to the [`log`] facade, prefixed with the
+/// name of the model.
+///
+/// Informational and dialog messages are logged at the `info` level, warnings at the `warn`
+/// level and errors at the `error` level, all with the target `russcip`.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone)]
+pub struct LogMessageHandler {
+    name: String,
+}
+
+#[cfg(feature = "log")]
+impl LogMessageHandler {
+    /// Creates a new `LogMessageHandler` for the model with the given name.
+    pub fn new(name: &str) -> Self {
+        LogMessageHandler {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl MessageHandler for LogMessageHandler {
+    fn info(&mut self, msg: &str) {
+        log::info!(target: "russcip", "[{}] {}", self.name, msg.trim_end());
+    }
+
+    fn warning(&mut self, msg: &str) {
+        log::warn!(target: "russcip", "[{}] {}", self.name, msg.trim_end());
+    }
+
+    fn error(&mut self, msg: &str) {
+        log::error!(target: "russcip", "[{}] {}", self.name, msg.trim_end());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Collector {
+        lines: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl MessageHandler for Collector {
+        fn info(&mut self, msg: &str) {
+            self.lines.borrow_mut().push(msg.to_string());
+        }
+    }
+
+    #[test]
+    fn message_handler_receives_solving_output() {
+        let lines = Rc::new(RefCell::new(vec![]));
+
+        let mut model = Model::default()
+            .set_message_handler(Collector {
+                lines: lines.clone(),
+            })
+            .show_output()
+            .maximize();
+        let x = model.add(var().int(0..=5).obj(1.0));
+        model.add(cons().coef(&x, 2.0).le(7.0));
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!(!lines.borrow().is_empty());
+        assert!(lines.borrow().iter().any(|l| l.contains("presolving")));
+    }
+
+    #[test]
+    fn capture_output() {
+        let mut model = Model::default().capture_output().show_output().maximize();
+        let x = model.add(var().int(0..=5).obj(1.0));
+        model.add(cons().coef(&x, 2.0).le(7.0));
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!(solved.captured_output().contains("presolving"));
+        assert!(!solved.captured_lines().is_empty());
+    }
+
+    #[test]
+    fn capture_output_is_per_model() {
+        let mut quiet = Model::default().capture_output().hide_output();
+        quiet.add(var().bin().obj(1.0));
+        let mut loud = Model::default().capture_output().show_output();
+        loud.add(var().bin().obj(1.0));
+
+        let quiet = quiet.solve();
+        let loud = loud.solve();
+        assert!(quiet.captured_output().is_empty());
+        assert!(!loud.captured_output().is_empty());
+    }
+}
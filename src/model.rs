@@ -1,22 +1,37 @@
 use crate::builder::CanBeAddedToModel;
 use crate::builder::cons::ConsBuilder;
 use crate::constraint::Constraint;
+use crate::copy::{CopyMap, CopyOptions};
 use crate::eventhdlr::Eventhdlr;
+use crate::expr::Expr;
+use crate::interrupt::InterruptEventhdlr;
+use crate::message::OutputCapture;
 use crate::node::Node;
 use crate::nodesel::{NodeSel, SCIPNodesel};
 use crate::param::ScipParameter;
+use crate::piecewise::{PiecewiseFormulation, PiecewiseLinear};
 use crate::probing::Prober;
+use crate::progress::{ProgressEventhdlr, ProgressRecorder};
 use crate::retcode::Retcode;
 use crate::scip::ScipPtr;
 use crate::solution::{SolError, Solution};
+use crate::statistics::Statistics;
 use crate::status::Status;
 use crate::variable::{VarId, VarType, Variable};
+use crate::{Aggregation, Presolver, Reader, Relaxator, SCIPReader, SCIPRelaxator};
+use crate::{Benders, BendersCut, InterruptHandle, LinExpr, MessageHandler, SCIPBenders};
+use crate::{BoundTracePoint, Progress};
+use crate::{BoundType, PresolTiming, PropTiming, Propagator, SCIPPropagator, Tightening};
 use crate::{BranchRule, Heur, HeurTiming, Heuristic, Pricer};
-use crate::{Conshdlr, Diver};
+use crate::{Conflict, ConflictHdlr, CutSelector};
+use crate::{Conshdlr, Diver, SCIPConshdlr};
 use crate::{Row, Separator, ffi, scip_call_panic};
 use scip_sys::SCIP;
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 /// Represents an optimization model.
 #[non_exhaustive]
@@ -46,6 +61,12 @@ pub struct Solving;
 #[derive(Debug)]
 pub struct Solved;
 
+/// Represents the state of an optimization model between two solves of a reoptimization
+/// sequence, reached with [`Model::free_reopt_solve`]. SCIP keeps the presolved problem in this
+/// state, so only the objective can be changed and the feasible region restricted.
+#[derive(Debug)]
+pub struct ReoptPresolved;
+
 impl Model<Unsolved> {
     /// Creates a new `Model` instance with an `Unsolved` state.
     pub fn new() -> Self {
@@ -151,6 +172,19 @@ impl Model<ProblemCreated> {
         self.set_obj_sense(ObjSense::Minimize)
     }
 
+    /// Sets the objective function to the given linear expression, e.g. `&x + 2.0 * &y`.
+    /// Variables that do not appear in the expression get an objective coefficient of zero,
+    /// and the constant of the expression becomes the objective offset.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the objective cannot be set in the current state.
+    pub fn set_objective<'a>(&mut self, expr: impl Into<LinExpr<'a>>) {
+        self.scip
+            .set_objective(&expr.into())
+            .expect("Failed to set objective in state ProblemCreated");
+    }
+
     /// Informs the SCIP instance that the objective value is always integral and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_obj_integral(mut self) -> Self {
@@ -160,6 +194,18 @@ impl Model<ProblemCreated> {
         self
     }
 
+    /// Enables reoptimization, which lets SCIP reuse the search tree of previous solves when a
+    /// sequence of problems that differ in objective or bounds is solved. After solving, the
+    /// next problem is set up with [`Model::free_reopt_solve`], which allows changing the
+    /// objective and restricting bounds.
+    #[allow(unused_mut)]
+    pub fn enable_reoptimization(mut self) -> Self {
+        self.scip
+            .enable_reoptimization(true)
+            .expect("Failed to enable reoptimization in state ProblemCreated");
+        self
+    }
+
     /// Adds a new variable to the model with the given lower bound, upper bound, objective coefficient, name, and type.
     ///
     /// # Arguments
@@ -196,6 +242,118 @@ impl Model<ProblemCreated> {
         }
     }
 
+    /// Adds the piecewise-linear function `y = f(x)` through the given breakpoints to the model.
+    ///
+    /// The function is modelled with a weight variable (lambda) per breakpoint, `x` and `y` are
+    /// the convex combination of two adjacent breakpoints. Outside of the breakpoints the function
+    /// is not defined, i.e. `x` is restricted to the range of the breakpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The argument of the function.
+    /// * `y` - The value of the function.
+    /// * `breakpoints` - The `(x, y)` breakpoints of the function, sorted by `x`.
+    /// * `formulation` - Whether the adjacency of the breakpoints is enforced with an SOS2
+    ///   constraint or with binary variables.
+    ///
+    /// # Returns
+    ///
+    /// The added variables and constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there are less than two breakpoints, if they are not sorted by `x`,
+    /// or if the function cannot be added in the current state.
+    pub fn add_piecewise_linear(
+        &mut self,
+        x: &Variable,
+        y: &Variable,
+        breakpoints: &[(f64, f64)],
+        formulation: PiecewiseFormulation,
+    ) -> PiecewiseLinear {
+        assert!(
+            breakpoints.len() >= 2,
+            "A piecewise-linear function needs at least two breakpoints"
+        );
+        assert!(
+            breakpoints.windows(2).all(|w| w[0].0 <= w[1].0),
+            "The breakpoints must be sorted by x"
+        );
+
+        let prefix = format!("pwl_{}_{}", x.name(), y.name());
+        let n = breakpoints.len();
+        let lambdas = (0..n)
+            .map(|i| {
+                self.add_var(
+                    0.0,
+                    1.0,
+                    0.0,
+                    &format!("{prefix}_lambda{i}"),
+                    VarType::Continuous,
+                )
+            })
+            .collect::<Vec<_>>();
+        let lambda_refs = lambdas.iter().collect::<Vec<_>>();
+
+        let mut conss = vec![self.add_cons(
+            lambda_refs.clone(),
+            &vec![1.0; n],
+            1.0,
+            1.0,
+            &format!("{prefix}_convex"),
+        )];
+        let (xs, ys): (Vec<_>, Vec<_>) = breakpoints.iter().copied().unzip();
+        for (var, mut coefs, name) in [(x, xs, "x"), (y, ys, "y")] {
+            let mut vars = lambda_refs.clone();
+            vars.push(var);
+            coefs.push(-1.0);
+            conss.push(self.add_cons(vars, &coefs, 0.0, 0.0, &format!("{prefix}_{name}")));
+        }
+
+        let mut binaries = Vec::new();
+        match formulation {
+            PiecewiseFormulation::Sos2 => {
+                conss.push(self.add_cons_sos2(lambda_refs, None, &format!("{prefix}_sos2")));
+            }
+            PiecewiseFormulation::Binary => {
+                binaries = (0..n - 1)
+                    .map(|i| {
+                        self.add_var(0.0, 1.0, 0.0, &format!("{prefix}_z{i}"), VarType::Binary)
+                    })
+                    .collect::<Vec<_>>();
+                conss.push(self.add_cons(
+                    binaries.iter().collect(),
+                    &vec![1.0; n - 1],
+                    1.0,
+                    1.0,
+                    &format!("{prefix}_segment"),
+                ));
+                // a breakpoint can only be used if one of its adjacent segments is selected
+                for (i, lambda) in lambdas.iter().enumerate() {
+                    let mut vars = vec![lambda];
+                    let mut coefs = vec![1.0];
+                    for segment in binaries.iter().take(i + 1).skip(i.saturating_sub(1)) {
+                        vars.push(segment);
+                        coefs.push(-1.0);
+                    }
+                    conss.push(self.add_cons(
+                        vars,
+                        &coefs,
+                        f64::NEG_INFINITY,
+                        0.0,
+                        &format!("{prefix}_link{i}"),
+                    ));
+                }
+            }
+        }
+
+        PiecewiseLinear {
+            lambdas,
+            binaries,
+            conss,
+        }
+    }
+
     /// Includes a new branch rule in the model with the given name, description, priority, maximum depth, maximum bound distance, and implementation.
     ///
     /// # Arguments
@@ -330,6 +488,228 @@ impl Model<ProblemCreated> {
             .expect("Failed to include separator at state ProblemCreated");
     }
 
+    /// Includes a new conflict handler in the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the conflict handler. This should be a unique identifier.
+    /// * `desc` - A brief description of the conflict handler.
+    /// * `priority` - The priority of the conflict handler, conflict handlers with a higher
+    ///   priority are called first.
+    /// * `conflicthdlr` - The conflict handler to be included.
+    pub fn include_conflicthdlr(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        conflicthdlr: Box<dyn ConflictHdlr>,
+    ) {
+        self.scip
+            .include_conflicthdlr(name, desc, priority, conflicthdlr)
+            .expect("Failed to include conflict handler at state ProblemCreated");
+    }
+
+    /// Includes a new cut selector in the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the cut selector. This should be a unique identifier.
+    /// * `desc` - A brief description of the cut selector.
+    /// * `priority` - The priority of the cut selector, only the cut selector with the highest
+    ///   priority is used (the default `hybrid` cut selector has a priority of 8000).
+    /// * `cutsel` - The cut selector to be included.
+    pub fn include_cutsel(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        cutsel: Box<dyn CutSelector>,
+    ) {
+        self.scip
+            .include_cutsel(name, desc, priority, cutsel)
+            .expect("Failed to include cut selector at state ProblemCreated");
+    }
+
+    /// Includes a new relaxator in the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the relaxator. This should be a unique identifier.
+    /// * `desc` - A brief description of the relaxator.
+    /// * `priority` - The priority of the relaxator, relaxators with a non-negative priority are
+    ///   called before the LP is solved, the others after it.
+    /// * `freq` - The frequency for calling the relaxator, -1 means never and 0 only at the root.
+    /// * `relax` - The relaxator to be included.
+    pub fn include_relax(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        relax: Box<dyn Relaxator>,
+    ) {
+        self.scip
+            .include_relax(name, desc, priority, freq, relax)
+            .expect("Failed to include relaxator at state ProblemCreated");
+    }
+
+    /// Adds subproblems to the model, which becomes the master problem of SCIP's default
+    /// Benders' decomposition. The subproblems are solved by SCIP and the classical optimality
+    /// and feasibility cuts are added to the master problem.
+    ///
+    /// Variables of the master problem are mapped to the variables of the subproblems by their
+    /// names, so linking variables must have the same name in all models. The subproblems are
+    /// kept alive by the model and freed after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `subproblems` - The subproblems of the decomposition.
+    ///
+    /// # Returns
+    ///
+    /// The default Benders' decomposition, e.g. to add custom cuts to it with
+    /// [`Model::include_benderscut`].
+    pub fn add_benders_subproblems(
+        &mut self,
+        subproblems: Vec<Model<ProblemCreated>>,
+    ) -> SCIPBenders {
+        self.scip
+            .add_benders_subproblems(subproblems.into_iter().map(|m| m.scip).collect())
+            .expect("Failed to add Benders subproblems at state ProblemCreated")
+    }
+
+    /// Includes a new Benders' decomposition in the model and activates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the Benders' decomposition. This should be a unique identifier.
+    /// * `desc` - A brief description of the Benders' decomposition.
+    /// * `priority` - The priority of the Benders' decomposition.
+    /// * `n_subproblems` - The number of subproblems.
+    /// * `cut_lp` - Whether cuts are generated for LP solutions.
+    /// * `cut_pseudo` - Whether cuts are generated for pseudo solutions.
+    /// * `cut_relax` - Whether cuts are generated for relaxation solutions.
+    /// * `share_aux_vars` - Whether the auxiliary variables of the highest priority Benders'
+    ///   decomposition are shared.
+    /// * `benders` - The Benders' decomposition to be included.
+    pub fn include_benders(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        n_subproblems: usize,
+        cut_lp: bool,
+        cut_pseudo: bool,
+        cut_relax: bool,
+        share_aux_vars: bool,
+        benders: Box<dyn Benders>,
+    ) -> SCIPBenders {
+        self.scip
+            .include_benders(
+                name,
+                desc,
+                priority,
+                n_subproblems,
+                cut_lp,
+                cut_pseudo,
+                cut_relax,
+                share_aux_vars,
+                benders,
+            )
+            .expect("Failed to include Benders decomposition at state ProblemCreated")
+    }
+
+    /// Includes a new Benders' cut in a Benders' decomposition of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `benders` - The Benders' decomposition the cut belongs to.
+    /// * `name` - The name of the Benders' cut. This should be a unique identifier.
+    /// * `desc` - A brief description of the Benders' cut.
+    /// * `priority` - The priority of the Benders' cut, cuts with a higher priority are called first.
+    /// * `is_lp_cut` - Whether the cut is generated from the LP solutions of the subproblems.
+    /// * `cut` - The Benders' cut to be included.
+    pub fn include_benderscut(
+        &mut self,
+        benders: &SCIPBenders,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        is_lp_cut: bool,
+        cut: Box<dyn BendersCut>,
+    ) {
+        self.scip
+            .include_benderscut(benders, name, desc, priority, is_lp_cut, cut)
+            .expect("Failed to include Benders cut at state ProblemCreated");
+    }
+
+    /// Includes a new presolver in the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the presolver. This should be a unique identifier.
+    /// * `desc` - A brief description of the presolver.
+    /// * `priority` - The priority of the presolver, presolvers with a higher priority are called first.
+    /// * `maxrounds` - The maximal number of presolving rounds the presolver participates in, -1 means no limit.
+    /// * `timing` - The presolving rounds in which the presolver is called.
+    /// * `presol` - The presolver to be included.
+    pub fn include_presol(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        maxrounds: i32,
+        timing: PresolTiming,
+        presol: Box<dyn Presolver>,
+    ) {
+        self.scip
+            .include_presol(name, desc, priority, maxrounds, timing, presol)
+            .expect("Failed to include presolver at state ProblemCreated");
+    }
+
+    /// Includes a new propagator in the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the propagator. This should be a unique identifier.
+    /// * `desc` - A brief description of the propagator.
+    /// * `priority` - The priority of the propagator, propagators with a higher priority are called first.
+    /// * `freq` - The frequency for calling the propagator in the tree; 1 means at every node, 2 means at every other node and so on, -1 turns off propagation in the tree (the root is still propagated if 0).
+    /// * `delay` - Whether the propagator should be delayed if other propagators found reductions.
+    /// * `timing` - The points in the node processing at which the propagator is called.
+    /// * `presol_priority` - The presolving priority of the propagator.
+    /// * `presol_maxrounds` - The maximal number of presolving rounds the propagator participates in, -1 means no limit.
+    /// * `presol_timing` - The presolving rounds in which the propagator is called, `PresolTiming::NONE` disables presolving.
+    /// * `prop` - The propagator to be included.
+    pub fn include_prop(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        delay: bool,
+        timing: PropTiming,
+        presol_priority: i32,
+        presol_maxrounds: i32,
+        presol_timing: PresolTiming,
+        prop: Box<dyn Propagator>,
+    ) {
+        self.scip
+            .include_prop(
+                name,
+                desc,
+                priority,
+                freq,
+                delay,
+                timing,
+                presol_priority,
+                presol_maxrounds,
+                presol_timing,
+                prop,
+            )
+            .expect("Failed to include propagator at state ProblemCreated");
+    }
+
     /// Includes a new event handler in the model.
     ///
     /// # Arguments
@@ -343,6 +723,58 @@ impl Model<ProblemCreated> {
             .expect("Failed to include event handler at state ProblemCreated");
     }
 
+    /// Returns a handle to interrupt the solving process of the model from another thread.
+    /// All handles of a model share the same interruption request.
+    pub fn interrupt_handle(&mut self) -> InterruptHandle {
+        let mut included = false;
+        let flag = self
+            .scip
+            .interrupt_flag
+            .get_or_init(|| {
+                included = true;
+                Arc::new(AtomicBool::new(false))
+            })
+            .clone();
+        if included {
+            self.include_eventhdlr(
+                "russcip_interrupt",
+                "interrupts the solving process on request of an interrupt handle",
+                Box::new(InterruptEventhdlr { flag: flag.clone() }),
+            );
+        }
+        InterruptHandle { flag }
+    }
+
+    /// Registers a callback that reports the solving progress. It is called whenever a new best
+    /// solution is found or the dual bound improves, and every `node_freq` processed nodes.
+    /// Registering a callback also records the bound trace, see [`Model::bound_trace`].
+    ///
+    /// # Arguments
+    ///
+    /// * `node_freq` - The number of nodes between two node reports, 0 disables them.
+    /// * `callback` - The callback to be called with the progress.
+    pub fn on_progress<F: FnMut(Progress) + 'static>(&mut self, node_freq: usize, callback: F) {
+        let mut included = false;
+        let recorder = self
+            .scip
+            .progress
+            .get_or_init(|| {
+                included = true;
+                Rc::new(RefCell::new(ProgressRecorder::default()))
+            })
+            .clone();
+        recorder
+            .borrow_mut()
+            .add_callback(node_freq, Box::new(callback));
+        if included {
+            self.include_eventhdlr(
+                "russcip_progress",
+                "reports the solving progress and records the bound trace",
+                Box::new(ProgressEventhdlr { recorder }),
+            );
+        }
+    }
+
     /// Includes a new pricer in the SCIP data structure.
     ///
     /// # Arguments
@@ -390,6 +822,16 @@ impl Model<ProblemCreated> {
     ///   The integrality constraint handler has a checking priority of 0. That means, constraint
     ///   handlers with negative checking priorities only have to deal with integral solutions.
     /// * `conshdlr` - The constraint handler to be included.
+    ///
+    /// The handler does not need constraints to be called, and runs its optional callbacks with the
+    /// default settings of [`ConshdlrBuilder`](crate::builder::conshdlr::ConshdlrBuilder), which
+    /// should be used for more control. Unlike the builder, all constraints are only used in the
+    /// first evaluation (an `eagerfreq` of 0), as in earlier versions.
+    ///
+    /// # Returns
+    ///
+    /// The included constraint handler, which can be used to create constraints with
+    /// [`add_cons_with_data`](ProblemOrSolving::add_cons_with_data).
     pub fn include_conshdlr(
         &mut self,
         name: &str,
@@ -397,10 +839,28 @@ impl Model<ProblemCreated> {
         enfopriority: i32,
         checkpriority: i32,
         conshdlr: Box<dyn Conshdlr>,
-    ) {
-        self.scip
-            .include_conshdlr(name, desc, enfopriority, checkpriority, conshdlr)
+    ) -> SCIPConshdlr {
+        let raw = self
+            .scip
+            .include_conshdlr(
+                name,
+                desc,
+                enfopriority,
+                checkpriority,
+                0,
+                1,
+                1,
+                0,
+                -1,
+                false,
+                false,
+                PropTiming::BEFORE_LP,
+                PresolTiming::MEDIUM,
+                false,
+                conshdlr,
+            )
             .expect("Failed to include constraint handler at state ProblemCreated");
+        SCIPConshdlr { raw }
     }
 
     /// Tries to solve the model, and returns a new `Model` instance with a `Solved` state if successful.
@@ -805,6 +1265,155 @@ impl Model<Solving> {
         self.scip.lp_status()
     }
 
+    /// Adds a linear constraint for a conflict, from within a conflict handler. The constraint is
+    /// added to the node the conflict was found at, and is valid from the node at the conflict's
+    /// validity depth on.
+    ///
+    /// # Arguments
+    ///
+    /// * `conflict` - The conflict the constraint was derived from.
+    /// * `cons` - The constraint to add (can be built by calling the cons() function).
+    pub fn add_conflict_cons(
+        &mut self,
+        conflict: &Conflict,
+        cons: &ConsBuilder,
+    ) -> Result<(), Retcode> {
+        let vars: Vec<&Variable> = cons.coefs.iter().map(|(var, _)| *var).collect();
+        let coefs: Vec<f64> = cons.coefs.iter().map(|(_, coef)| *coef).collect();
+        self.scip.add_conflict_cons(
+            conflict,
+            vars,
+            &coefs,
+            cons.lhs,
+            cons.rhs,
+            cons.name.unwrap_or(""),
+        )
+    }
+
+    /// Returns the auxiliary variable of a subproblem of a Benders' decomposition, which
+    /// estimates the objective value of the subproblem in the master problem.
+    ///
+    /// # Arguments
+    ///
+    /// * `benders` - The Benders' decomposition.
+    /// * `probnumber` - The number of the subproblem.
+    pub fn benders_auxiliary_var(&self, benders: &SCIPBenders, probnumber: usize) -> Variable {
+        assert!(probnumber < benders.n_subproblems());
+        let var = unsafe { ffi::SCIPbendersGetAuxiliaryVar(benders.raw, probnumber as i32) };
+        assert!(!var.is_null());
+        Variable {
+            raw: var,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Interrupts the solving process as soon as possible, [`Model::solve`] then returns with
+    /// [`Status::UserInterrupt`].
+    pub fn interrupt_solve(&mut self) {
+        self.scip
+            .interrupt_solve()
+            .expect("Failed to interrupt solving process");
+    }
+
+    /// Sets the relaxation solution of a relaxator and marks it as valid. Variables that are
+    /// not given get the value 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `relax` - The relaxator the solution belongs to.
+    /// * `vars` - The variables to set the values of.
+    /// * `vals` - The values of the variables, in the same order.
+    /// * `includes_lp` - Whether the relaxation includes all rows of the LP, so that its solution
+    ///   can be enforced through linear cuts.
+    pub fn set_relax_sol(
+        &mut self,
+        relax: &SCIPRelaxator,
+        vars: &[Variable],
+        vals: &[f64],
+        includes_lp: bool,
+    ) -> Result<(), Retcode> {
+        self.scip.set_relax_sol(relax, vars, vals, includes_lp)
+    }
+
+    /// Returns the value of a variable in the current relaxation solution.
+    pub fn relax_sol_val(&self, var: &Variable) -> f64 {
+        self.scip
+            .relax_sol_val(var)
+            .expect("Failed to get relaxation solution value in state Solving")
+    }
+
+    /// Fixes a variable of the transformed problem to the given value, e.g. in a presolver.
+    ///
+    /// # Returns
+    /// `Tightening::Tightened` if the variable was fixed, `Tightening::Redundant` if it was
+    /// already fixed to the value, and `Tightening::Infeasible` if the value is outside its domain.
+    pub fn fix_var(&mut self, var: &Variable, val: f64) -> Result<Tightening, Retcode> {
+        self.scip.fix_var(var, val)
+    }
+
+    /// Aggregates two variables of the transformed problem by the equation
+    /// `scalarx * varx + scalary * vary = rhs`, e.g. in a presolver.
+    pub fn aggregate_vars(
+        &mut self,
+        varx: &Variable,
+        vary: &Variable,
+        scalarx: f64,
+        scalary: f64,
+        rhs: f64,
+    ) -> Result<Aggregation, Retcode> {
+        self.scip.aggregate_vars(varx, vary, scalarx, scalary, rhs)
+    }
+
+    /// Tightens the lower bound of a variable. During presolving the bound is changed globally,
+    /// during the tree search only at the current node.
+    pub fn tighten_var_lb(&mut self, var: &Variable, lb: f64) -> Result<Tightening, Retcode> {
+        self.scip.tighten_var_bound(var, lb, BoundType::Lower)
+    }
+
+    /// Tightens the upper bound of a variable. During presolving the bound is changed globally,
+    /// during the tree search only at the current node.
+    pub fn tighten_var_ub(&mut self, var: &Variable, ub: f64) -> Result<Tightening, Retcode> {
+        self.scip.tighten_var_bound(var, ub, BoundType::Upper)
+    }
+
+    /// Tightens the local lower bound of a variable, recording the propagator that inferred the change.
+    ///
+    /// # Arguments
+    /// * `var` - The variable whose bound is tightened.
+    /// * `lb` - The new lower bound.
+    /// * `prop` - The propagator that inferred the bound.
+    /// * `info` - User information passed back to the propagator when the change has to be explained
+    ///   in [`Propagator::resolve_propagation`](crate::Propagator::resolve_propagation).
+    pub fn infer_var_lb(
+        &mut self,
+        var: &Variable,
+        lb: f64,
+        prop: &SCIPPropagator,
+        info: i32,
+    ) -> Result<Tightening, Retcode> {
+        self.scip
+            .infer_var_bound(var, lb, BoundType::Lower, prop, info)
+    }
+
+    /// Tightens the local upper bound of a variable, recording the propagator that inferred the change.
+    ///
+    /// # Arguments
+    /// * `var` - The variable whose bound is tightened.
+    /// * `ub` - The new upper bound.
+    /// * `prop` - The propagator that inferred the bound.
+    /// * `info` - User information passed back to the propagator when the change has to be explained
+    ///   in [`Propagator::resolve_propagation`](crate::Propagator::resolve_propagation).
+    pub fn infer_var_ub(
+        &mut self,
+        var: &Variable,
+        ub: f64,
+        prop: &SCIPPropagator,
+        info: i32,
+    ) -> Result<Tightening, Retcode> {
+        self.scip
+            .infer_var_bound(var, ub, BoundType::Upper, prop, info)
+    }
+
     /// Changes the upper bound of the variable in a given node.
     pub fn set_ub_node(&mut self, node: &Node, var: &Variable, ub: f64) {
         scip_call_panic!(ffi::SCIPchgVarUbNode(
@@ -847,6 +1456,58 @@ impl Model<Solved> {
         self.scip.n_lp_iterations()
     }
 
+    /// Returns the primal and dual bounds recorded whenever one of them improved, in the
+    /// order they were recorded. The trace is only recorded if a callback was registered with
+    /// [`Model::on_progress`].
+    pub fn bound_trace(&self) -> Vec<BoundTracePoint> {
+        self.scip
+            .progress
+            .get()
+            .map(|recorder| recorder.borrow().trace.clone())
+            .unwrap_or_default()
+    }
+
+    /// Tries to continue solving the model, e.g. after raising the limit that stopped the
+    /// previous solve. The search resumes where it stopped, keeping the branch-and-bound tree.
+    /// If the model was already solved to completion, this does nothing.
+    ///
+    /// # Returns
+    ///
+    /// The `Model` in the `Solved` state, or a [`Retcode`] if solving cannot be continued.
+    pub fn try_solve(self) -> Result<Model<Solved>, Retcode> {
+        self.scip.solve()?;
+        Ok(self)
+    }
+
+    /// Continues solving the model, see [`Model::try_solve`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if solving cannot be continued.
+    pub fn solve(self) -> Model<Solved> {
+        self.try_solve()
+            .expect("Failed to continue solving in state Solved")
+    }
+
+    /// Frees the solving data but keeps the information needed for reoptimization, and returns
+    /// the model in the ReoptPresolved state where the objective can be changed and the bounds
+    /// restricted for the next solve.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Retcode::InvalidCall`] if reoptimization was not enabled with
+    /// [`Model::enable_reoptimization`], or another `Retcode` if SCIP fails to free the solve.
+    pub fn free_reopt_solve(self) -> Result<Model<ReoptPresolved>, Retcode> {
+        if !self.scip.is_reopt_enabled() {
+            return Err(Retcode::InvalidCall);
+        }
+        self.scip.free_reopt_solve()?;
+        Ok(Model {
+            scip: self.scip,
+            state: PhantomData,
+        })
+    }
+
     /// Frees the transformed problem and returns the model the ProblemCreated state where you
     /// can add variables and constraints, useful for iterated solving
     pub fn free_transform(self) -> Model<ProblemCreated> {
@@ -860,16 +1521,80 @@ impl Model<Solved> {
     }
 }
 
-/// A trait for optimization models with a problem created.
-pub trait ModelWithProblem {
-    /// Returns a vector of all variables in the optimization model.
-    fn vars(&self) -> Vec<Variable>;
-    /// Returns a vector of all original variables in the optimization model.
-    fn orig_vars(&self) -> Vec<Variable>;
-
-    /// Returns the variable with the given ID, if it exists.
-    fn var(&self, var_id: VarId) -> Option<Variable>;
-
+impl Model<ReoptPresolved> {
+    /// Changes the objective function for the next solve of the reoptimization sequence. All
+    /// variables that are not given get an objective coefficient of zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `sense` - The objective sense of the new objective.
+    /// * `vars` - The variables with a nonzero objective coefficient.
+    /// * `coefs` - The objective coefficients of the variables.
+    pub fn chg_reopt_objective(
+        &mut self,
+        sense: ObjSense,
+        vars: &[&Variable],
+        coefs: &[f64],
+    ) -> Result<(), Retcode> {
+        self.scip.chg_reopt_objective(sense, vars, coefs)
+    }
+
+    /// Restricts the lower bound of a variable for all following solves of the reoptimization
+    /// sequence.
+    ///
+    /// SCIP does not allow changing the bounds of the presolved problem, so the bound is added
+    /// as a permanent constraint instead. Restrictions are therefore cumulative and one-way:
+    /// every call adds to the restrictions of earlier calls, a bound looser than a previous one
+    /// has no effect and a restriction cannot be undone later in the sequence.
+    pub fn restrict_var_lb(&mut self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        let name = format!("reopt_lb_{}", var.name());
+        self.scip
+            .add_reopt_bound_cons(var, lb, f64::INFINITY, &name)
+    }
+
+    /// Restricts the upper bound of a variable for all following solves of the reoptimization
+    /// sequence. Like [`Model::restrict_var_lb`], the restriction is cumulative and one-way.
+    pub fn restrict_var_ub(&mut self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        let name = format!("reopt_ub_{}", var.name());
+        self.scip
+            .add_reopt_bound_cons(var, f64::NEG_INFINITY, ub, &name)
+    }
+
+    /// Tries to solve the next problem of the reoptimization sequence, reusing the search tree
+    /// of the previous solves.
+    ///
+    /// # Returns
+    ///
+    /// The `Model` in the `Solved` state, or a [`Retcode`] if the problem cannot be solved.
+    pub fn try_solve(self) -> Result<Model<Solved>, Retcode> {
+        self.scip.solve()?;
+        Ok(Model {
+            scip: self.scip,
+            state: PhantomData,
+        })
+    }
+
+    /// Solves the next problem of the reoptimization sequence, see [`Model::try_solve`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the problem cannot be solved.
+    pub fn solve(self) -> Model<Solved> {
+        self.try_solve()
+            .expect("Failed to solve problem in state ReoptPresolved")
+    }
+}
+
+/// A trait for optimization models with a problem created.
+pub trait ModelWithProblem {
+    /// Returns a vector of all variables in the optimization model.
+    fn vars(&self) -> Vec<Variable>;
+    /// Returns a vector of all original variables in the optimization model.
+    fn orig_vars(&self) -> Vec<Variable>;
+
+    /// Returns the variable with the given ID, if it exists.
+    fn var(&self, var_id: VarId) -> Option<Variable>;
+
     /// Returns the number of variables in the optimization model.
     fn n_vars(&self) -> usize;
 
@@ -908,6 +1633,25 @@ pub trait ModelWithProblem {
     /// * `Result<(), Retcode>` - Ok(()) if successful, Err(Retcode) otherwise.
     ///
     fn write(&self, path: &str, ext: &str, symb: bool) -> Result<(), Retcode>;
+
+    /// Returns the objective sense of the problem.
+    fn obj_sense(&self) -> ObjSense;
+
+    /// Copies the model into a new model with the default [`CopyOptions`], e.g. to fork a base
+    /// model into scenario variants or to solve a sub-MIP inside a heuristic.
+    ///
+    /// # Returns
+    ///
+    /// * The copy in `ProblemCreated` stage and the mapping from the variables and constraints
+    ///   of this model to the ones of the copy.
+    fn try_copy(&self) -> Result<(Model<ProblemCreated>, CopyMap), Retcode>;
+
+    /// Copies the model into a new model with the given [`CopyOptions`], see
+    /// [`try_copy`](Self::try_copy).
+    fn try_copy_with(
+        &self,
+        options: CopyOptions,
+    ) -> Result<(Model<ProblemCreated>, CopyMap), Retcode>;
 }
 
 /// A trait for model stages that have a problem.
@@ -1024,6 +1768,65 @@ impl<S: ModelStageWithProblem> ModelWithProblem for Model<S> {
         self.scip.write(path, ext, symb)?;
         Ok(())
     }
+
+    /// Returns the objective sense of the problem.
+    fn obj_sense(&self) -> ObjSense {
+        self.scip.obj_sense()
+    }
+
+    /// Copies the model into a new model with the default [`CopyOptions`].
+    fn try_copy(&self) -> Result<(Model<ProblemCreated>, CopyMap), Retcode> {
+        self.try_copy_with(CopyOptions::default())
+    }
+
+    /// Copies the model into a new model with the given [`CopyOptions`].
+    fn try_copy_with(
+        &self,
+        options: CopyOptions,
+    ) -> Result<(Model<ProblemCreated>, CopyMap), Retcode> {
+        let fixings = options
+            .fixings
+            .iter()
+            .map(|(var, val)| (var.raw, *val))
+            .collect::<Vec<_>>();
+        let (scip, vars, conss, valid) = self.scip.copy(
+            options.name.as_deref(),
+            options.original,
+            options.global,
+            options.copy_plugins,
+            &fixings,
+        )?;
+
+        let scip = Rc::new(scip);
+        let map = CopyMap {
+            vars: vars
+                .into_iter()
+                .map(|(source, raw)| {
+                    let var = Variable {
+                        raw,
+                        scip: scip.clone(),
+                    };
+                    (source, var)
+                })
+                .collect(),
+            conss: conss
+                .into_iter()
+                .map(|(source, raw)| {
+                    let cons = Constraint {
+                        raw,
+                        scip: scip.clone(),
+                    };
+                    (source, cons)
+                })
+                .collect(),
+            valid,
+        };
+        let model = Model {
+            scip,
+            state: PhantomData,
+        };
+        Ok((model, map))
+    }
 }
 
 /// A trait for optimization models with a problem created or solved.
@@ -1094,6 +1897,24 @@ pub trait ProblemOrSolving {
         name: &str,
     ) -> Constraint;
 
+    /// Adds a new nonlinear constraint `lhs <= expr <= rhs` to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The expression of the constraint.
+    /// * `lhs` - The left-hand side of the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The new constraint.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_nonlinear(&mut self, expr: &Expr, lhs: f64, rhs: f64, name: &str) -> Constraint;
+
     /// Adds a new constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
     ///
     /// # Arguments
@@ -1242,120 +2063,850 @@ pub trait ProblemOrSolving {
         weights: Option<&[f64]>,
         name: &str,
     ) -> Constraint;
-}
 
-/// A trait for model stages that have a problem or are during solving.
-pub trait ModelStageProblemOrSolving {}
-impl ModelStageProblemOrSolving for ProblemCreated {}
-impl ModelStageProblemOrSolving for Solving {}
+    /// Adds a new SOS2 constraint to the model with the given variables, optional weights, and name.
+    ///
+    /// At most two variables of the constraint can be non-zero, and they have to be adjacent in
+    /// the order of the weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The variables in the SOS2 constraint.
+    /// * `weights` - Optional weights for the variables, determining their order. Without weights the given order is used.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    fn add_cons_sos2(
+        &mut self,
+        vars: Vec<&Variable>,
+        weights: Option<&[f64]>,
+        name: &str,
+    ) -> Constraint;
 
-impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
-    /// Create a new solution in the original space
-    fn create_orig_sol(&'_ self) -> Solution<'_> {
-        let sol_ptr = self
-            .scip
-            .create_sol(true)
-            .expect("Failed to create solution in state ProblemCreated");
-        Solution {
-            raw: sol_ptr,
-            scip_ptr: &self.scip,
-        }
-    }
+    /// Adds a new AND constraint `resultant = vars[0] && vars[1] && ...` over binary variables to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `resultant` - The binary variable that equals the conjunction of the variables.
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if the resultant or any of the variables is not binary.
+    fn add_cons_and(
+        &mut self,
+        resultant: &Variable,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Constraint;
 
-    /// Adds a solution to the model
+    /// Adds a new OR constraint `resultant = vars[0] || vars[1] || ...` over binary variables to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `resultant` - The binary variable that equals the disjunction of the variables.
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
     ///
     /// # Returns
-    /// A `Result` indicating whether the solution was added successfully.
-    fn add_sol(&self, sol: Solution) -> Result<(), SolError> {
-        let successfully_stored = self.scip.add_sol(sol).expect("Failed to add solution");
-        if successfully_stored {
-            Ok(())
-        } else {
-            Err(SolError::Infeasible)
-        }
-    }
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if the resultant or any of the variables is not binary.
+    fn add_cons_or(&mut self, resultant: &Variable, vars: Vec<&Variable>, name: &str)
+    -> Constraint;
 
-    /// Adds a binary variable to the given set partitioning constraint.
+    /// Adds a new XOR constraint `vars[0] ^ vars[1] ^ ... = rhs` over binary variables to the model.
+    ///
+    /// SCIP adds an integer variable to the constraint to express its parity, see
+    /// [`Constraint::xor_int_var`].
     ///
     /// # Arguments
     ///
-    /// * `cons` - The constraint to add the variable to.
-    /// * `var` - The binary variable to add.
+    /// * `rhs` - The parity the variables must have, `true` for an odd number of ones.
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
     ///
     /// # Panics
     ///
-    /// This method panics if the variable cannot be added in the current state, or if the variable is not binary.
-    fn add_cons_coef_setppc(&mut self, cons: &Constraint, var: &Variable) {
-        assert_eq!(var.var_type(), VarType::Binary);
-        self.scip
-            .add_cons_coef_setppc(cons, var)
-            .expect("Failed to add constraint coefficient in state ProblemCreated");
-    }
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_xor(&mut self, rhs: bool, vars: Vec<&Variable>, name: &str) -> Constraint;
 
-    /// Adds a coefficient to the given constraint for the given variable and coefficient value.
+    /// Adds a new logicor constraint `vars[0] || vars[1] || ...` to the model, i.e. at least one of the binary variables must be one.
     ///
     /// # Arguments
     ///
-    /// * `cons` - The constraint to add the coefficient to.
-    /// * `var` - The variable to add the coefficient for.
-    /// * `coef` - The coefficient value to add.
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
     ///
     /// # Panics
     ///
-    /// This method panics if the coefficient cannot be added in the current state.
-    fn add_cons_coef(&mut self, cons: &Constraint, var: &Variable, coef: f64) {
-        self.scip
-            .add_cons_coef(cons, var, coef)
-            .expect("Failed to add constraint coefficient in state ProblemCreated");
-    }
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_logicor(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint;
 
-    /// Adds a new quadratic constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
+    /// Adds a new pseudoboolean constraint `lhs <= sum(coef * var) + sum(coef * prod(vars)) <= rhs` over binary variables to the model.
+    ///
+    /// SCIP adds an AND constraint with an artificial resultant for every product of variables.
     ///
     /// # Arguments
     ///
-    /// * `lin_vars` - The linear variables in the constraint.
-    /// * `lin_coefs` - The coefficients of the linear variables in the constraint.
-    /// * `quad_vars_1` - The first variable in the quadratic constraints.
-    /// * `quad_vars_2` - The second variable in the quadratic constraints.
-    /// * `quad_coefs` - The coefficients of the quadratic terms in the constraint.
+    /// * `lin_vars` - The binary variables of the linear terms.
+    /// * `lin_coefs` - The coefficients of the linear terms.
+    /// * `and_terms` - The products of binary variables with their coefficients.
     /// * `lhs` - The left-hand side of the constraint.
     /// * `rhs` - The right-hand side of the constraint.
     /// * `name` - The name of the constraint.
     ///
     /// # Returns
     ///
-    /// A reference-counted pointer to the new constraint.
+    /// The created `Constraint`
     ///
     /// # Panics
     ///
-    /// This method panics if the constraint cannot be created in the current state.
-    fn add_cons_quadratic(
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_pseudoboolean(
         &mut self,
         lin_vars: Vec<&Variable>,
-        lin_coefs: &mut [f64],
-        quad_vars_1: Vec<&Variable>,
-        quad_vars_2: Vec<&Variable>,
-        quad_coefs: &mut [f64],
+        lin_coefs: &[f64],
+        and_terms: Vec<(Vec<&Variable>, f64)>,
         lhs: f64,
         rhs: f64,
         name: &str,
-    ) -> Constraint {
-        assert_eq!(lin_vars.len(), lin_coefs.len());
-        assert_eq!(quad_vars_1.len(), quad_vars_2.len());
-        assert_eq!(quad_vars_1.len(), quad_coefs.len());
-        let cons = self
-            .scip
-            .create_cons_quadratic(
-                lin_vars,
-                lin_coefs,
-                quad_vars_1,
-                quad_vars_2,
-                quad_coefs,
-                lhs,
-                rhs,
-                name,
-            )
-            .expect("Failed to create constraint in state ProblemCreated");
+    ) -> Constraint;
+
+    /// Adds a new knapsack constraint `sum(weight * var) <= capacity` over binary variables to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The binary variables in the constraint.
+    /// * `weights` - The integer weights of the variables.
+    /// * `capacity` - The capacity of the knapsack.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_knapsack(
+        &mut self,
+        vars: Vec<&Variable>,
+        weights: &[i64],
+        capacity: i64,
+        name: &str,
+    ) -> Constraint;
+
+    /// Adds a new variable bound constraint `lhs <= var + vbd_coef * vbd_var <= rhs` to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - The variable whose bound is given by the other variable.
+    /// * `vbd_var` - The bounding variable, it must not be continuous.
+    /// * `vbd_coef` - The coefficient of the bounding variable.
+    /// * `lhs` - The left-hand side of the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_varbound(
+        &mut self,
+        var: &Variable,
+        vbd_var: &Variable,
+        vbd_coef: f64,
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Constraint;
+
+    /// Adds a new bound disjunction constraint to the model, i.e. at least one of the bound literals must hold.
+    ///
+    /// # Arguments
+    ///
+    /// * `literals` - The bound literals, `(var, BoundType::Lower, b)` stands for `var >= b` and
+    ///   `(var, BoundType::Upper, b)` for `var <= b`.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_bounddisjunction(
+        &mut self,
+        literals: Vec<(&Variable, BoundType, f64)>,
+        name: &str,
+    ) -> Constraint;
+
+    /// Adds a new linking constraint to the model, linking an integer or continuous variable to binary variables with
+    /// `link_var = sum(val * bin_var)` and `sum(bin_var) = 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `link_var` - The variable that takes one of the values.
+    /// * `bin_vars` - The binary variables selecting the value.
+    /// * `vals` - The values of the binary variables.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if any of the binary variables is not binary.
+    fn add_cons_linking(
+        &mut self,
+        link_var: &Variable,
+        bin_vars: Vec<&Variable>,
+        vals: &[f64],
+        name: &str,
+    ) -> Constraint;
+
+    /// Adds a new constraint of a custom constraint handler to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `conshdlr` - The constraint handler the constraint belongs to, as returned when including it.
+    /// * `name` - The name of the constraint.
+    /// * `data` - The data of the constraint, accessible with [`SCIPConshdlr::cons_data`] in the
+    ///   callbacks of the handler and dropped when the constraint is deleted.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    fn add_cons_with_data<T: 'static>(
+        &mut self,
+        conshdlr: &SCIPConshdlr,
+        name: &str,
+        data: T,
+    ) -> Constraint;
+
+    /// Deletes a constraint from the problem. After the problem was transformed, the transformed
+    /// counterpart of an original constraint is deleted.
+    fn del_cons(&mut self, cons: &Constraint) -> Result<(), Retcode>;
+
+    /// Changes the lower bound of a variable. Before solving, this changes the bound of the
+    /// original problem, during presolving the global bound and during solving the local bound
+    /// at the current node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if bounds cannot be changed in the current stage.
+    fn chg_var_lb(&mut self, var: &Variable, lb: f64) -> Result<(), Retcode>;
+
+    /// Changes the upper bound of a variable. Before solving, this changes the bound of the
+    /// original problem, during presolving the global bound and during solving the local bound
+    /// at the current node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if bounds cannot be changed in the current stage.
+    fn chg_var_ub(&mut self, var: &Variable, ub: f64) -> Result<(), Retcode>;
+
+    /// Changes the global lower bound of a variable, which is valid in the whole search tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if global bounds cannot be changed in the current stage.
+    fn chg_var_lb_global(&mut self, var: &Variable, lb: f64) -> Result<(), Retcode>;
+
+    /// Changes the global upper bound of a variable, which is valid in the whole search tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if global bounds cannot be changed in the current stage.
+    fn chg_var_ub_global(&mut self, var: &Variable, ub: f64) -> Result<(), Retcode>;
+
+    /// Changes the local lower bound of a variable at the current node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if the model is not solving.
+    fn chg_var_lb_local(&mut self, var: &Variable, lb: f64) -> Result<(), Retcode>;
+
+    /// Changes the local upper bound of a variable at the current node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if the model is not solving.
+    fn chg_var_ub_local(&mut self, var: &Variable, ub: f64) -> Result<(), Retcode>;
+
+    /// Changes the objective coefficient of a variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if the objective cannot be changed in the current stage,
+    /// which is only possible before solving and during presolving.
+    fn chg_var_obj(&mut self, var: &Variable, obj: f64) -> Result<(), Retcode>;
+
+    /// Changes the type of a variable. Changing a continuous variable to an integral type
+    /// rounds its bounds.
+    ///
+    /// # Returns
+    ///
+    /// * Whether the type change detected that the problem is infeasible.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if the type cannot be changed in the current stage, which
+    /// is only possible before solving and during presolving.
+    fn chg_var_type(&mut self, var: &Variable, var_type: VarType) -> Result<bool, Retcode>;
+
+    /// Deletes a variable from the problem. Before solving, the variable has to be removed from
+    /// all constraints, or the constraints deleted, first. The variable must not be used
+    /// after it was deleted.
+    ///
+    /// # Returns
+    ///
+    /// * Whether the variable was marked for deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidCall` if variables cannot be deleted in the current stage, which
+    /// is only possible before solving and during presolving.
+    fn del_var(&mut self, var: &Variable) -> Result<bool, Retcode>;
+
+    /// Changes the left-hand side of a linear constraint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidData` if the constraint is not linear, and
+    /// `Retcode::InvalidCall` if the sides cannot be changed in the current stage, which is only
+    /// possible before solving and during presolving.
+    fn chg_lhs(&mut self, cons: &Constraint, lhs: f64) -> Result<(), Retcode>;
+
+    /// Changes the right-hand side of a linear constraint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidData` if the constraint is not linear, and
+    /// `Retcode::InvalidCall` if the sides cannot be changed in the current stage, which is only
+    /// possible before solving and during presolving.
+    fn chg_rhs(&mut self, cons: &Constraint, rhs: f64) -> Result<(), Retcode>;
+
+    /// Changes the coefficient of a variable in a linear constraint, adding the variable if it
+    /// is not yet in the constraint and removing it if the coefficient is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Retcode::InvalidData` if the constraint is not linear, and
+    /// `Retcode::InvalidCall` if the model is already solving.
+    fn chg_coef(&mut self, cons: &Constraint, var: &Variable, coef: f64) -> Result<(), Retcode>;
+}
+
+/// A trait for model stages that have a problem or are during solving.
+pub trait ModelStageProblemOrSolving {}
+impl ModelStageProblemOrSolving for ProblemCreated {}
+impl ModelStageProblemOrSolving for Solving {}
+
+impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
+    /// Create a new solution in the original space
+    fn create_orig_sol(&'_ self) -> Solution<'_> {
+        let sol_ptr = self
+            .scip
+            .create_sol(true)
+            .expect("Failed to create solution in state ProblemCreated");
+        Solution {
+            raw: sol_ptr,
+            scip_ptr: &self.scip,
+        }
+    }
+
+    /// Adds a solution to the model
+    ///
+    /// # Returns
+    /// A `Result` indicating whether the solution was added successfully.
+    fn add_sol(&self, sol: Solution) -> Result<(), SolError> {
+        let successfully_stored = self.scip.add_sol(sol).expect("Failed to add solution");
+        if successfully_stored {
+            Ok(())
+        } else {
+            Err(SolError::Infeasible)
+        }
+    }
+
+    /// Adds a binary variable to the given set partitioning constraint.
+    ///
+    /// # Arguments
+    ///
+    /// * `cons` - The constraint to add the variable to.
+    /// * `var` - The binary variable to add.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the variable cannot be added in the current state, or if the variable is not binary.
+    fn add_cons_coef_setppc(&mut self, cons: &Constraint, var: &Variable) {
+        assert_eq!(var.var_type(), VarType::Binary);
+        self.scip
+            .add_cons_coef_setppc(cons, var)
+            .expect("Failed to add constraint coefficient in state ProblemCreated");
+    }
+
+    /// Adds a coefficient to the given constraint for the given variable and coefficient value.
+    ///
+    /// # Arguments
+    ///
+    /// * `cons` - The constraint to add the coefficient to.
+    /// * `var` - The variable to add the coefficient for.
+    /// * `coef` - The coefficient value to add.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the coefficient cannot be added in the current state.
+    fn add_cons_coef(&mut self, cons: &Constraint, var: &Variable, coef: f64) {
+        self.scip
+            .add_cons_coef(cons, var, coef)
+            .expect("Failed to add constraint coefficient in state ProblemCreated");
+    }
+
+    /// Adds a new quadratic constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `lin_vars` - The linear variables in the constraint.
+    /// * `lin_coefs` - The coefficients of the linear variables in the constraint.
+    /// * `quad_vars_1` - The first variable in the quadratic constraints.
+    /// * `quad_vars_2` - The second variable in the quadratic constraints.
+    /// * `quad_coefs` - The coefficients of the quadratic terms in the constraint.
+    /// * `lhs` - The left-hand side of the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// A reference-counted pointer to the new constraint.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_quadratic(
+        &mut self,
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &mut [f64],
+        quad_vars_1: Vec<&Variable>,
+        quad_vars_2: Vec<&Variable>,
+        quad_coefs: &mut [f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Constraint {
+        assert_eq!(lin_vars.len(), lin_coefs.len());
+        assert_eq!(quad_vars_1.len(), quad_vars_2.len());
+        assert_eq!(quad_vars_1.len(), quad_coefs.len());
+        let cons = self
+            .scip
+            .create_cons_quadratic(
+                lin_vars,
+                lin_coefs,
+                quad_vars_1,
+                quad_vars_2,
+                quad_coefs,
+                lhs,
+                rhs,
+                name,
+            )
+            .expect("Failed to create constraint in state ProblemCreated");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new nonlinear constraint `lhs <= expr <= rhs` to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The expression of the constraint.
+    /// * `lhs` - The left-hand side of the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The new constraint.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_nonlinear(&mut self, expr: &Expr, lhs: f64, rhs: f64, name: &str) -> Constraint {
+        let cons = self
+            .scip
+            .create_cons_nonlinear(expr, lhs, rhs, name)
+            .expect("Failed to create constraint in state ProblemCreated");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The variables in the constraint.
+    /// * `coefs` - The coefficients of the variables in the constraint.
+    /// * `lhs` - The left-hand side of the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// A reference-counted pointer to the new constraint.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons(
+        &mut self,
+        vars: Vec<&Variable>,
+        coefs: &[f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Constraint {
+        assert_eq!(vars.len(), coefs.len());
+        let cons = self
+            .scip
+            .create_cons(None, vars, coefs, lhs, rhs, name, false)
+            .expect("Failed to create constraint in state ProblemCreated");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new set partitioning constraint to the model with the given variables and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// A reference-counted pointer to the new constraint.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
+    fn add_cons_set_part(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+        let cons = self
+            .scip
+            .create_cons_set_part(vars, name)
+            .expect("Failed to add constraint set partition in state ProblemCreated");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new set cover constraint to the model with the given variables and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The new `Constraint`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
+    fn add_cons_set_cover(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+        let cons = self
+            .scip
+            .create_cons_set_cover(vars, name)
+            .expect("Failed to add constraint set cover in state ProblemCreated");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new set packing constraint to the model with the given variables and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
+    fn add_cons_set_pack(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+        let cons = self
+            .scip
+            .create_cons_set_pack(vars, name)
+            .expect("Failed to add constraint set packing in state ProblemCreated");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new cardinality constraint to the model with the given variables, cardinality limit, and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The binary variables in the constraint.
+    /// * `cardinality` - The maximum number of non-zero variables this constraint allows
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_cardinality(
+        &mut self,
+        vars: Vec<&Variable>,
+        cardinality: usize,
+        name: &str,
+    ) -> Constraint {
+        let cons = self
+            .scip
+            .create_cons_cardinality(vars, cardinality, name)
+            .expect("Failed to add cardinality constraint");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new indicator constraint to the model with the given variables, coefficients, right-hand side, and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `bin_var` - The binary variable in the constraint.
+    /// * `vars` - The variables of the constraints.
+    /// * `coefs` - The coefficients of the variables in the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_indicator(
+        &mut self,
+        bin_var: &Variable,
+        vars: Vec<&Variable>,
+        coefs: &mut [f64],
+        rhs: f64,
+        name: &str,
+    ) -> Constraint {
+        assert_eq!(vars.len(), coefs.len());
+        assert_eq!(bin_var.var_type(), VarType::Binary);
+        let cons = self
+            .scip
+            .create_cons_indicator(bin_var, vars, coefs, rhs, name)
+            .expect("Failed to create constraint in state ProblemCreated");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new SOS1 constraint to the model with the given variables, optional weights, and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The variables in the SOS1 constraint.
+    /// * `weights` - Optional weights for the variables (used for branching priorities).
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    fn add_cons_sos1(
+        &mut self,
+        vars: Vec<&Variable>,
+        weights: Option<&[f64]>,
+        name: &str,
+    ) -> Constraint {
+        let cons = self
+            .scip
+            .create_cons_sos1(vars, weights, name)
+            .expect("Failed to create SOS1 constraint");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new SOS2 constraint to the model with the given variables, optional weights, and name.
+    ///
+    /// At most two variables of the constraint can be non-zero, and they have to be adjacent in
+    /// the order of the weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The variables in the SOS2 constraint.
+    /// * `weights` - Optional weights for the variables, determining their order. Without weights the given order is used.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    fn add_cons_sos2(
+        &mut self,
+        vars: Vec<&Variable>,
+        weights: Option<&[f64]>,
+        name: &str,
+    ) -> Constraint {
+        let cons = self
+            .scip
+            .create_cons_sos2(vars, weights, name)
+            .expect("Failed to create SOS2 constraint");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new AND constraint `resultant = vars[0] && vars[1] && ...` over binary variables to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `resultant` - The binary variable that equals the conjunction of the variables.
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if the resultant or any of the variables is not binary.
+    fn add_cons_and(
+        &mut self,
+        resultant: &Variable,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Constraint {
+        assert_eq!(resultant.var_type(), VarType::Binary);
+        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+        let cons = self
+            .scip
+            .create_cons_and(resultant, vars, name)
+            .expect("Failed to add AND constraint");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new OR constraint `resultant = vars[0] || vars[1] || ...` over binary variables to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `resultant` - The binary variable that equals the disjunction of the variables.
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if the resultant or any of the variables is not binary.
+    fn add_cons_or(
+        &mut self,
+        resultant: &Variable,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Constraint {
+        assert_eq!(resultant.var_type(), VarType::Binary);
+        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+        let cons = self
+            .scip
+            .create_cons_or(resultant, vars, name)
+            .expect("Failed to add OR constraint");
+
+        Constraint {
+            raw: cons,
+            scip: self.scip.clone(),
+        }
+    }
+
+    /// Adds a new XOR constraint `vars[0] ^ vars[1] ^ ... = rhs` over binary variables to the model.
+    ///
+    /// SCIP adds an integer variable to the constraint to express its parity, see
+    /// [`Constraint::xor_int_var`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The parity the variables must have, `true` for an odd number of ones.
+    /// * `vars` - The binary variables in the constraint.
+    /// * `name` - The name of the constraint.
+    ///
+    /// # Returns
+    ///
+    /// The created `Constraint`
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_xor(&mut self, rhs: bool, vars: Vec<&Variable>, name: &str) -> Constraint {
+        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+        let cons = self
+            .scip
+            .create_cons_xor(rhs, vars, name)
+            .expect("Failed to add XOR constraint");
 
         Constraint {
             raw: cons,
@@ -1363,36 +2914,26 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
-    /// Adds a new constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
+    /// Adds a new logicor constraint `vars[0] || vars[1] || ...` to the model, i.e. at least one of the binary variables must be one.
     ///
     /// # Arguments
     ///
-    /// * `vars` - The variables in the constraint.
-    /// * `coefs` - The coefficients of the variables in the constraint.
-    /// * `lhs` - The left-hand side of the constraint.
-    /// * `rhs` - The right-hand side of the constraint.
+    /// * `vars` - The binary variables in the constraint.
     /// * `name` - The name of the constraint.
     ///
     /// # Returns
     ///
-    /// A reference-counted pointer to the new constraint.
+    /// The created `Constraint`
     ///
     /// # Panics
     ///
-    /// This method panics if the constraint cannot be created in the current state.
-    fn add_cons(
-        &mut self,
-        vars: Vec<&Variable>,
-        coefs: &[f64],
-        lhs: f64,
-        rhs: f64,
-        name: &str,
-    ) -> Constraint {
-        assert_eq!(vars.len(), coefs.len());
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_logicor(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
         let cons = self
             .scip
-            .create_cons(None, vars, coefs, lhs, rhs, name, false)
-            .expect("Failed to create constraint in state ProblemCreated");
+            .create_cons_logicor(vars, name)
+            .expect("Failed to add logicor constraint");
 
         Constraint {
             raw: cons,
@@ -1400,26 +2941,46 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
-    /// Adds a new set partitioning constraint to the model with the given variables and name.
+    /// Adds a new pseudoboolean constraint `lhs <= sum(coef * var) + sum(coef * prod(vars)) <= rhs` over binary variables to the model.
+    ///
+    /// SCIP adds an AND constraint with an artificial resultant for every product of variables.
     ///
     /// # Arguments
     ///
-    /// * `vars` - The binary variables in the constraint.
+    /// * `lin_vars` - The binary variables of the linear terms.
+    /// * `lin_coefs` - The coefficients of the linear terms.
+    /// * `and_terms` - The products of binary variables with their coefficients.
+    /// * `lhs` - The left-hand side of the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
     /// * `name` - The name of the constraint.
     ///
     /// # Returns
     ///
-    /// A reference-counted pointer to the new constraint.
+    /// The created `Constraint`
     ///
     /// # Panics
     ///
-    /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
-    fn add_cons_set_part(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
-        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_pseudoboolean(
+        &mut self,
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &[f64],
+        and_terms: Vec<(Vec<&Variable>, f64)>,
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Constraint {
+        assert!(lin_vars.iter().all(|v| v.var_type() == VarType::Binary));
+        assert!(
+            and_terms
+                .iter()
+                .flat_map(|(vars, _)| vars)
+                .all(|v| v.var_type() == VarType::Binary)
+        );
         let cons = self
             .scip
-            .create_cons_set_part(vars, name)
-            .expect("Failed to add constraint set partition in state ProblemCreated");
+            .create_cons_pseudoboolean(lin_vars, lin_coefs, and_terms, lhs, rhs, name)
+            .expect("Failed to add pseudoboolean constraint");
 
         Constraint {
             raw: cons,
@@ -1427,26 +2988,34 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
-    /// Adds a new set cover constraint to the model with the given variables and name.
+    /// Adds a new knapsack constraint `sum(weight * var) <= capacity` over binary variables to the model.
     ///
     /// # Arguments
     ///
     /// * `vars` - The binary variables in the constraint.
+    /// * `weights` - The integer weights of the variables.
+    /// * `capacity` - The capacity of the knapsack.
     /// * `name` - The name of the constraint.
     ///
     /// # Returns
     ///
-    /// The new `Constraint`.
+    /// The created `Constraint`
     ///
     /// # Panics
     ///
-    /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
-    fn add_cons_set_cover(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+    /// This method panics if the constraint cannot be created in the current state, or if any of the variables is not binary.
+    fn add_cons_knapsack(
+        &mut self,
+        vars: Vec<&Variable>,
+        weights: &[i64],
+        capacity: i64,
+        name: &str,
+    ) -> Constraint {
         assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
         let cons = self
             .scip
-            .create_cons_set_cover(vars, name)
-            .expect("Failed to add constraint set cover in state ProblemCreated");
+            .create_cons_knapsack(vars, weights, capacity, name)
+            .expect("Failed to add knapsack constraint");
 
         Constraint {
             raw: cons,
@@ -1454,11 +3023,15 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
-    /// Adds a new set packing constraint to the model with the given variables and name.
+    /// Adds a new variable bound constraint `lhs <= var + vbd_coef * vbd_var <= rhs` to the model.
     ///
     /// # Arguments
     ///
-    /// * `vars` - The binary variables in the constraint.
+    /// * `var` - The variable whose bound is given by the other variable.
+    /// * `vbd_var` - The bounding variable, it must not be continuous.
+    /// * `vbd_coef` - The coefficient of the bounding variable.
+    /// * `lhs` - The left-hand side of the constraint.
+    /// * `rhs` - The right-hand side of the constraint.
     /// * `name` - The name of the constraint.
     ///
     /// # Returns
@@ -1467,13 +3040,20 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// # Panics
     ///
-    /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
-    fn add_cons_set_pack(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
-        assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
+    /// This method panics if the constraint cannot be created in the current state.
+    fn add_cons_varbound(
+        &mut self,
+        var: &Variable,
+        vbd_var: &Variable,
+        vbd_coef: f64,
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Constraint {
         let cons = self
             .scip
-            .create_cons_set_pack(vars, name)
-            .expect("Failed to add constraint set packing in state ProblemCreated");
+            .create_cons_varbound(var, vbd_var, vbd_coef, lhs, rhs, name)
+            .expect("Failed to add varbound constraint");
 
         Constraint {
             raw: cons,
@@ -1481,12 +3061,12 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
-    /// Adds a new cardinality constraint to the model with the given variables, cardinality limit, and name.
+    /// Adds a new bound disjunction constraint to the model, i.e. at least one of the bound literals must hold.
     ///
     /// # Arguments
     ///
-    /// * `vars` - The binary variables in the constraint.
-    /// * `cardinality` - The maximum number of non-zero variables this constraint allows
+    /// * `literals` - The bound literals, `(var, BoundType::Lower, b)` stands for `var >= b` and
+    ///   `(var, BoundType::Upper, b)` for `var <= b`.
     /// * `name` - The name of the constraint.
     ///
     /// # Returns
@@ -1496,16 +3076,15 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     /// # Panics
     ///
     /// This method panics if the constraint cannot be created in the current state.
-    fn add_cons_cardinality(
+    fn add_cons_bounddisjunction(
         &mut self,
-        vars: Vec<&Variable>,
-        cardinality: usize,
+        literals: Vec<(&Variable, BoundType, f64)>,
         name: &str,
     ) -> Constraint {
         let cons = self
             .scip
-            .create_cons_cardinality(vars, cardinality, name)
-            .expect("Failed to add cardinality constraint");
+            .create_cons_bounddisjunction(literals, name)
+            .expect("Failed to add bounddisjunction constraint");
 
         Constraint {
             raw: cons,
@@ -1513,14 +3092,14 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
-    /// Adds a new indicator constraint to the model with the given variables, coefficients, right-hand side, and name.
+    /// Adds a new linking constraint to the model, linking an integer or continuous variable to binary variables with
+    /// `link_var = sum(val * bin_var)` and `sum(bin_var) = 1`.
     ///
     /// # Arguments
     ///
-    /// * `bin_var` - The binary variable in the constraint.
-    /// * `vars` - The variables of the constraints.
-    /// * `coefs` - The coefficients of the variables in the constraint.
-    /// * `rhs` - The right-hand side of the constraint.
+    /// * `link_var` - The variable that takes one of the values.
+    /// * `bin_vars` - The binary variables selecting the value.
+    /// * `vals` - The values of the binary variables.
     /// * `name` - The name of the constraint.
     ///
     /// # Returns
@@ -1529,21 +3108,19 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// # Panics
     ///
-    /// This method panics if the constraint cannot be created in the current state.
-    fn add_cons_indicator(
+    /// This method panics if the constraint cannot be created in the current state, or if any of the binary variables is not binary.
+    fn add_cons_linking(
         &mut self,
-        bin_var: &Variable,
-        vars: Vec<&Variable>,
-        coefs: &mut [f64],
-        rhs: f64,
+        link_var: &Variable,
+        bin_vars: Vec<&Variable>,
+        vals: &[f64],
         name: &str,
     ) -> Constraint {
-        assert_eq!(vars.len(), coefs.len());
-        assert_eq!(bin_var.var_type(), VarType::Binary);
+        assert!(bin_vars.iter().all(|v| v.var_type() == VarType::Binary));
         let cons = self
             .scip
-            .create_cons_indicator(bin_var, vars, coefs, rhs, name)
-            .expect("Failed to create constraint in state ProblemCreated");
+            .create_cons_linking(link_var, bin_vars, vals, name)
+            .expect("Failed to add linking constraint");
 
         Constraint {
             raw: cons,
@@ -1551,27 +3128,28 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
-    /// Adds a new SOS1 constraint to the model with the given variables, optional weights, and name.
+    /// Adds a new constraint of a custom constraint handler to the model.
     ///
     /// # Arguments
     ///
-    /// * `vars` - The variables in the SOS1 constraint.
-    /// * `weights` - Optional weights for the variables (used for branching priorities).
+    /// * `conshdlr` - The constraint handler the constraint belongs to, as returned when including it.
     /// * `name` - The name of the constraint.
+    /// * `data` - The data of the constraint, accessible with [`SCIPConshdlr::cons_data`] in the
+    ///   callbacks of the handler and dropped when the constraint is deleted.
     ///
     /// # Returns
     ///
     /// The created `Constraint`
-    fn add_cons_sos1(
+    fn add_cons_with_data<T: 'static>(
         &mut self,
-        vars: Vec<&Variable>,
-        weights: Option<&[f64]>,
+        conshdlr: &SCIPConshdlr,
         name: &str,
+        data: T,
     ) -> Constraint {
         let cons = self
             .scip
-            .create_cons_sos1(vars, weights, name)
-            .expect("Failed to create SOS1 constraint");
+            .create_cons_with_data(conshdlr.raw, name, Box::new(data))
+            .expect("Failed to create constraint with data");
 
         Constraint {
             raw: cons,
@@ -1579,6 +3157,72 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
+    /// Deletes a constraint from the problem. After the problem was transformed, the transformed
+    /// counterpart of an original constraint is deleted.
+    fn del_cons(&mut self, cons: &Constraint) -> Result<(), Retcode> {
+        self.scip.del_cons(cons)
+    }
+
+    /// Changes the lower bound of a variable.
+    fn chg_var_lb(&mut self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        self.scip.chg_var_lb(var, lb)
+    }
+
+    /// Changes the upper bound of a variable.
+    fn chg_var_ub(&mut self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        self.scip.chg_var_ub(var, ub)
+    }
+
+    /// Changes the global lower bound of a variable.
+    fn chg_var_lb_global(&mut self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        self.scip.chg_var_lb_global(var, lb)
+    }
+
+    /// Changes the global upper bound of a variable.
+    fn chg_var_ub_global(&mut self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        self.scip.chg_var_ub_global(var, ub)
+    }
+
+    /// Changes the local lower bound of a variable at the current node.
+    fn chg_var_lb_local(&mut self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        self.scip.chg_var_lb_local(var, lb)
+    }
+
+    /// Changes the local upper bound of a variable at the current node.
+    fn chg_var_ub_local(&mut self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        self.scip.chg_var_ub_local(var, ub)
+    }
+
+    /// Changes the objective coefficient of a variable.
+    fn chg_var_obj(&mut self, var: &Variable, obj: f64) -> Result<(), Retcode> {
+        self.scip.chg_var_obj(var, obj)
+    }
+
+    /// Changes the type of a variable.
+    fn chg_var_type(&mut self, var: &Variable, var_type: VarType) -> Result<bool, Retcode> {
+        self.scip.chg_var_type(var, var_type)
+    }
+
+    /// Deletes a variable from the problem.
+    fn del_var(&mut self, var: &Variable) -> Result<bool, Retcode> {
+        self.scip.del_var(var)
+    }
+
+    /// Changes the left-hand side of a linear constraint.
+    fn chg_lhs(&mut self, cons: &Constraint, lhs: f64) -> Result<(), Retcode> {
+        self.scip.chg_lhs(cons, lhs)
+    }
+
+    /// Changes the right-hand side of a linear constraint.
+    fn chg_rhs(&mut self, cons: &Constraint, rhs: f64) -> Result<(), Retcode> {
+        self.scip.chg_rhs(cons, rhs)
+    }
+
+    /// Changes the coefficient of a variable in a linear constraint.
+    fn chg_coef(&mut self, cons: &Constraint, var: &Variable, coef: f64) -> Result<(), Retcode> {
+        self.scip.chg_coef(cons, var, coef)
+    }
+
     /// Sets the constraint as modifiable or not.
     fn set_cons_modifiable(&mut self, cons: &Constraint, modifiable: bool) {
         self.scip
@@ -1680,6 +3324,10 @@ pub trait WithSolvingStats {
     /// `SCIPprintStatisticsJson` behaviour.
     fn write_stats_json(&self, path: &str) -> Result<(), Retcode>;
 
+    /// Returns the solving statistics as a typed [`Statistics`] struct, gathered from SCIP's
+    /// statistics getters instead of parsing [`stats_json`](Self::stats_json).
+    fn statistics(&self) -> Statistics;
+
     /// Returns the solving statistics parsed as a [`serde_json::Value`].
     ///
     /// Requires the `serde` feature.
@@ -1730,6 +3378,11 @@ impl<S: ModelStageWithSolvingStats> WithSolvingStats for Model<S> {
         self.scip.write_statistics_json(path)
     }
 
+    /// Returns the solving statistics as a typed [`Statistics`] struct.
+    fn statistics(&self) -> Statistics {
+        Statistics::collect(&self.scip)
+    }
+
     /// Returns the solving statistics parsed as a [`serde_json::Value`].
     #[cfg(feature = "serde")]
     fn stats_json_value(&self) -> serde_json::Value {
@@ -1756,6 +3409,31 @@ impl<T> Model<T> {
         self.scip.raw
     }
 
+    /// Includes a new file reader in the model. Problems in files with the given extension are
+    /// then read and written by the reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the reader. This should be a unique identifier.
+    /// * `desc` - A brief description of the reader.
+    /// * `extension` - The file extension the reader is responsible for.
+    /// * `reader` - The reader to be included.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the reader cannot be included in the current state.
+    pub fn include_reader(
+        &mut self,
+        name: &str,
+        desc: &str,
+        extension: &str,
+        reader: Box<dyn Reader>,
+    ) -> SCIPReader {
+        self.scip
+            .include_reader(name, desc, extension, reader)
+            .expect("Failed to include reader")
+    }
+
     /// Adds anything that could be added to the model (variables, constraints, plugins, etc.).
     pub fn add<R, O: CanBeAddedToModel<T, Return = R>>(&mut self, object: O) -> R {
         object.add(self)
@@ -1799,6 +3477,44 @@ impl<T> Model<T> {
         self.set_display_verbosity(0)
     }
 
+    /// Routes the output of the optimization model to the given message handler instead of the
+    /// standard output. Which messages are printed is still controlled by the verbosity.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The message handler to be installed.
+    #[allow(unused_mut)]
+    pub fn set_message_handler<H: MessageHandler + 'static>(mut self, handler: H) -> Self {
+        self.scip
+            .set_message_handler(Box::new(handler))
+            .expect("Failed to set message handler");
+        self
+    }
+
+    /// Captures the output of the optimization model in memory instead of printing it, it can be
+    /// read with [`Model::captured_output`]. This replaces any installed message handler.
+    #[allow(unused_mut)]
+    pub fn capture_output(mut self) -> Self {
+        let buffer = self.scip.captured_output.clone();
+        self.set_message_handler(OutputCapture { buffer })
+    }
+
+    /// Returns the output captured since [`Model::capture_output`] was called, or an empty
+    /// string if the output is not captured.
+    pub fn captured_output(&self) -> String {
+        self.scip.captured_output.borrow().clone()
+    }
+
+    /// Returns the lines of the output captured since [`Model::capture_output`] was called.
+    pub fn captured_lines(&self) -> Vec<String> {
+        self.scip
+            .captured_output
+            .borrow()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
     /// Sets the time limit for the optimization model.
     ///
     /// # Arguments
@@ -2039,7 +3755,7 @@ impl From<ParamSetting> for ffi::SCIP_PARAMSETTING {
 }
 
 /// An enum representing the objective sense of a SCIP optimization model.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ObjSense {
     /// The problem is a minimization problem.
     Minimize,
@@ -2057,6 +3773,17 @@ impl From<ObjSense> for ffi::SCIP_OBJSENSE {
     }
 }
 
+impl From<ffi::SCIP_OBJSENSE> for ObjSense {
+    /// Converts a `ffi::SCIP_OBJSENSE` value into its corresponding `ObjSense` enum variant.
+    fn from(val: ffi::SCIP_OBJSENSE) -> Self {
+        match val {
+            ffi::SCIP_Objsense_SCIP_OBJSENSE_MAXIMIZE => ObjSense::Maximize,
+            ffi::SCIP_Objsense_SCIP_OBJSENSE_MINIMIZE => ObjSense::Minimize,
+            _ => panic!("Unknown objective sense"),
+        }
+    }
+}
+
 /// Status of the LP solver
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LPStatus {
@@ -2208,6 +3935,33 @@ mod tests {
         assert_eq!(model.n_lp_iterations(), 0);
     }
 
+    #[test]
+    fn resume_after_limit() {
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add_var(0., 1., 1., "x", VarType::Binary);
+        let y = model.add_var(0., 1., 1., "y", VarType::Binary);
+        model.add_cons(vec![&x, &y], &[2., 2.], -f64::INFINITY, 3., "c");
+
+        let model = model.set_longint_param("limits/nodes", 1).unwrap().solve();
+        assert_eq!(model.status(), Status::NodeLimit);
+        assert_eq!(model.n_nodes(), 1);
+
+        let model = model
+            .set_longint_param("limits/nodes", -1)
+            .unwrap()
+            .try_solve()
+            .unwrap();
+        assert_eq!(model.status(), Status::Optimal);
+        assert!(model.n_nodes() > 1);
+        assert!((model.obj_val() - 1.0).abs() < 1e-6);
+
+        // solving a finished model again does nothing
+        let n_nodes = model.n_nodes();
+        let model = model.solve();
+        assert_eq!(model.status(), Status::Optimal);
+        assert_eq!(model.n_nodes(), n_nodes);
+    }
+
     #[test]
     fn set_memory_limit() {
         let model = Model::new()
@@ -2761,6 +4515,121 @@ mod tests {
         assert!((second_solved.obj_val() - expected_obj).abs() <= 1e-6);
     }
 
+    #[test]
+    fn reoptimization() {
+        let mut model = Model::default()
+            .hide_output()
+            .enable_reoptimization()
+            .maximize();
+        let x = model.add_var(0.0, 10.0, 1.0, "x", VarType::Integer);
+        let y = model.add_var(0.0, 10.0, 2.0, "y", VarType::Integer);
+        model.add_cons(vec![&x, &y], &[1.0, 1.0], -f64::INFINITY, 6.0, "c");
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 12.0).abs() < 1e-6);
+
+        let mut model = solved.free_reopt_solve().unwrap();
+        model
+            .chg_reopt_objective(ObjSense::Maximize, &[&x, &y], &[3.0, 1.0])
+            .unwrap();
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 18.0).abs() < 1e-6);
+
+        let mut model = solved.free_reopt_solve().unwrap();
+        model.restrict_var_ub(&x, 2.0).unwrap();
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 10.0).abs() < 1e-6);
+        assert!((solved.best_sol().unwrap().val(&x) - 2.0).abs() < 1e-6);
+
+        // the upper bound of x still applies, so x = 1 and y = 5
+        let mut model = solved.free_reopt_solve().unwrap();
+        model.restrict_var_lb(&y, 5.0).unwrap();
+        let solved = model.try_solve().unwrap();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 8.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn chg_var_data() {
+        let mut model = Model::default().hide_output().maximize();
+        let x = model.add_var(0.0, 10.0, 1.0, "x", VarType::Continuous);
+        let y = model.add_var(0.0, 10.0, 2.0, "y", VarType::Integer);
+        let z = model.add_var(0.0, 1.0, 0.0, "z", VarType::Binary);
+        model.add_cons(vec![&x, &y], &[1.0, 1.0], -f64::INFINITY, 6.5, "c");
+
+        model.chg_var_ub(&y, 3.0).unwrap();
+        model.chg_var_lb_global(&x, 1.0).unwrap();
+        model.chg_var_obj(&x, 3.0).unwrap();
+        assert!(!model.chg_var_type(&x, VarType::Integer).unwrap());
+        assert_eq!(x.var_type(), VarType::Integer);
+        assert_eq!(y.ub(), 3.0);
+        assert_eq!(x.obj(), 3.0);
+        assert!(model.del_var(&z).unwrap());
+        assert_eq!(model.n_vars(), 2);
+
+        // local bounds only exist during solving
+        assert_eq!(model.chg_var_ub_local(&y, 1.0), Err(Retcode::InvalidCall));
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        // x = 6, y = 0
+        assert!((solved.obj_val() - 18.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn deleted_var_and_cons_outlive_model() {
+        let mut model = Model::default().hide_output().maximize();
+        let x = model.add_var(0.0, 10.0, 1.0, "x", VarType::Integer);
+        let y = model.add_var(0.0, 10.0, 1.0, "y", VarType::Integer);
+        let c = model.add_cons(vec![&x, &y], &[1.0, 1.0], -f64::INFINITY, 6.0, "c");
+        let d = model.add_cons(vec![&x], &[1.0], -f64::INFINITY, 2.0, "d");
+
+        model.del_cons(&c).unwrap();
+        model.del_cons(&d).unwrap();
+        assert!(model.del_var(&y).unwrap());
+        assert_eq!(model.n_vars(), 1);
+        assert_eq!(model.n_conss(), 0);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 10.0).abs() < 1e-6);
+        drop(solved);
+
+        // the handles keep the deleted objects alive until they are dropped
+        assert_eq!(y.name(), "y");
+        assert_eq!(c.name(), "c");
+    }
+
+    #[test]
+    fn chg_cons_data() {
+        let mut model = Model::default().hide_output().maximize();
+        let x = model.add_var(0.0, 10.0, 1.0, "x", VarType::Integer);
+        let y = model.add_var(0.0, 10.0, 2.0, "y", VarType::Integer);
+        let c = model.add_cons(vec![&x], &[1.0], -f64::INFINITY, 6.0, "c");
+        let sos = model.add_cons_sos1(vec![&x, &y], None, "sos");
+
+        model.chg_rhs(&c, 4.0).unwrap();
+        model.chg_lhs(&c, 1.0).unwrap();
+        model.chg_coef(&c, &y, 1.0).unwrap();
+        assert_eq!(model.chg_rhs(&sos, 1.0), Err(Retcode::InvalidData));
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        // x + y in [1, 4] and at most one of them nonzero
+        assert!((solved.obj_val() - 8.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn free_reopt_solve_requires_reoptimization() {
+        let mut model = Model::default().hide_output();
+        model.add_var(0.0, 1.0, 1.0, "x", VarType::Binary);
+        let solved = model.solve();
+        assert_eq!(solved.free_reopt_solve().err(), Some(Retcode::InvalidCall));
+    }
+
     #[test]
     fn best_bound() {
         let model = create_model();
@@ -2875,4 +4744,104 @@ mod tests {
         assert_eq!(solution.val(&x3), 0.);
         assert_eq!(solved_model.obj_val(), 10.);
     }
+
+    #[test]
+    fn knapsack_constraint() {
+        let mut model = minimal_model().hide_output().maximize();
+        let vars =
+            [3., 4., 5., 6.].map(|value| model.add_var(0., 1., value, "item", VarType::Binary));
+        let knapsack = model.add_cons_knapsack(vars.iter().collect(), &[2, 3, 4, 5], 6, "knapsack");
+
+        assert_eq!(knapsack.knapsack_capacity(), Some(6));
+        let mut weights = knapsack
+            .knapsack_items()
+            .unwrap()
+            .into_iter()
+            .map(|(_, weight)| weight)
+            .collect::<Vec<_>>();
+        weights.sort();
+        assert_eq!(weights, vec![2, 3, 4, 5]);
+        assert_eq!(knapsack.varbound_coef(), None);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.obj_val(), 8.);
+    }
+
+    #[test]
+    fn varbound_and_bounddisjunction_constraints() {
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let y = model.add_var(0., 1., -2., "y", VarType::Binary);
+
+        // x <= 10 y
+        let varbound = model.add_cons_varbound(&x, &y, -10., f64::NEG_INFINITY, 0., "vb");
+        assert_eq!(varbound.varbound_var(), Some(x.clone()));
+        assert_eq!(varbound.varbound_vbd_var(), Some(y.clone()));
+        assert_eq!(varbound.varbound_coef(), Some(-10.));
+        let infinity = unsafe { crate::ffi::SCIPinfinity(model.scip.raw) };
+        assert!(varbound.varbound_lhs().unwrap() <= -infinity);
+        assert_eq!(varbound.varbound_rhs(), Some(0.));
+
+        // x <= 3 or y <= 0
+        let disjunction = model.add_cons_bounddisjunction(
+            vec![(&x, BoundType::Upper, 3.), (&y, BoundType::Upper, 0.)],
+            "disjunction",
+        );
+        assert_eq!(
+            disjunction.bounddisjunction_literals(),
+            Some(vec![
+                (x.clone(), BoundType::Upper, 3.),
+                (y.clone(), BoundType::Upper, 0.)
+            ])
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn linking_constraint() {
+        let mut model = minimal_model().hide_output().maximize();
+        let z = model.add_var(0., 10., 1., "z", VarType::Integer);
+        let bins = (0..3)
+            .map(|i| model.add_var(0., 1., 0., &format!("b{i}"), VarType::Binary))
+            .collect::<Vec<_>>();
+        let linking = model.add_cons_linking(&z, bins.iter().collect(), &[2., 5., 7.], "link");
+        model.add_cons(vec![&z], &[1.], f64::NEG_INFINITY, 6., "z_limit");
+
+        assert_eq!(linking.linking_var(), Some(z.clone()));
+        let terms = linking.linking_terms().unwrap();
+        assert_eq!(
+            terms.iter().map(|(_, val)| *val).collect::<Vec<_>>(),
+            vec![2., 5., 7.]
+        );
+        assert_eq!(terms[1].0, bins[1]);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.obj_val(), 5.);
+    }
+
+    #[test]
+    fn del_cons() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("test")
+            .set_obj_sense(ObjSense::Maximize);
+
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let c1 = model.add_cons(vec![&x], &[1.], -f64::INFINITY, 2., "c1");
+        model.add_cons(vec![&x], &[1.], -f64::INFINITY, 5., "c2");
+        assert_eq!(model.n_conss(), 2);
+
+        model.del_cons(&c1).unwrap();
+        assert_eq!(model.n_conss(), 1);
+
+        let solved_model = model.solve();
+        assert_eq!(solved_model.status(), Status::Optimal);
+        assert_eq!(solved_model.obj_val(), 5.);
+    }
 }
@@ -0,0 +1,74 @@
+use crate::{Constraint, Variable};
+
+/// The formulation of a piecewise-linear function added with
+/// [`Model::add_piecewise_linear`](crate::Model::add_piecewise_linear).
+///
+/// Both formulations describe the point `(x, y)` as a convex combination of the breakpoints with
+/// one weight variable (lambda) per breakpoint, they differ in how the combination is restricted
+/// to two adjacent breakpoints.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PiecewiseFormulation {
+    /// The lambda variables form an SOS2 constraint.
+    Sos2,
+    /// A binary variable per segment selects the segment, the lambda variables of the other
+    /// segments are forced to zero.
+    Binary,
+}
+
+/// The variables and constraints of a piecewise-linear function added with
+/// [`Model::add_piecewise_linear`](crate::Model::add_piecewise_linear).
+#[derive(Debug, Clone)]
+pub struct PiecewiseLinear {
+    pub(crate) lambdas: Vec<Variable>,
+    pub(crate) binaries: Vec<Variable>,
+    pub(crate) conss: Vec<Constraint>,
+}
+
+impl PiecewiseLinear {
+    /// Returns the weight variables of the breakpoints.
+    pub fn lambdas(&self) -> &[Variable] {
+        &self.lambdas
+    }
+
+    /// Returns the segment variables of the binary formulation, empty for the SOS2 formulation.
+    pub fn binaries(&self) -> &[Variable] {
+        &self.binaries
+    }
+
+    /// Returns the constraints of the formulation.
+    pub fn conss(&self) -> &[Constraint] {
+        &self.conss
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimal_model;
+    use crate::prelude::*;
+
+    #[test]
+    fn nonconvex_cost_curve() {
+        let breakpoints = [(0.0, 0.0), (4.0, 2.0), (6.0, 6.0), (10.0, 8.0)];
+        for formulation in [PiecewiseFormulation::Sos2, PiecewiseFormulation::Binary] {
+            let mut model = minimal_model().hide_output().maximize();
+            let x = model.add(var().name("x").cont(0.0..=10.0));
+            let y = model.add(var().name("y").cont(..).obj(1.0));
+            model.add(cons().coef(&x, 1.0).le(5.0));
+
+            let pwl = model.add_piecewise_linear(&x, &y, &breakpoints, formulation);
+            assert_eq!(pwl.lambdas().len(), 4);
+            match formulation {
+                PiecewiseFormulation::Sos2 => assert!(pwl.binaries().is_empty()),
+                PiecewiseFormulation::Binary => assert_eq!(pwl.binaries().len(), 3),
+            }
+
+            let solved = model.solve();
+            assert_eq!(solved.status(), Status::Optimal);
+            // a convex combination of (0, 0) and (6, 6) would give 5
+            assert!((solved.obj_val() - 4.0).abs() < 1e-6);
+            let sol = solved.best_sol().unwrap();
+            assert!((sol.val(&x) - 5.0).abs() < 1e-6);
+        }
+    }
+}
@@ -1,16 +1,34 @@
+pub use crate::benders::*;
 pub use crate::branchrule::*;
+pub use crate::builder::benders::{benders, benderscut};
 pub use crate::builder::branchrule::branchrule;
+pub use crate::builder::conflicthdlr::conflicthdlr;
 pub use crate::builder::cons::cons;
+pub use crate::builder::conshdlr::conshdlr;
+pub use crate::builder::cutsel::cutsel;
 pub use crate::builder::eventhdlr::eventhdlr;
 pub use crate::builder::heur::heur;
+pub use crate::builder::indicator::indicator;
+pub use crate::builder::logic::{cons_and, cons_logicor, cons_or, cons_pseudoboolean, cons_xor};
 pub use crate::builder::nodesel::nodesel;
+pub use crate::builder::presol::presol;
 pub use crate::builder::pricer::pricer;
+pub use crate::builder::prop::prop;
+pub use crate::builder::reader::reader;
+pub use crate::builder::relax::relax;
 pub use crate::builder::row::row;
 pub use crate::builder::sepa::sepa;
 pub use crate::builder::var::var;
+pub use crate::conflicthdlr::*;
 pub use crate::conshdlr::*;
+pub use crate::constraint::{ConsKind, SetPPCType};
+pub use crate::copy::CopyOptions;
+pub use crate::cutsel::*;
 pub use crate::eventhdlr::*;
+pub use crate::expr::Expr;
 pub use crate::heuristic::*;
+pub use crate::linexpr::LinExpr;
+pub use crate::message::MessageHandler;
 pub use crate::model::Model;
 pub use crate::model::ModelWithProblem;
 pub use crate::model::ObjSense;
@@ -20,8 +38,14 @@ pub use crate::model::Solving;
 pub use crate::model::WithSolutions;
 pub use crate::model::WithSolvingStats;
 pub use crate::nodesel::*;
+pub use crate::piecewise::PiecewiseFormulation;
+pub use crate::presolver::*;
 pub use crate::pricer::*;
+pub use crate::propagator::*;
+pub use crate::reader::*;
+pub use crate::relaxator::*;
 pub use crate::retcode::Retcode;
 pub use crate::separator::*;
 pub use crate::status::Status;
+pub use crate::subscip::SubScip;
 pub use crate::variable::VarType;
@@ -0,0 +1,260 @@
+use crate::{Model, Solving, ffi};
+use std::ops::{BitOr, BitOrAssign};
+
+/// A trait for defining custom presolvers.
+///
+/// Presolvers are called in the presolving rounds on the transformed problem. They can fix
+/// ([`Model::fix_var`]), aggregate ([`Model::aggregate_vars`]) and tighten
+/// ([`Model::tighten_var_lb`], [`Model::tighten_var_ub`]) variables or delete constraints
+/// ([`ProblemOrSolving::del_cons`](crate::ProblemOrSolving::del_cons)), and report the number of
+/// reductions they found in `changes`.
+pub trait Presolver {
+    /// Executes the presolver.
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance, holding the transformed problem.
+    /// * `presol` - the internal presolver object.
+    /// * `timing` - the presolving round type of the current call.
+    /// * `changes` - the reductions found in this call should be counted here.
+    ///
+    /// # Returns
+    /// * `PresolveResult` indicating the result of the presolving round.
+    fn execute(
+        &mut self,
+        model: Model<Solving>,
+        presol: SCIPPresolver,
+        timing: PresolTiming,
+        changes: &mut PresolveChanges,
+    ) -> PresolveResult;
+}
+
+/// The result of a presolving call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresolveResult {
+    /// The problem was detected to be unbounded.
+    Unbounded,
+    /// The problem was detected to be infeasible.
+    CutOff,
+    /// At least one reduction was found.
+    Success,
+    /// The presolving ran, but did not find any reductions.
+    DidNotFind,
+    /// The presolving was skipped.
+    DidNotRun,
+    /// The presolving was skipped, but should be called again.
+    Delayed,
+}
+
+impl From<PresolveResult> for ffi::SCIP_Result {
+    fn from(result: PresolveResult) -> Self {
+        match result {
+            PresolveResult::Unbounded => ffi::SCIP_Result_SCIP_UNBOUNDED,
+            PresolveResult::CutOff => ffi::SCIP_Result_SCIP_CUTOFF,
+            PresolveResult::Success => ffi::SCIP_Result_SCIP_SUCCESS,
+            PresolveResult::DidNotFind => ffi::SCIP_Result_SCIP_DIDNOTFIND,
+            PresolveResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+            PresolveResult::Delayed => ffi::SCIP_Result_SCIP_DELAYED,
+        }
+    }
+}
+
+/// The number of reductions found in a presolving call, these are added to SCIP's counters.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PresolveChanges {
+    /// Number of fixed variables.
+    pub n_fixed_vars: i32,
+    /// Number of aggregated variables.
+    pub n_aggr_vars: i32,
+    /// Number of variable type changes.
+    pub n_chg_var_types: i32,
+    /// Number of bound changes.
+    pub n_chg_bds: i32,
+    /// Number of domain holes added.
+    pub n_add_holes: i32,
+    /// Number of deleted constraints.
+    pub n_del_conss: i32,
+    /// Number of added constraints.
+    pub n_add_conss: i32,
+    /// Number of upgraded constraints.
+    pub n_upgd_conss: i32,
+    /// Number of changed coefficients.
+    pub n_chg_coefs: i32,
+    /// Number of changed left or right hand sides.
+    pub n_chg_sides: i32,
+}
+
+/// The timing masks of a presolving method, describing in which presolving rounds it is called.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PresolTiming(u32);
+
+impl PresolTiming {
+    /// never call the presolving method
+    pub const NONE: Self = PresolTiming(ffi::SCIP_PRESOLTIMING_NONE);
+    /// call the presolving method in fast rounds
+    pub const FAST: Self = PresolTiming(ffi::SCIP_PRESOLTIMING_FAST);
+    /// call the presolving method in medium rounds
+    pub const MEDIUM: Self = PresolTiming(ffi::SCIP_PRESOLTIMING_MEDIUM);
+    /// call the presolving method in exhaustive rounds
+    pub const EXHAUSTIVE: Self = PresolTiming(ffi::SCIP_PRESOLTIMING_EXHAUSTIVE);
+    /// call the presolving method in the final round
+    pub const FINAL: Self = PresolTiming(ffi::SCIP_PRESOLTIMING_FINAL);
+    /// call the presolving method in fast, medium and exhaustive rounds
+    pub const ALWAYS: Self = PresolTiming(ffi::SCIP_PRESOLTIMING_ALWAYS);
+}
+
+impl BitOr for PresolTiming {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        PresolTiming(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for PresolTiming {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<PresolTiming> for u32 {
+    fn from(mask: PresolTiming) -> Self {
+        mask.0
+    }
+}
+
+impl From<u32> for PresolTiming {
+    fn from(mask: u32) -> Self {
+        PresolTiming(mask)
+    }
+}
+
+/// The outcome of aggregating two variables with [`Model::aggregate_vars`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aggregation {
+    /// The aggregation proved the problem to be infeasible.
+    pub infeasible: bool,
+    /// The equation defining the aggregation is now redundant and its constraint can be deleted.
+    pub redundant: bool,
+    /// The aggregation was performed.
+    pub aggregated: bool,
+}
+
+/// A wrapper struct for the internal ffi::SCIP_PRESOL
+#[derive(Debug)]
+pub struct SCIPPresolver {
+    pub(crate) raw: *mut ffi::SCIP_PRESOL,
+}
+
+impl SCIPPresolver {
+    /// Returns the internal raw pointer of the presolver.
+    pub fn inner(&self) -> *mut ffi::SCIP_PRESOL {
+        self.raw
+    }
+
+    /// Returns the name of the presolver.
+    pub fn name(&self) -> String {
+        unsafe {
+            let name = ffi::SCIPpresolGetName(self.raw);
+            std::ffi::CStr::from_ptr(name).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the description of the presolver.
+    pub fn desc(&self) -> String {
+        unsafe {
+            let desc = ffi::SCIPpresolGetDesc(self.raw);
+            std::ffi::CStr::from_ptr(desc).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the priority of the presolver.
+    pub fn priority(&self) -> i32 {
+        unsafe { ffi::SCIPpresolGetPriority(self.raw) }
+    }
+
+    /// Returns the maximal number of presolving rounds of the presolver.
+    pub fn maxrounds(&self) -> i32 {
+        unsafe { ffi::SCIPpresolGetMaxrounds(self.raw) }
+    }
+
+    /// Returns the timing mask of the presolver.
+    pub fn timing(&self) -> PresolTiming {
+        unsafe { ffi::SCIPpresolGetTiming(self.raw) }.into()
+    }
+
+    /// Returns the number of times the presolver was called.
+    pub fn n_calls(&self) -> i32 {
+        unsafe { ffi::SCIPpresolGetNCalls(self.raw) }
+    }
+
+    /// Returns the number of variables fixed by the presolver.
+    pub fn n_fixed_vars(&self) -> i32 {
+        unsafe { ffi::SCIPpresolGetNFixedVars(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{Tightening, minimal_model};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// In `max 2x + y s.t. x + y <= 4`, the variable `y` is dominated by `x` and can be fixed to 0.
+    struct DominatedColumnPresolver {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Presolver for DominatedColumnPresolver {
+        fn execute(
+            &mut self,
+            mut model: Model<Solving>,
+            presol: SCIPPresolver,
+            _timing: PresolTiming,
+            changes: &mut PresolveChanges,
+        ) -> PresolveResult {
+            assert_eq!(presol.name(), "dominated");
+            self.calls.set(self.calls.get() + 1);
+
+            let y = model
+                .orig_vars()
+                .into_iter()
+                .find(|v| v.name() == "y")
+                .unwrap();
+            match model.fix_var(&y, 0.0).unwrap() {
+                Tightening::Infeasible => PresolveResult::CutOff,
+                Tightening::Tightened => {
+                    changes.n_fixed_vars += 1;
+                    PresolveResult::Success
+                }
+                Tightening::Redundant => PresolveResult::DidNotFind,
+            }
+        }
+    }
+
+    #[test]
+    fn presolver_fixes_dominated_var() {
+        let calls = Rc::new(Cell::new(0));
+
+        let mut model = minimal_model().maximize();
+        let x = model.add(var().int(0..=10).obj(2.0).name("x"));
+        let y = model.add(var().int(0..=10).obj(1.0).name("y"));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).le(4.0));
+        model.add(
+            presol(DominatedColumnPresolver {
+                calls: calls.clone(),
+            })
+            .name("dominated")
+            .priority(10000000)
+            .timing(PresolTiming::FAST),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 8.0).abs() < 1e-6);
+        assert!(calls.get() > 0);
+        let sol = solved.best_sol().unwrap();
+        assert_eq!(sol.val(&y), 0.0);
+    }
+}
@@ -0,0 +1,206 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{Event, EventMask, Eventhdlr, Model, SCIPEventhdlr, Solving, ffi};
+
+/// A snapshot of the solving progress, passed to the callbacks registered with
+/// [`Model::on_progress`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    /// What triggered the report.
+    pub event: ProgressEvent,
+    /// The objective value of the best solution found so far.
+    pub primal_bound: f64,
+    /// The best bound proven so far.
+    pub dual_bound: f64,
+    /// The relative gap between the primal and the dual bound.
+    pub gap: f64,
+    /// The number of nodes processed so far.
+    pub n_nodes: usize,
+    /// The solving time in seconds.
+    pub time: f64,
+    /// The name of the heuristic that found the new incumbent, `None` if it was not found by a
+    /// heuristic (e.g. by the LP) or the report is not about a new incumbent.
+    pub heuristic: Option<String>,
+}
+
+/// What triggered a progress report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// A new best solution was found.
+    NewIncumbent,
+    /// The dual bound was improved.
+    DualBoundImproved,
+    /// The given number of nodes was processed since the last node report.
+    Nodes,
+}
+
+/// A point of the primal and dual bound trace of a solve, see [`Model::bound_trace`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundTracePoint {
+    /// The solving time in seconds.
+    pub time: f64,
+    /// The number of nodes processed so far.
+    pub n_nodes: usize,
+    /// The objective value of the best solution found so far.
+    pub primal_bound: f64,
+    /// The best bound proven so far.
+    pub dual_bound: f64,
+}
+
+/// A progress callback together with its node frequency.
+struct ProgressCallback {
+    node_freq: usize,
+    callback: Box<dyn FnMut(Progress)>,
+}
+
+/// Records the bound trace of a model and calls its progress callbacks.
+#[derive(Default)]
+pub(crate) struct ProgressRecorder {
+    callbacks: Vec<ProgressCallback>,
+    pub(crate) trace: Vec<BoundTracePoint>,
+}
+
+impl std::fmt::Debug for ProgressRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressRecorder")
+            .field("n_callbacks", &self.callbacks.len())
+            .field("trace", &self.trace)
+            .finish()
+    }
+}
+
+impl ProgressRecorder {
+    pub(crate) fn add_callback(&mut self, node_freq: usize, callback: Box<dyn FnMut(Progress)>) {
+        self.callbacks.push(ProgressCallback {
+            node_freq,
+            callback,
+        });
+    }
+}
+
+/// Feeds the [`ProgressRecorder`] of a model from SCIP's events.
+pub(crate) struct ProgressEventhdlr {
+    pub(crate) recorder: Rc<RefCell<ProgressRecorder>>,
+}
+
+impl Eventhdlr for ProgressEventhdlr {
+    fn get_type(&self) -> EventMask {
+        EventMask::BEST_SOL_FOUND | EventMask::DUAL_BOUND_IMPROVED | EventMask::NODE_SOLVED
+    }
+
+    fn execute(&mut self, model: Model<Solving>, _eventhdlr: SCIPEventhdlr, event: Event) {
+        let event_type = event.event_type();
+        let scip = &model.scip;
+        let n_nodes = scip.n_nodes();
+
+        let progress_event = if event_type.matches(EventMask::BEST_SOL_FOUND) {
+            ProgressEvent::NewIncumbent
+        } else if event_type.matches(EventMask::DUAL_BOUND_IMPROVED) {
+            ProgressEvent::DualBoundImproved
+        } else {
+            ProgressEvent::Nodes
+        };
+
+        let heuristic = match progress_event {
+            ProgressEvent::NewIncumbent => unsafe {
+                let heur = ffi::SCIPsolGetHeur(ffi::SCIPeventGetSol(event.raw));
+                (!heur.is_null()).then(|| {
+                    std::ffi::CStr::from_ptr(ffi::SCIPheurGetName(heur))
+                        .to_string_lossy()
+                        .into_owned()
+                })
+            },
+            _ => None,
+        };
+
+        let progress = Progress {
+            event: progress_event,
+            primal_bound: scip.obj_val(),
+            dual_bound: scip.best_bound(),
+            gap: scip.gap(),
+            n_nodes,
+            time: scip.solving_time(),
+            heuristic,
+        };
+
+        let mut recorder = self.recorder.borrow_mut();
+        if progress_event != ProgressEvent::Nodes {
+            recorder.trace.push(BoundTracePoint {
+                time: progress.time,
+                n_nodes,
+                primal_bound: progress.primal_bound,
+                dual_bound: progress.dual_bound,
+            });
+        }
+        for callback in recorder.callbacks.iter_mut() {
+            let due = match progress_event {
+                ProgressEvent::Nodes => {
+                    callback.node_freq > 0 && n_nodes.is_multiple_of(callback.node_freq)
+                }
+                _ => true,
+            };
+            if due {
+                (callback.callback)(progress.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn progress_reports_and_bound_trace() {
+        let reports = Rc::new(RefCell::new(vec![]));
+
+        let mut model = Model::new()
+            .hide_output()
+            .set_longint_param("limits/nodes", 200)
+            .unwrap()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap();
+        let reports_clone = reports.clone();
+        model.on_progress(10, move |p| reports_clone.borrow_mut().push(p));
+
+        let solved = model.solve();
+        assert!(matches!(
+            solved.status(),
+            Status::Optimal | Status::NodeLimit
+        ));
+
+        let reports = reports.borrow();
+        assert!(
+            reports
+                .iter()
+                .any(|p| p.event == ProgressEvent::NewIncumbent)
+        );
+        assert!(
+            reports
+                .iter()
+                .filter(|p| p.event == ProgressEvent::Nodes)
+                .all(|p| p.n_nodes.is_multiple_of(10))
+        );
+        assert!(
+            reports
+                .iter()
+                .filter(|p| p.event != ProgressEvent::NewIncumbent)
+                .all(|p| p.heuristic.is_none())
+        );
+
+        let trace = solved.bound_trace();
+        assert!(!trace.is_empty());
+        assert!(trace.windows(2).all(|w| w[0].time <= w[1].time));
+        // the problem is a minimization, so the primal bound never increases
+        assert!(
+            trace
+                .windows(2)
+                .all(|w| w[1].primal_bound <= w[0].primal_bound)
+        );
+        let last = trace.last().unwrap();
+        assert!((last.primal_bound - solved.obj_val()).abs() < 1e-6);
+    }
+}
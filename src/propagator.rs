@@ -0,0 +1,331 @@
+use crate::scip::ScipPtr;
+use crate::{
+    BoundType, Model, PresolveChanges, PresolveResult, Retcode, Solving, Variable, ffi, scip_call,
+};
+use std::ops::{BitOr, BitOrAssign};
+use std::rc::Rc;
+
+/// A trait for defining custom propagators.
+///
+/// Propagators tighten the local bounds of variables based on reasoning that does not belong to a
+/// single constraint. Bounds should be tightened with [`Model::infer_var_lb`] and
+/// [`Model::infer_var_ub`], so that SCIP can ask the propagator for the reason of the
+/// tightening during conflict analysis (see [`resolve_propagation`](Propagator::resolve_propagation)).
+pub trait Propagator {
+    /// Executes the propagator.
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance in `Solving` stage.
+    /// * `prop` - the internal propagator object.
+    /// * `timing` - the point in the node processing at which the propagator is called.
+    ///
+    /// # Returns
+    /// * `PropagationResult` indicating the result of the propagation.
+    fn propagate(
+        &mut self,
+        model: Model<Solving>,
+        prop: SCIPPropagator,
+        timing: PropTiming,
+    ) -> PropagationResult;
+
+    /// Presolves the problem, only called if the propagator was included with a presolving timing.
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance.
+    /// * `prop` - the internal propagator object.
+    /// * `changes` - the reductions found in this call should be counted here.
+    ///
+    /// # Returns
+    /// * `PresolveResult` indicating the result of the presolving, defaults to `DidNotRun`.
+    fn presolve(
+        &mut self,
+        _model: Model<Solving>,
+        _prop: SCIPPropagator,
+        _changes: &mut PresolveChanges,
+    ) -> PresolveResult {
+        PresolveResult::DidNotRun
+    }
+
+    /// Explains a bound change that was inferred by this propagator, by adding the bounds that
+    /// implied it to the conflict (see [`Inference`]).
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance in `Solving` stage.
+    /// * `prop` - the internal propagator object.
+    /// * `inference` - the bound change that should be explained.
+    ///
+    /// # Returns
+    /// * `ResolvePropResult` indicating whether the reason was added, defaults to `DidNotFind`.
+    fn resolve_propagation(
+        &mut self,
+        _model: Model<Solving>,
+        _prop: SCIPPropagator,
+        _inference: &Inference,
+    ) -> ResolvePropResult {
+        ResolvePropResult::DidNotFind
+    }
+}
+
+/// The result of a propagation call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropagationResult {
+    /// The current node is infeasible and can be cut off.
+    CutOff,
+    /// At least one domain reduction was found.
+    ReducedDom,
+    /// The propagation ran, but did not find any domain reductions.
+    DidNotFind,
+    /// The propagation was skipped.
+    DidNotRun,
+    /// The propagation was skipped, but should be called again.
+    Delayed,
+}
+
+impl From<PropagationResult> for ffi::SCIP_Result {
+    fn from(result: PropagationResult) -> Self {
+        match result {
+            PropagationResult::CutOff => ffi::SCIP_Result_SCIP_CUTOFF,
+            PropagationResult::ReducedDom => ffi::SCIP_Result_SCIP_REDUCEDDOM,
+            PropagationResult::DidNotFind => ffi::SCIP_Result_SCIP_DIDNOTFIND,
+            PropagationResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+            PropagationResult::Delayed => ffi::SCIP_Result_SCIP_DELAYED,
+        }
+    }
+}
+
+/// The result of resolving a propagation for conflict analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolvePropResult {
+    /// The reason of the bound change was added to the conflict.
+    Success,
+    /// The reason of the bound change could not be determined.
+    DidNotFind,
+}
+
+impl From<ResolvePropResult> for ffi::SCIP_Result {
+    fn from(result: ResolvePropResult) -> Self {
+        match result {
+            ResolvePropResult::Success => ffi::SCIP_Result_SCIP_SUCCESS,
+            ResolvePropResult::DidNotFind => ffi::SCIP_Result_SCIP_DIDNOTFIND,
+        }
+    }
+}
+
+/// The timing masks of a propagator, describing when in the node processing it is called.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PropTiming(u32);
+
+impl PropTiming {
+    /// call propagator before the LP is solved
+    pub const BEFORE_LP: Self = PropTiming(ffi::SCIP_PROPTIMING_BEFORELP);
+    /// call propagator after each LP solving during cut-and-price loop
+    pub const DURING_LP_LOOP: Self = PropTiming(ffi::SCIP_PROPTIMING_DURINGLPLOOP);
+    /// call propagator after the cut-and-price loop was finished
+    pub const AFTER_LP_LOOP: Self = PropTiming(ffi::SCIP_PROPTIMING_AFTERLPLOOP);
+    /// call propagator after the processing of a node with solved LP was finished
+    pub const AFTER_LP_NODE: Self = PropTiming(ffi::SCIP_PROPTIMING_AFTERLPNODE);
+    /// call propagator at all of the above points
+    pub const ALWAYS: Self = PropTiming(ffi::SCIP_PROPTIMING_ALWAYS);
+
+    /// Returns whether all the timings of `other` are contained in this mask.
+    pub fn contains(&self, other: PropTiming) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for PropTiming {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        PropTiming(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for PropTiming {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<PropTiming> for u32 {
+    fn from(mask: PropTiming) -> Self {
+        mask.0
+    }
+}
+
+impl From<u32> for PropTiming {
+    fn from(mask: u32) -> Self {
+        PropTiming(mask)
+    }
+}
+
+/// The outcome of tightening a variable bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tightening {
+    /// The new bound makes the domain of the variable empty, the current node can be cut off.
+    Infeasible,
+    /// The bound was tightened.
+    Tightened,
+    /// The new bound is not tighter than the current one, nothing changed.
+    Redundant,
+}
+
+/// A bound change inferred by a propagator, which has to be explained during conflict analysis.
+pub struct Inference {
+    /// The variable whose bound was changed.
+    pub var: Variable,
+    /// The information given when the bound change was inferred.
+    pub info: i32,
+    /// The bound of the variable that was changed.
+    pub bound_type: BoundType,
+    /// The bound that has to be explained, it can be weaker than the inferred bound.
+    pub relaxed_bound: f64,
+    pub(crate) bdchgidx: *mut ffi::SCIP_BDCHGIDX,
+    pub(crate) scip: Rc<ScipPtr>,
+}
+
+impl Inference {
+    /// Adds the lower bound of the given variable, at the time of the inference, to the reason.
+    pub fn add_lb_reason(&self, var: &Variable) -> Result<(), Retcode> {
+        scip_call! { ffi::SCIPaddConflictLb(self.scip.raw, var.raw, self.bdchgidx) };
+        Ok(())
+    }
+
+    /// Adds the upper bound of the given variable, at the time of the inference, to the reason.
+    pub fn add_ub_reason(&self, var: &Variable) -> Result<(), Retcode> {
+        scip_call! { ffi::SCIPaddConflictUb(self.scip.raw, var.raw, self.bdchgidx) };
+        Ok(())
+    }
+
+    /// Returns the lower bound of the given variable at the time of the inference.
+    pub fn lb_at_inference(&self, var: &Variable) -> f64 {
+        unsafe { ffi::SCIPgetVarLbAtIndex(self.scip.raw, var.raw, self.bdchgidx, 0) }
+    }
+
+    /// Returns the upper bound of the given variable at the time of the inference.
+    pub fn ub_at_inference(&self, var: &Variable) -> f64 {
+        unsafe { ffi::SCIPgetVarUbAtIndex(self.scip.raw, var.raw, self.bdchgidx, 0) }
+    }
+}
+
+/// A wrapper struct for the internal ffi::SCIP_PROP
+#[derive(Debug)]
+pub struct SCIPPropagator {
+    pub(crate) raw: *mut ffi::SCIP_PROP,
+}
+
+impl SCIPPropagator {
+    /// Returns the internal raw pointer of the propagator.
+    pub fn inner(&self) -> *mut ffi::SCIP_PROP {
+        self.raw
+    }
+
+    /// Returns the name of the propagator.
+    pub fn name(&self) -> String {
+        unsafe {
+            let name = ffi::SCIPpropGetName(self.raw);
+            std::ffi::CStr::from_ptr(name).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the description of the propagator.
+    pub fn desc(&self) -> String {
+        unsafe {
+            let desc = ffi::SCIPpropGetDesc(self.raw);
+            std::ffi::CStr::from_ptr(desc).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the priority of the propagator.
+    pub fn priority(&self) -> i32 {
+        unsafe { ffi::SCIPpropGetPriority(self.raw) }
+    }
+
+    /// Returns the frequency of the propagator.
+    pub fn freq(&self) -> i32 {
+        unsafe { ffi::SCIPpropGetFreq(self.raw) }
+    }
+
+    /// Returns the timing mask of the propagator.
+    pub fn timing(&self) -> PropTiming {
+        unsafe { ffi::SCIPpropGetTimingmask(self.raw) }.into()
+    }
+
+    /// Returns the number of times the propagator was called.
+    pub fn n_calls(&self) -> i64 {
+        unsafe { ffi::SCIPpropGetNCalls(self.raw) }
+    }
+
+    /// Returns the number of domain reductions found by the propagator.
+    pub fn n_domreds_found(&self) -> i64 {
+        unsafe { ffi::SCIPpropGetNDomredsFound(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ModelWithProblem, minimal_model};
+    use std::cell::Cell;
+
+    /// Infers `x <= 4` from the constraint `x + y <= 4` with `y >= 0`.
+    struct UpperBoundProp {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Propagator for UpperBoundProp {
+        fn propagate(
+            &mut self,
+            mut model: Model<Solving>,
+            prop: SCIPPropagator,
+            timing: PropTiming,
+        ) -> PropagationResult {
+            assert_eq!(prop.name(), "ub_prop");
+            assert!(PropTiming::BEFORE_LP.contains(timing));
+            self.calls.set(self.calls.get() + 1);
+
+            let x = model
+                .orig_vars()
+                .into_iter()
+                .find(|v| v.name() == "x")
+                .unwrap();
+            match model.infer_var_ub(&x, 4.0, &prop, 0).unwrap() {
+                Tightening::Infeasible => PropagationResult::CutOff,
+                Tightening::Tightened => PropagationResult::ReducedDom,
+                Tightening::Redundant => PropagationResult::DidNotFind,
+            }
+        }
+    }
+
+    #[test]
+    fn propagator_infers_bound() {
+        let calls = Rc::new(Cell::new(0));
+
+        let mut model = minimal_model().set_presolving(ParamSetting::Off).maximize();
+        let x = model.add(var().int(0..=10).obj(2.0).name("x"));
+        let y = model.add(var().int(0..=10).obj(1.0).name("y"));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).le(4.0));
+        model.add(
+            prop(UpperBoundProp {
+                calls: calls.clone(),
+            })
+            .name("ub_prop")
+            .timing(PropTiming::BEFORE_LP),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 8.0).abs() < 1e-6);
+        assert!(calls.get() > 0);
+    }
+
+    #[test]
+    fn timing_masks() {
+        let timing = PropTiming::BEFORE_LP | PropTiming::AFTER_LP_LOOP;
+        assert!(timing.contains(PropTiming::BEFORE_LP));
+        assert!(!timing.contains(PropTiming::DURING_LP_LOOP));
+        assert!(PropTiming::ALWAYS.contains(timing));
+        assert_eq!(u32::from(PresolTiming::FAST | PresolTiming::MEDIUM), 12);
+    }
+}
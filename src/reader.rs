@@ -0,0 +1,247 @@
+use std::io::Write;
+
+use crate::{Constraint, Model, PluginsIncluded, ProblemCreated, Retcode, Variable, ffi};
+
+/// A trait for defining custom file readers and writers.
+///
+/// A reader is registered for a file extension: [`Model::read_prob`] on a file with that
+/// extension calls [`read`](Reader::read), and [`ModelWithProblem::write`](crate::ModelWithProblem::write)
+/// with that extension calls [`write`](Reader::write).
+pub trait Reader {
+    /// Reads a problem from the given file.
+    ///
+    /// The reader has to create the problem with [`Model::create_prob`] and then add its
+    /// variables and constraints to the returned model.
+    ///
+    /// # Arguments
+    /// * `model` - the model the problem should be read into.
+    /// * `path` - the path of the file to read.
+    ///
+    /// # Returns
+    /// * `ReaderResult::Success` if the problem was read, defaults to `ReaderResult::DidNotRun`.
+    /// * `Err(Retcode::ReadError)` (or `Retcode::NoFile`) if the file could not be read.
+    fn read(
+        &mut self,
+        _model: Model<PluginsIncluded>,
+        _path: &str,
+    ) -> Result<ReaderResult, Retcode> {
+        Ok(ReaderResult::DidNotRun)
+    }
+
+    /// Writes the problem to the given writer.
+    ///
+    /// # Arguments
+    /// * `model` - the model holding the problem to write.
+    /// * `vars` - the variables of the problem.
+    /// * `conss` - the constraints of the problem.
+    /// * `writer` - where the problem should be written to.
+    ///
+    /// # Returns
+    /// * `ReaderResult::Success` if the problem was written, defaults to `ReaderResult::DidNotRun`.
+    fn write(
+        &mut self,
+        _model: Model<ProblemCreated>,
+        _vars: &[Variable],
+        _conss: &[Constraint],
+        _writer: &mut dyn Write,
+    ) -> Result<ReaderResult, Retcode> {
+        Ok(ReaderResult::DidNotRun)
+    }
+}
+
+/// The result of reading or writing a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReaderResult {
+    /// The file was read or written.
+    Success,
+    /// The reader does not support the operation, SCIP reports that no suitable reader was found.
+    DidNotRun,
+}
+
+impl From<ReaderResult> for ffi::SCIP_Result {
+    fn from(result: ReaderResult) -> Self {
+        match result {
+            ReaderResult::Success => ffi::SCIP_Result_SCIP_SUCCESS,
+            ReaderResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+        }
+    }
+}
+
+/// Writes to the C file handle that SCIP passes to the write callback of a reader.
+pub(crate) struct FileWriter {
+    pub(crate) file: *mut ffi::FILE,
+}
+
+impl Write for FileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // SCIP passes no file when the problem should be written to the standard output
+        if self.file.is_null() {
+            return std::io::stdout().write(buf);
+        }
+        let written = unsafe {
+            ffi::fwrite(
+                buf.as_ptr() as *const std::os::raw::c_void,
+                1,
+                buf.len() as _,
+                self.file,
+            )
+        };
+        if written as usize != buf.len() {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.file.is_null() {
+            return std::io::stdout().flush();
+        }
+        if unsafe { ffi::fflush(self.file) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+/// A wrapper struct for the internal ffi::SCIP_READER
+#[derive(Debug)]
+pub struct SCIPReader {
+    pub(crate) raw: *mut ffi::SCIP_READER,
+}
+
+impl SCIPReader {
+    /// Returns the internal raw pointer of the reader.
+    pub fn inner(&self) -> *mut ffi::SCIP_READER {
+        self.raw
+    }
+
+    /// Returns the name of the reader.
+    pub fn name(&self) -> String {
+        unsafe {
+            let name = ffi::SCIPreaderGetName(self.raw);
+            std::ffi::CStr::from_ptr(name).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the description of the reader.
+    pub fn desc(&self) -> String {
+        unsafe {
+            let desc = ffi::SCIPreaderGetDesc(self.raw);
+            std::ffi::CStr::from_ptr(desc).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the file extension of the reader.
+    pub fn extension(&self) -> String {
+        unsafe {
+            let ext = ffi::SCIPreaderGetExtension(self.raw);
+            std::ffi::CStr::from_ptr(ext).to_str().unwrap().to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ModelWithProblem, ObjSense};
+    use std::io::BufRead;
+
+    /// Stores the objective sense and one `name lb ub obj` line per variable.
+    struct VarListReader;
+
+    impl Reader for VarListReader {
+        fn read(
+            &mut self,
+            model: Model<PluginsIncluded>,
+            path: &str,
+        ) -> Result<ReaderResult, Retcode> {
+            let file = std::fs::File::open(path).map_err(|_| Retcode::NoFile)?;
+            let mut lines = std::io::BufReader::new(file).lines();
+
+            let sense = lines
+                .next()
+                .and_then(|l| l.ok())
+                .ok_or(Retcode::ReadError)?;
+            let mut model = model.create_prob("varlist");
+            model = match sense.as_str() {
+                "max" => model.maximize(),
+                "min" => model.minimize(),
+                _ => return Err(Retcode::ReadError),
+            };
+
+            for line in lines {
+                let line = line.map_err(|_| Retcode::ReadError)?;
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let [name, lb, ub, obj] = fields[..] else {
+                    return Err(Retcode::ReadError);
+                };
+                let parse = |s: &str| s.parse::<f64>().map_err(|_| Retcode::ReadError);
+                model.add(
+                    var()
+                        .name(name)
+                        .cont(parse(lb)?..=parse(ub)?)
+                        .obj(parse(obj)?),
+                );
+            }
+            Ok(ReaderResult::Success)
+        }
+
+        fn write(
+            &mut self,
+            model: Model<ProblemCreated>,
+            vars: &[Variable],
+            conss: &[Constraint],
+            writer: &mut dyn Write,
+        ) -> Result<ReaderResult, Retcode> {
+            assert!(conss.is_empty());
+            let sense = match model.obj_sense() {
+                ObjSense::Maximize => "max",
+                ObjSense::Minimize => "min",
+            };
+            let mut write = || -> std::io::Result<()> {
+                writeln!(writer, "{sense}")?;
+                for var in vars {
+                    writeln!(
+                        writer,
+                        "{} {} {} {}",
+                        var.name(),
+                        var.lb(),
+                        var.ub(),
+                        var.obj()
+                    )?;
+                }
+                Ok(())
+            };
+            write().map_err(|_| Retcode::WriteError)?;
+            Ok(ReaderResult::Success)
+        }
+    }
+
+    #[test]
+    fn custom_reader_roundtrip() {
+        let path = std::env::temp_dir().join("russcip_custom_reader.varlist");
+        let path = path.to_str().unwrap();
+
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("test")
+            .maximize();
+        model.add(reader(VarListReader).extension("varlist"));
+        model.add(var().name("x").cont(0.0..=3.0).obj(2.0));
+        model.add(var().name("y").cont(1.0..=5.0).obj(-1.0));
+        model.write(path, "varlist", false).unwrap();
+
+        let mut read_model = Model::new().hide_output().include_default_plugins();
+        read_model.add(reader(VarListReader).extension("varlist"));
+        let read_model = read_model.read_prob(path).unwrap();
+        assert_eq!(read_model.n_vars(), 2);
+
+        let solved = read_model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 5.0).abs() < 1e-6);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{Model, Solving, ffi};
+
+/// A trait for defining custom relaxation handlers.
+///
+/// Relaxators solve a relaxation of the problem at the nodes of the branch-and-bound tree, in
+/// addition to the LP. They can report a lower bound for the current node and provide a
+/// relaxation solution with [`Model::set_relax_sol`], which is then available to heuristics and
+/// separators.
+pub trait Relaxator {
+    /// Solves the relaxation at the current node.
+    ///
+    /// # Arguments
+    /// * `model` - the current model of the SCIP instance in `Solving` stage.
+    /// * `relax` - the internal relaxator object.
+    /// * `lower_bound` - the lower bound obtained by the relaxation should be stored here, it
+    ///   refers to the transformed problem, which is always a minimization problem. It is
+    ///   initialized to minus infinity.
+    ///
+    /// # Returns
+    /// * `RelaxResult` indicating the result of solving the relaxation.
+    fn solve(
+        &mut self,
+        model: Model<Solving>,
+        relax: SCIPRelaxator,
+        lower_bound: &mut f64,
+    ) -> RelaxResult;
+}
+
+/// The result of solving a relaxation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelaxResult {
+    /// The current node is infeasible and can be cut off.
+    CutOff,
+    /// A constraint was added to the problem.
+    ConsAdded,
+    /// The domain of at least one variable was reduced.
+    ReducedDom,
+    /// A cutting plane was added to the LP.
+    Separated,
+    /// The relaxation was solved and the lower bound is valid.
+    Success,
+    /// The relaxation was interrupted and should be called again later.
+    Suspended,
+    /// The relaxator was skipped.
+    DidNotRun,
+}
+
+impl From<RelaxResult> for ffi::SCIP_Result {
+    fn from(result: RelaxResult) -> Self {
+        match result {
+            RelaxResult::CutOff => ffi::SCIP_Result_SCIP_CUTOFF,
+            RelaxResult::ConsAdded => ffi::SCIP_Result_SCIP_CONSADDED,
+            RelaxResult::ReducedDom => ffi::SCIP_Result_SCIP_REDUCEDDOM,
+            RelaxResult::Separated => ffi::SCIP_Result_SCIP_SEPARATED,
+            RelaxResult::Success => ffi::SCIP_Result_SCIP_SUCCESS,
+            RelaxResult::Suspended => ffi::SCIP_Result_SCIP_SUSPENDED,
+            RelaxResult::DidNotRun => ffi::SCIP_Result_SCIP_DIDNOTRUN,
+        }
+    }
+}
+
+/// A wrapper struct for the internal ffi::SCIP_RELAX
+#[derive(Debug)]
+pub struct SCIPRelaxator {
+    pub(crate) raw: *mut ffi::SCIP_RELAX,
+}
+
+impl SCIPRelaxator {
+    /// Returns the internal raw pointer of the relaxator.
+    pub fn inner(&self) -> *mut ffi::SCIP_RELAX {
+        self.raw
+    }
+
+    /// Returns the name of the relaxator.
+    pub fn name(&self) -> String {
+        unsafe {
+            let name = ffi::SCIPrelaxGetName(self.raw);
+            std::ffi::CStr::from_ptr(name).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the description of the relaxator.
+    pub fn desc(&self) -> String {
+        unsafe {
+            let desc = ffi::SCIPrelaxGetDesc(self.raw);
+            std::ffi::CStr::from_ptr(desc).to_str().unwrap().to_string()
+        }
+    }
+
+    /// Returns the priority of the relaxator.
+    pub fn priority(&self) -> i32 {
+        unsafe { ffi::SCIPrelaxGetPriority(self.raw) }
+    }
+
+    /// Returns the frequency of the relaxator.
+    pub fn freq(&self) -> i32 {
+        unsafe { ffi::SCIPrelaxGetFreq(self.raw) }
+    }
+
+    /// Returns the number of times the relaxator was called.
+    pub fn n_calls(&self) -> i64 {
+        unsafe { ffi::SCIPrelaxGetNCalls(self.raw) }
+    }
+
+    /// Returns the number of times the relaxator cut off a node.
+    pub fn n_cutoffs(&self) -> i64 {
+        unsafe { ffi::SCIPrelaxGetNCutoffs(self.raw) }
+    }
+
+    /// Returns the number of times the relaxator improved the lower bound of a node.
+    pub fn n_improved_lower_bounds(&self) -> i64 {
+        unsafe { ffi::SCIPrelaxGetNImprovedLowerbound(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ModelWithProblem, minimal_model};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Relaxes all constraints away, so every variable sits at the bound minimizing its objective.
+    struct BoundRelaxator {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Relaxator for BoundRelaxator {
+        fn solve(
+            &mut self,
+            mut model: Model<Solving>,
+            relax: SCIPRelaxator,
+            lower_bound: &mut f64,
+        ) -> RelaxResult {
+            assert_eq!(relax.name(), "bounds");
+            self.calls.set(self.calls.get() + 1);
+
+            let vars = model.vars();
+            let vals: Vec<f64> = vars
+                .iter()
+                .map(|v| {
+                    if v.obj() >= 0.0 {
+                        v.lb_local()
+                    } else {
+                        v.ub_local()
+                    }
+                })
+                .collect();
+            *lower_bound = vars.iter().zip(&vals).map(|(v, val)| v.obj() * val).sum();
+            model.set_relax_sol(&relax, &vars, &vals, false).unwrap();
+
+            for (var, val) in vars.iter().zip(&vals) {
+                assert!((model.relax_sol_val(var) - val).abs() < 1e-6);
+            }
+            RelaxResult::Success
+        }
+    }
+
+    #[test]
+    fn relaxator_provides_bound() {
+        let calls = Rc::new(Cell::new(0));
+
+        let mut model = minimal_model().set_presolving(ParamSetting::Off).minimize();
+        let x = model.add(var().int(0..=10).obj(1.0).name("x"));
+        let y = model.add(var().int(1..=10).obj(2.0).name("y"));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).ge(4.0));
+        model.add(
+            relax(BoundRelaxator {
+                calls: calls.clone(),
+            })
+            .name("bounds"),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 5.0).abs() < 1e-6);
+        assert!(calls.get() > 0);
+    }
+}
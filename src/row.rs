@@ -173,6 +173,23 @@ impl Row {
         count as usize
     }
 
+    /// Returns the efficacy of the row as a cut, i.e. the distance of the current LP solution to
+    /// the hyperplane of the row.
+    pub fn efficacy(&self) -> f64 {
+        unsafe { ffi::SCIPgetCutEfficacy(self.scip.raw, std::ptr::null_mut(), self.raw) }
+    }
+
+    /// Returns the parallelism of the row with another row, as the cosine of the angle between
+    /// their normal vectors.
+    pub fn parallelism(&self, other: &Row) -> f64 {
+        unsafe { ffi::SCIProwGetParallelism(self.raw, other.raw, b'e' as std::os::raw::c_char) }
+    }
+
+    /// Returns the parallelism of the row with the objective function.
+    pub fn obj_parallelism(&self) -> f64 {
+        unsafe { ffi::SCIPgetRowObjParallelism(self.scip.raw, self.raw) }
+    }
+
     /// Sets the rank of the row.
     pub fn set_rank(&mut self, rank: usize) {
         unsafe { ffi::SCIProwChgRank(self.raw, rank as c_int) };
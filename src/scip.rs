@@ -5,22 +5,34 @@ use crate::branchrule::{BranchRule, BranchingCandidate};
 use crate::node::Node;
 use crate::nodesel::NodeSel;
 use crate::pricer::{Pricer, PricerResultState};
+use crate::progress::ProgressRecorder;
+use crate::reader::FileWriter;
+use crate::{Aggregation, Expr, LinExpr, Presolver, SCIPPresolver};
+use crate::{Benders, BendersCut, MessageHandler, SCIPBenders};
 use crate::{
-    BranchingResult, Conshdlr, Constraint, Event, Eventhdlr, HeurResult, LPStatus, Model, ObjSense,
-    ParamSetting, Retcode, Row, SCIPBranchRule, SCIPConshdlr, SCIPEventhdlr, SCIPPricer,
-    SCIPSeparator, Separator, Solution, Status, VarType, Variable, ffi, scip_call_panic,
+    BoundType, BranchingResult, Conshdlr, Constraint, Event, Eventhdlr, HeurResult, LPStatus,
+    LockDirection, Model, ObjSense, ParamSetting, PresolveChanges, Retcode, Row, SCIPBranchRule,
+    SCIPConshdlr, SCIPEventhdlr, SCIPPricer, SCIPSeparator, Separator, Solution, Status, VarType,
+    Variable, ffi, scip_call_panic,
 };
+use crate::{Conflict, ConflictHdlr, CutSelector, SCIPConflictHdlr};
 use crate::{HeurTiming, Heuristic, scip_call};
+use crate::{Inference, PresolTiming, PropTiming, Propagator, SCIPPropagator, Tightening};
+use crate::{Reader, Relaxator, SCIPReader, SCIPRelaxator};
 use core::panic;
 use scip_sys::{
     SCIP, SCIP_CONS, SCIP_CONSHDLR, SCIP_Cons, SCIP_LOCKTYPE, SCIP_NODE, SCIP_RESULT, SCIP_RETCODE,
     SCIP_SOL, SCIP_Var, Scip,
 };
+use std::any::Any;
+use std::cell::{OnceCell, RefCell};
 use std::collections::BTreeMap;
 use std::ffi::{CStr, CString, c_int};
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 use crate::builder::row::{RowBuilder, RowSource};
 
@@ -33,6 +45,19 @@ pub struct ScipPtr {
     pub(crate) weak: bool,
     /// Variables added during solving (to be released after solving)
     vars_added_in_solving: Vec<*mut ffi::SCIP_VAR>,
+    /// Original variables deleted from the problem, SCIP dropped its capture but the one of
+    /// their creation is released when the instance is dropped
+    deleted_vars: RefCell<Vec<*mut ffi::SCIP_VAR>>,
+    /// Original constraints deleted from the problem, released like `deleted_vars`
+    deleted_conss: RefCell<Vec<*mut ffi::SCIP_CONS>>,
+    /// Subproblems of the default Benders' decomposition, they are freed after this instance
+    benders_subproblems: RefCell<Vec<Rc<ScipPtr>>>,
+    /// Output of the SCIP instance, filled if it is captured
+    pub(crate) captured_output: Rc<RefCell<String>>,
+    /// Interruption flag shared with the interrupt handles of the SCIP instance
+    pub(crate) interrupt_flag: OnceCell<Arc<AtomicBool>>,
+    /// Progress callbacks and bound trace, set up on the first progress callback
+    pub(crate) progress: OnceCell<Rc<RefCell<ProgressRecorder>>>,
 }
 
 impl ScipPtr {
@@ -44,6 +69,12 @@ impl ScipPtr {
             raw: scip_ptr,
             weak: false,
             vars_added_in_solving: Vec::new(),
+            deleted_vars: RefCell::new(Vec::new()),
+            deleted_conss: RefCell::new(Vec::new()),
+            benders_subproblems: RefCell::new(Vec::new()),
+            captured_output: Rc::new(RefCell::new(String::new())),
+            interrupt_flag: OnceCell::new(),
+            progress: OnceCell::new(),
         })
     }
 
@@ -52,6 +83,12 @@ impl ScipPtr {
             raw,
             weak,
             vars_added_in_solving: Vec::new(),
+            deleted_vars: RefCell::new(Vec::new()),
+            deleted_conss: RefCell::new(Vec::new()),
+            benders_subproblems: RefCell::new(Vec::new()),
+            captured_output: Rc::new(RefCell::new(String::new())),
+            interrupt_flag: OnceCell::new(),
+            progress: OnceCell::new(),
         }
     }
 
@@ -85,6 +122,77 @@ impl ScipPtr {
         Ok(value != 0)
     }
 
+    pub(crate) fn set_message_handler(
+        &self,
+        handler: Box<dyn MessageHandler>,
+    ) -> Result<(), Retcode> {
+        fn handler_ptr(messagehdlr: *mut ffi::SCIP_MESSAGEHDLR) -> *mut Box<dyn MessageHandler> {
+            let data_ptr = unsafe { ffi::SCIPmessagehdlrGetData(messagehdlr) };
+            assert!(!data_ptr.is_null());
+            data_ptr as *mut Box<dyn MessageHandler>
+        }
+
+        fn message(msg: *const std::os::raw::c_char) -> String {
+            unsafe { CStr::from_ptr(msg) }
+                .to_string_lossy()
+                .into_owned()
+        }
+
+        extern "C" fn messagewarning(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            _file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            unsafe { (*handler_ptr(messagehdlr)).warning(&message(msg)) };
+        }
+
+        extern "C" fn messagedialog(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            _file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            unsafe { (*handler_ptr(messagehdlr)).dialog(&message(msg)) };
+        }
+
+        extern "C" fn messageinfo(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            _file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            unsafe { (*handler_ptr(messagehdlr)).info(&message(msg)) };
+        }
+
+        extern "C" fn messagehdlrfree(messagehdlr: *mut ffi::SCIP_MESSAGEHDLR) -> SCIP_RETCODE {
+            drop(unsafe { Box::from_raw(handler_ptr(messagehdlr)) });
+            Retcode::Okay.into()
+        }
+
+        let ptr = Box::into_raw(Box::new(handler));
+        let handler_faker = ptr as *mut ffi::SCIP_MESSAGEHDLRDATA;
+
+        let mut messagehdlr: *mut ffi::SCIP_MESSAGEHDLR = std::ptr::null_mut();
+        scip_call!(ffi::SCIPmessagehdlrCreate(
+            &mut messagehdlr,
+            true.into(),
+            std::ptr::null(),
+            false.into(),
+            Some(messagewarning),
+            Some(messagedialog),
+            Some(messageinfo),
+            Some(messagehdlrfree),
+            handler_faker,
+        ));
+        // SCIP captures the message handler, so our reference can be released right away
+        scip_call!(ffi::SCIPsetMessagehdlr(self.raw, messagehdlr));
+        scip_call!(ffi::SCIPmessagehdlrRelease(&mut messagehdlr));
+        Ok(())
+    }
+
+    pub(crate) fn interrupt_solve(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPinterruptSolve(self.raw));
+        Ok(())
+    }
+
     pub(crate) fn set_int_param(&self, param: &str, value: i32) -> Result<(), Retcode> {
         let param = CString::new(param).unwrap();
         scip_call! { ffi::SCIPsetIntParam(self.raw, param.as_ptr(), value) };
@@ -174,11 +282,31 @@ impl ScipPtr {
         }
     }
 
+    pub(crate) fn obj_sense(&self) -> ObjSense {
+        unsafe { ffi::SCIPgetObjsense(self.raw) }.into()
+    }
+
     pub(crate) fn set_obj_sense(&self, sense: ObjSense) -> Result<(), Retcode> {
         scip_call!(ffi::SCIPsetObjsense(self.raw, sense.into()));
         Ok(())
     }
 
+    pub(crate) fn set_objective(&self, expr: &LinExpr) -> Result<(), Retcode> {
+        for var in self.vars(true, false).into_values() {
+            scip_call!(ffi::SCIPchgVarObj(self.raw, var, 0.0));
+        }
+        for (var, coef) in expr.terms() {
+            let obj = unsafe { ffi::SCIPvarGetObj(var.raw) };
+            scip_call!(ffi::SCIPchgVarObj(self.raw, var.raw, obj + coef));
+        }
+        let offset = unsafe { ffi::SCIPgetOrigObjoffset(self.raw) };
+        scip_call!(ffi::SCIPaddOrigObjoffset(
+            self.raw,
+            expr.constant() - offset
+        ));
+        Ok(())
+    }
+
     pub(crate) fn set_obj_integral(&self) -> Result<(), Retcode> {
         scip_call!(ffi::SCIPsetObjIntegral(self.raw));
         Ok(())
@@ -218,6 +346,23 @@ impl ScipPtr {
         }
     }
 
+    /// Returns the variable to modify in the current stage, i.e. the transformed counterpart of
+    /// an original variable once the problem is transformed.
+    pub(crate) fn var_in_stage(&self, var: &Variable) -> Result<*mut SCIP_Var, Retcode> {
+        let stage = unsafe { ffi::SCIPgetStage(self.raw) };
+        let var_is_original = unsafe { ffi::SCIPvarIsOriginal(var.raw) } != 0;
+        if stage == ffi::SCIP_Stage_SCIP_STAGE_PROBLEM || !var_is_original {
+            return Ok(var.raw);
+        }
+        let mut transformed_var = std::ptr::null_mut();
+        scip_call! { ffi::SCIPgetTransformedVar(self.raw, var.raw, &mut transformed_var) };
+        if transformed_var.is_null() {
+            Err(Retcode::InvalidData)
+        } else {
+            Ok(transformed_var)
+        }
+    }
+
     pub(crate) fn get_transformed_cons(
         &self,
         cons: &Constraint,
@@ -345,6 +490,254 @@ impl ScipPtr {
         Ok(())
     }
 
+    /// Copies the problem into a new SCIP instance, returning it together with the mapping of
+    /// the copied variables and constraints and whether the copy is complete.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn copy(
+        &self,
+        name: Option<&str>,
+        original: bool,
+        global: bool,
+        copy_plugins: bool,
+        fixings: &[(*mut SCIP_Var, f64)],
+    ) -> Result<
+        (
+            ScipPtr,
+            Vec<(*mut SCIP_Var, *mut SCIP_Var)>,
+            Vec<(*mut SCIP_Cons, *mut SCIP_Cons)>,
+            bool,
+        ),
+        Retcode,
+    > {
+        let target = ScipPtr::new()?;
+        // only the original problem exists before the problem is transformed
+        let original = original || unsafe { ffi::SCIPisTransformed(self.raw) } == 0;
+
+        let name = match name {
+            Some(name) => CString::new(name).unwrap(),
+            None => unsafe { CStr::from_ptr(ffi::SCIPgetProbName(self.raw)) }.to_owned(),
+        };
+
+        let mut fixed_vars = Vec::with_capacity(fixings.len());
+        let mut fixed_vals = Vec::with_capacity(fixings.len());
+        for &(var, val) in fixings {
+            let mut var = var;
+            if !original && unsafe { ffi::SCIPvarIsOriginal(var) } != 0 {
+                scip_call!(ffi::SCIPgetTransformedVar(self.raw, var, &mut var));
+            }
+            fixed_vars.push(var);
+            fixed_vals.push(val);
+        }
+
+        let mut varmap = std::ptr::null_mut();
+        let mut consmap = std::ptr::null_mut();
+        let n_vars = unsafe { ffi::SCIPgetNTotalVars(self.raw) };
+        let n_conss = unsafe { ffi::SCIPgetNConss(self.raw) + ffi::SCIPgetNOrigConss(self.raw) };
+        scip_call!(ffi::SCIPhashmapCreate(
+            &mut varmap,
+            ffi::SCIPblkmem(target.raw),
+            n_vars.max(1)
+        ));
+        scip_call!(ffi::SCIPhashmapCreate(
+            &mut consmap,
+            ffi::SCIPblkmem(target.raw),
+            n_conss.max(1)
+        ));
+
+        let copied = (|| {
+            if copy_plugins {
+                // plugins without a copy callback are skipped, which is reported through the
+                // validity of the copied constraints
+                let mut plugins_valid = 1;
+                let t = 1;
+                scip_call!(ffi::SCIPcopyPlugins(
+                    self.raw,
+                    target.raw,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    t,
+                    0,
+                    &mut plugins_valid
+                ));
+            } else {
+                scip_call!(ffi::SCIPincludeDefaultPlugins(target.raw));
+            }
+            scip_call!(ffi::SCIPcopyParamSettings(self.raw, target.raw));
+
+            let mut conss_valid = 0;
+            if original {
+                scip_call!(ffi::SCIPcopyOrigProb(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    name.as_ptr()
+                ));
+                scip_call!(ffi::SCIPcopyOrigVars(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    fixed_vars.as_mut_ptr(),
+                    fixed_vals.as_mut_ptr(),
+                    fixed_vars.len() as c_int
+                ));
+                scip_call!(ffi::SCIPcopyOrigConss(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    0,
+                    &mut conss_valid
+                ));
+            } else {
+                scip_call!(ffi::SCIPcopyProb(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    global.into(),
+                    name.as_ptr()
+                ));
+                scip_call!(ffi::SCIPcopyVars(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    fixed_vars.as_mut_ptr(),
+                    fixed_vals.as_mut_ptr(),
+                    fixed_vars.len() as c_int,
+                    global.into()
+                ));
+                scip_call!(ffi::SCIPcopyConss(
+                    self.raw,
+                    target.raw,
+                    varmap,
+                    consmap,
+                    global.into(),
+                    0,
+                    &mut conss_valid
+                ));
+            }
+            Ok(conss_valid != 0)
+        })();
+
+        // the copied variables and constraints are added to the target but not captured, while
+        // the Drop impl releases every original variable/constraint, so capture them like
+        // `read_prob` does, also when copying failed after the problem was created
+        if unsafe { ffi::SCIPgetStage(target.raw) } == ffi::SCIP_Stage_SCIP_STAGE_PROBLEM {
+            target.vars(false, true);
+            target.conss(true);
+        }
+
+        let image = |map: *mut ffi::SCIP_HASHMAP, source: *mut std::os::raw::c_void| unsafe {
+            ffi::SCIPhashmapGetImage(map, source)
+        };
+
+        let mut vars = Vec::new();
+        let mut conss = Vec::new();
+        if copied.is_ok() {
+            for (_, var) in self.vars(original, false) {
+                let copy = image(varmap, var as _) as *mut SCIP_Var;
+                if !copy.is_null() {
+                    vars.push((var, copy));
+                }
+            }
+            let (n_source_conss, source_conss) = unsafe {
+                if original {
+                    (
+                        ffi::SCIPgetNOrigConss(self.raw),
+                        ffi::SCIPgetOrigConss(self.raw),
+                    )
+                } else {
+                    (ffi::SCIPgetNConss(self.raw), ffi::SCIPgetConss(self.raw))
+                }
+            };
+            for i in 0..n_source_conss as usize {
+                let cons = unsafe { *source_conss.add(i) };
+                let copy = image(consmap, cons as _) as *mut SCIP_Cons;
+                if !copy.is_null() {
+                    conss.push((cons, copy));
+                }
+            }
+
+            // variables and constraints of the original problem are mapped through their
+            // transformed counterparts
+            if !original {
+                for (_, var) in self.vars(true, false) {
+                    let trans_var = unsafe { ffi::SCIPvarGetTransVar(var) };
+                    if !trans_var.is_null() {
+                        let copy = image(varmap, trans_var as _) as *mut SCIP_Var;
+                        if !copy.is_null() {
+                            vars.push((var, copy));
+                        }
+                    }
+                }
+                let orig_conss = unsafe { ffi::SCIPgetOrigConss(self.raw) };
+                for i in 0..unsafe { ffi::SCIPgetNOrigConss(self.raw) } as usize {
+                    let cons = unsafe { *orig_conss.add(i) };
+                    let mut trans_cons = std::ptr::null_mut();
+                    unsafe { ffi::SCIPgetTransformedCons(self.raw, cons, &mut trans_cons) };
+                    if !trans_cons.is_null() {
+                        let copy = image(consmap, trans_cons as _) as *mut SCIP_Cons;
+                        if !copy.is_null() {
+                            conss.push((cons, copy));
+                        }
+                    }
+                }
+            }
+        }
+
+        unsafe {
+            ffi::SCIPhashmapFree(&mut varmap);
+            ffi::SCIPhashmapFree(&mut consmap);
+        }
+        let valid = copied?;
+        Ok((target, vars, conss, valid))
+    }
+
+    /// Returns whether enough time and memory is left to solve a sub-SCIP.
+    pub(crate) fn check_copy_limits(&self) -> Result<bool, Retcode> {
+        let mut success = 0;
+        scip_call!(ffi::SCIPcheckCopyLimits(self.raw, &mut success));
+        Ok(success != 0)
+    }
+
+    /// Sets the limits and search parameters of a sub-SCIP created from this instance.
+    pub(crate) fn set_subscip_params(
+        &self,
+        subscip: &ScipPtr,
+        node_limit: i64,
+        stall_node_limit: i64,
+        best_sol_limit: i32,
+    ) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPcopyLimits(self.raw, subscip.raw));
+        scip_call!(ffi::SCIPsetCommonSubscipParams(
+            self.raw,
+            subscip.raw,
+            node_limit,
+            stall_node_limit,
+            best_sol_limit
+        ));
+        Ok(())
+    }
+
     pub(crate) fn vars(&self, original: bool, capture: bool) -> BTreeMap<usize, *mut SCIP_Var> {
         // NOTE: this method should only be called once per SCIP instance
         let n_vars = {
@@ -619,793 +1012,2470 @@ impl ScipPtr {
         Ok(scip_cons)
     }
 
-    pub(crate) fn create_cons_quadratic(
+    /// Create and constraint
+    pub(crate) fn create_cons_and(
         &self,
-        lin_vars: Vec<&Variable>,
-        lin_coefs: &mut [f64],
-        quad_vars_1: Vec<&Variable>,
-        quad_vars_2: Vec<&Variable>,
-        quad_coefs: &mut [f64],
-        lhs: f64,
-        rhs: f64,
+        resultant: &Variable,
+        vars: Vec<&Variable>,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
-        assert_eq!(lin_vars.len(), lin_coefs.len());
-        assert!(
-            lin_vars.len() <= c_int::MAX as usize,
-            "Number of linear variables exceeds SCIP capabilities"
-        );
-        assert_eq!(quad_vars_1.len(), quad_vars_2.len());
-        assert_eq!(quad_vars_1.len(), quad_coefs.len());
-        assert!(
-            quad_vars_1.len() <= c_int::MAX as usize,
-            "Number of quadratic terms exceeds SCIP capabilities"
-        );
-
         let c_name = CString::new(name).unwrap();
         let mut scip_cons = MaybeUninit::uninit();
-
-        let get_ptrs = |vars: Vec<&Variable>| {
-            vars.into_iter()
-                .map(|var_rc| var_rc.raw)
-                .collect::<Vec<_>>()
-        };
-        let mut lin_var_ptrs = get_ptrs(lin_vars);
-        let mut quad_vars_1_ptrs = get_ptrs(quad_vars_1);
-        let mut quad_vars_2_ptrs = get_ptrs(quad_vars_2);
-        scip_call! { ffi::SCIPcreateConsBasicQuadraticNonlinear(
+        let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        scip_call! { ffi::SCIPcreateConsBasicAnd(
             self.raw,
             scip_cons.as_mut_ptr(),
             c_name.as_ptr(),
-            lin_var_ptrs.len() as c_int,
-            lin_var_ptrs.as_mut_ptr(),
-            lin_coefs.as_mut_ptr(),
-            quad_vars_1_ptrs.len() as c_int,
-            quad_vars_1_ptrs.as_mut_ptr(),
-            quad_vars_2_ptrs.as_mut_ptr(),
-            quad_coefs.as_mut_ptr(),
-            lhs,
-            rhs,
+            resultant.raw,
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
         ) };
-
         let scip_cons = unsafe { scip_cons.assume_init() };
         scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
         Ok(scip_cons)
     }
 
-    /// Create set packing constraint
-    pub(crate) fn create_cons_set_pack(
+    /// Create or constraint
+    pub(crate) fn create_cons_or(
         &self,
+        resultant: &Variable,
         vars: Vec<&Variable>,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
         let c_name = CString::new(name).unwrap();
         let mut scip_cons = MaybeUninit::uninit();
-        scip_call! { ffi::SCIPcreateConsBasicSetpack(
+        let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        scip_call! { ffi::SCIPcreateConsBasicOr(
             self.raw,
             scip_cons.as_mut_ptr(),
             c_name.as_ptr(),
-            0,
-            std::ptr::null_mut(),
+            resultant.raw,
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
         ) };
         let scip_cons = unsafe { scip_cons.assume_init() };
-        for var in vars.iter() {
-            scip_call! { ffi::SCIPaddCoefSetppc(self.raw, scip_cons, var.raw) };
-        }
         scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
         Ok(scip_cons)
     }
 
-    /// Create cardinality constraint
-    pub(crate) fn create_cons_cardinality(
+    /// Create xor constraint
+    pub(crate) fn create_cons_xor(
         &self,
+        rhs: bool,
         vars: Vec<&Variable>,
-        cardinality: usize,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
         let c_name = CString::new(name).unwrap();
         let mut scip_cons = MaybeUninit::uninit();
-        scip_call! { ffi::SCIPcreateConsBasicCardinality(
+        let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        scip_call! { ffi::SCIPcreateConsBasicXor(
             self.raw,
             scip_cons.as_mut_ptr(),
             c_name.as_ptr(),
-            0,
-            std::ptr::null_mut(),
-            0,
-            std::ptr::null_mut(),
-            std::ptr::null_mut(),
+            rhs.into(),
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
         ) };
         let scip_cons = unsafe { scip_cons.assume_init() };
-        for (ind, var) in vars.iter().enumerate() {
-            scip_call! { ffi::SCIPaddVarCardinality(self.raw, scip_cons, var.raw, std::ptr::null_mut(), ind as f64) };
-        }
-        scip_call! { ffi:: SCIPchgCardvalCardinality(self.raw, scip_cons, cardinality as i32) };
         scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
         Ok(scip_cons)
     }
 
-    /// Get number of constraints added in node
-    pub(crate) fn node_get_n_added_conss(&self, node: &Node) -> usize {
-        unsafe { ffi::SCIPnodeGetNAddedConss(node.raw) as usize }
-    }
-
-    pub(crate) fn var_from_id(scip: *mut Scip, var_prob_id: usize) -> Option<*mut SCIP_Var> {
-        let n_vars = unsafe { ffi::SCIPgetNVars(scip) as usize };
-        let var = unsafe { *ffi::SCIPgetVars(scip).add(var_prob_id) };
-        if var_prob_id >= n_vars {
-            None
-        } else {
-            Some(var)
-        }
+    /// Create logicor constraint
+    pub(crate) fn create_cons_logicor(
+        &self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        scip_call! { ffi::SCIPcreateConsBasicLogicor(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
+        ) };
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
     }
 
-    /// Create indicator constraint
-    pub(crate) fn create_cons_indicator(
+    /// Create pseudoboolean constraint
+    pub(crate) fn create_cons_pseudoboolean(
         &self,
-        bin_var: &Variable,
-        vars: Vec<&Variable>,
-        coefs: &mut [f64],
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &[f64],
+        and_terms: Vec<(Vec<&Variable>, f64)>,
+        lhs: f64,
         rhs: f64,
         name: &str,
     ) -> Result<*mut SCIP_Cons, Retcode> {
-        assert_eq!(vars.len(), coefs.len());
+        assert_eq!(lin_vars.len(), lin_coefs.len());
         let c_name = CString::new(name).unwrap();
         let mut scip_cons = MaybeUninit::uninit();
-
-        scip_call! { ffi::SCIPcreateConsBasicIndicator(
+        let mut lin_var_ptrs = lin_vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        let mut lin_coefs = lin_coefs.to_vec();
+        let mut term_var_ptrs = and_terms
+            .iter()
+            .map(|(vars, _)| vars.iter().map(|v| v.raw).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut n_term_vars = term_var_ptrs
+            .iter()
+            .map(|vars| vars.len() as c_int)
+            .collect::<Vec<_>>();
+        let mut terms = term_var_ptrs
+            .iter_mut()
+            .map(|vars| vars.as_mut_ptr())
+            .collect::<Vec<_>>();
+        let mut term_coefs = and_terms.iter().map(|(_, coef)| *coef).collect::<Vec<_>>();
+        scip_call! { ffi::SCIPcreateConsBasicPseudoboolean(
             self.raw,
             scip_cons.as_mut_ptr(),
             c_name.as_ptr(),
-            bin_var.raw,
-            vars.len() as c_int,
-            (vars.into_iter()
-              .map(|var_rc| var_rc.raw)
-                .collect::<Vec<_>>()).as_mut_ptr(),
-            coefs.as_mut_ptr(),
+            lin_var_ptrs.as_mut_ptr(),
+            lin_var_ptrs.len() as c_int,
+            lin_coefs.as_mut_ptr(),
+            terms.as_mut_ptr(),
+            terms.len() as c_int,
+            n_term_vars.as_mut_ptr(),
+            term_coefs.as_mut_ptr(),
+            std::ptr::null_mut(),
+            0.0,
+            false.into(),
+            lhs,
             rhs,
         ) };
-
         let scip_cons = unsafe { scip_cons.assume_init() };
         scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
         Ok(scip_cons)
     }
 
-    /// Create solution
-    pub(crate) fn create_sol(&self, original: bool) -> Result<*mut SCIP_SOL, Retcode> {
-        let mut sol = MaybeUninit::uninit();
-        if original {
-            scip_call! { ffi::SCIPcreateOrigSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
-        } else {
-            scip_call! { ffi::SCIPcreateSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
-        }
-        let sol = unsafe { sol.assume_init() };
-        assert!(!sol.is_null());
-        Ok(sol)
+    /// Returns the linear terms of a pseudoboolean constraint, without the and-terms.
+    pub(crate) fn pseudoboolean_linear_terms(
+        &self,
+        cons: *mut SCIP_Cons,
+    ) -> Result<Vec<(*mut SCIP_Var, f64)>, Retcode> {
+        let n_vars = unsafe { ffi::SCIPgetNLinVarsWithoutAndPseudoboolean(self.raw, cons) };
+        let mut vars = vec![std::ptr::null_mut(); n_vars as usize];
+        let mut coefs = vec![0.0; n_vars as usize];
+        let mut n_vars = n_vars;
+        scip_call! { ffi::SCIPgetLinDatasWithoutAndPseudoboolean(
+            self.raw,
+            cons,
+            vars.as_mut_ptr(),
+            coefs.as_mut_ptr(),
+            &mut n_vars,
+        ) };
+        Ok(vars.into_iter().zip(coefs).take(n_vars as usize).collect())
     }
 
-    /// Create a partial solution: unset variables are UNKNOWN, not 0.
-    pub(crate) fn create_partial_sol(&self) -> Result<*mut SCIP_SOL, Retcode> {
-        let mut sol = MaybeUninit::uninit();
-        scip_call! { ffi::SCIPcreatePartialSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
-        let sol = unsafe { sol.assume_init() };
-        assert!(!sol.is_null());
-        Ok(sol)
+    /// Returns the and-constraints of a pseudoboolean constraint with their coefficients.
+    pub(crate) fn pseudoboolean_and_terms(
+        &self,
+        cons: *mut SCIP_Cons,
+    ) -> Result<Vec<(*mut SCIP_Cons, f64)>, Retcode> {
+        let n_ands = unsafe { ffi::SCIPgetNAndsPseudoboolean(self.raw, cons) };
+        let mut and_conss = vec![std::ptr::null_mut(); n_ands as usize];
+        let mut coefs = vec![0.0; n_ands as usize];
+        let mut n_ands = n_ands;
+        scip_call! { ffi::SCIPgetAndDatasPseudoboolean(
+            self.raw,
+            cons,
+            and_conss.as_mut_ptr(),
+            coefs.as_mut_ptr(),
+            &mut n_ands,
+        ) };
+        Ok(and_conss
+            .into_iter()
+            .zip(coefs)
+            .take(n_ands as usize)
+            .collect())
     }
 
-    /// Add coefficient to set packing/partitioning/covering constraint
-    pub(crate) fn add_cons_coef_setppc(
+    /// Create knapsack constraint
+    pub(crate) fn create_cons_knapsack(
+        &self,
+        vars: Vec<&Variable>,
+        weights: &[i64],
+        capacity: i64,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        assert_eq!(vars.len(), weights.len());
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        let mut weights = weights.to_vec();
+        scip_call! { ffi::SCIPcreateConsBasicKnapsack(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
+            weights.as_mut_ptr(),
+            capacity,
+        ) };
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Create varbound constraint
+    pub(crate) fn create_cons_varbound(
         &self,
-        cons: &Constraint,
         var: &Variable,
+        vbd_var: &Variable,
+        vbd_coef: f64,
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        scip_call! { ffi::SCIPcreateConsBasicVarbound(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            var.raw,
+            vbd_var.raw,
+            vbd_coef,
+            lhs,
+            rhs,
+        ) };
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Create bounddisjunction constraint
+    pub(crate) fn create_cons_bounddisjunction(
+        &self,
+        literals: Vec<(&Variable, BoundType, f64)>,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        let mut var_ptrs = literals.iter().map(|(v, _, _)| v.raw).collect::<Vec<_>>();
+        let mut bound_types = literals
+            .iter()
+            .map(|(_, bound_type, _)| (*bound_type).into())
+            .collect::<Vec<ffi::SCIP_BOUNDTYPE>>();
+        let mut bounds = literals.iter().map(|(_, _, b)| *b).collect::<Vec<_>>();
+        scip_call! { ffi::SCIPcreateConsBasicBounddisjunction(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
+            bound_types.as_mut_ptr(),
+            bounds.as_mut_ptr(),
+        ) };
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Create linking constraint
+    pub(crate) fn create_cons_linking(
+        &self,
+        link_var: &Variable,
+        bin_vars: Vec<&Variable>,
+        vals: &[f64],
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        assert_eq!(bin_vars.len(), vals.len());
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        let mut bin_var_ptrs = bin_vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        let mut vals = vals.to_vec();
+        scip_call! { ffi::SCIPcreateConsBasicLinking(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            link_var.raw,
+            bin_var_ptrs.as_mut_ptr(),
+            vals.as_mut_ptr(),
+            bin_var_ptrs.len() as c_int,
+        ) };
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Returns the binary variables of a linking constraint with their values.
+    pub(crate) fn linking_terms(
+        &self,
+        cons: *mut SCIP_Cons,
+    ) -> Result<Vec<(*mut SCIP_Var, f64)>, Retcode> {
+        let mut bin_vars = std::ptr::null_mut();
+        let mut vals = std::ptr::null_mut();
+        let mut n_bin_vars = 0;
+        scip_call! { ffi::SCIPgetBinvarsDataLinking(cons, &mut bin_vars, &mut vals, &mut n_bin_vars) };
+        if n_bin_vars == 0 {
+            return Ok(Vec::new());
+        }
+        let bin_vars = unsafe { std::slice::from_raw_parts(bin_vars, n_bin_vars as usize) };
+        let vals = unsafe { std::slice::from_raw_parts(vals, n_bin_vars as usize) };
+        Ok(bin_vars.iter().copied().zip(vals.iter().copied()).collect())
+    }
+
+    pub(crate) fn create_cons_quadratic(
+        &self,
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &mut [f64],
+        quad_vars_1: Vec<&Variable>,
+        quad_vars_2: Vec<&Variable>,
+        quad_coefs: &mut [f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        assert_eq!(lin_vars.len(), lin_coefs.len());
+        assert!(
+            lin_vars.len() <= c_int::MAX as usize,
+            "Number of linear variables exceeds SCIP capabilities"
+        );
+        assert_eq!(quad_vars_1.len(), quad_vars_2.len());
+        assert_eq!(quad_vars_1.len(), quad_coefs.len());
+        assert!(
+            quad_vars_1.len() <= c_int::MAX as usize,
+            "Number of quadratic terms exceeds SCIP capabilities"
+        );
+
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+
+        let get_ptrs = |vars: Vec<&Variable>| {
+            vars.into_iter()
+                .map(|var_rc| var_rc.raw)
+                .collect::<Vec<_>>()
+        };
+        let mut lin_var_ptrs = get_ptrs(lin_vars);
+        let mut quad_vars_1_ptrs = get_ptrs(quad_vars_1);
+        let mut quad_vars_2_ptrs = get_ptrs(quad_vars_2);
+        scip_call! { ffi::SCIPcreateConsBasicQuadraticNonlinear(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            lin_var_ptrs.len() as c_int,
+            lin_var_ptrs.as_mut_ptr(),
+            lin_coefs.as_mut_ptr(),
+            quad_vars_1_ptrs.len() as c_int,
+            quad_vars_1_ptrs.as_mut_ptr(),
+            quad_vars_2_ptrs.as_mut_ptr(),
+            quad_coefs.as_mut_ptr(),
+            lhs,
+            rhs,
+        ) };
+
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Create nonlinear constraint
+    pub(crate) fn create_cons_nonlinear(
+        &self,
+        expr: &Expr,
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let mut scip_expr = self.create_expr(expr)?;
+        let mut scip_cons = MaybeUninit::uninit();
+        let created = unsafe {
+            ffi::SCIPcreateConsBasicNonlinear(
+                self.raw,
+                scip_cons.as_mut_ptr(),
+                c_name.as_ptr(),
+                scip_expr,
+                lhs,
+                rhs,
+            )
+        };
+        // the constraint holds its own copy of the expression
+        scip_call! { ffi::SCIPreleaseExpr(self.raw, &mut scip_expr) };
+        let created = Retcode::from(created);
+        if created != Retcode::Okay {
+            return Err(created);
+        }
+
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+
+        let stage = unsafe { ffi::SCIPgetStage(self.raw) };
+        if stage == ffi::SCIP_Stage_SCIP_STAGE_SOLVING {
+            // release a copy of the pointer, as SCIPreleaseCons sets it to null, the constraint
+            // stays alive as part of the problem
+            let mut released = scip_cons;
+            scip_call! { ffi::SCIPreleaseCons(self.raw, &mut released) };
+        }
+        Ok(scip_cons)
+    }
+
+    /// Creates a SCIP expression for the given expression tree, the caller has to release it.
+    fn create_expr(&self, expr: &Expr) -> Result<*mut ffi::SCIP_EXPR, Retcode> {
+        let mut children = Vec::new();
+        for child in expr.children() {
+            match self.create_expr(child) {
+                Ok(child) => children.push(child),
+                Err(err) => {
+                    self.release_exprs(&mut children)?;
+                    return Err(err);
+                }
+            }
+        }
+        let created = self.create_expr_node(expr, &mut children);
+        self.release_exprs(&mut children)?;
+        created
+    }
+
+    /// Creates the root node of the given expression tree with the already created children.
+    fn create_expr_node(
+        &self,
+        expr: &Expr,
+        children: &mut [*mut ffi::SCIP_EXPR],
+    ) -> Result<*mut ffi::SCIP_EXPR, Retcode> {
+        let mut scip_expr = MaybeUninit::uninit();
+        let ptr = scip_expr.as_mut_ptr();
+        let child = children.first().copied().unwrap_or(std::ptr::null_mut());
+        let no_owner = std::ptr::null_mut();
+        match expr {
+            Expr::Var(var) => {
+                scip_call! { ffi::SCIPcreateExprVar(self.raw, ptr, var.raw, None, no_owner) }
+            }
+            Expr::Const(val) => {
+                scip_call! { ffi::SCIPcreateExprValue(self.raw, ptr, *val, None, no_owner) }
+            }
+            Expr::Sum { terms, constant } => {
+                let mut coefs = terms.iter().map(|(coef, _)| *coef).collect::<Vec<_>>();
+                scip_call! { ffi::SCIPcreateExprSum(
+                    self.raw,
+                    ptr,
+                    children.len() as c_int,
+                    children.as_mut_ptr(),
+                    coefs.as_mut_ptr(),
+                    *constant,
+                    None,
+                    no_owner,
+                ) }
+            }
+            Expr::Product { coef, .. } => {
+                scip_call! { ffi::SCIPcreateExprProduct(
+                    self.raw,
+                    ptr,
+                    children.len() as c_int,
+                    children.as_mut_ptr(),
+                    *coef,
+                    None,
+                    no_owner,
+                ) }
+            }
+            Expr::Pow(_, exponent) => {
+                scip_call! { ffi::SCIPcreateExprPow(self.raw, ptr, child, *exponent, None, no_owner) }
+            }
+            Expr::SignPower(_, exponent) => {
+                scip_call! { ffi::SCIPcreateExprSignpower(self.raw, ptr, child, *exponent, None, no_owner) }
+            }
+            Expr::Exp(_) => {
+                scip_call! { ffi::SCIPcreateExprExp(self.raw, ptr, child, None, no_owner) }
+            }
+            Expr::Log(_) => {
+                scip_call! { ffi::SCIPcreateExprLog(self.raw, ptr, child, None, no_owner) }
+            }
+            Expr::Abs(_) => {
+                scip_call! { ffi::SCIPcreateExprAbs(self.raw, ptr, child, None, no_owner) }
+            }
+            Expr::Sin(_) => {
+                scip_call! { ffi::SCIPcreateExprSin(self.raw, ptr, child, None, no_owner) }
+            }
+            Expr::Cos(_) => {
+                scip_call! { ffi::SCIPcreateExprCos(self.raw, ptr, child, None, no_owner) }
+            }
+            Expr::Entropy(_) => {
+                scip_call! { ffi::SCIPcreateExprEntropy(self.raw, ptr, child, None, no_owner) }
+            }
+        }
+        Ok(unsafe { scip_expr.assume_init() })
+    }
+
+    /// Releases the given expressions.
+    fn release_exprs(&self, exprs: &mut [*mut ffi::SCIP_EXPR]) -> Result<(), Retcode> {
+        for expr in exprs.iter_mut() {
+            scip_call! { ffi::SCIPreleaseExpr(self.raw, expr) };
+        }
+        Ok(())
+    }
+
+    /// Returns the variables of a constraint, `None` if its constraint handler cannot provide them.
+    pub(crate) fn cons_vars(
+        &self,
+        cons: *mut SCIP_Cons,
+    ) -> Result<Option<Vec<*mut SCIP_Var>>, Retcode> {
+        let mut n_vars = 0;
+        let mut success = 0;
+        scip_call! { ffi::SCIPgetConsNVars(self.raw, cons, &mut n_vars, &mut success) };
+        if success == 0 {
+            return Ok(None);
+        }
+        let mut vars = vec![std::ptr::null_mut(); n_vars as usize];
+        scip_call! { ffi::SCIPgetConsVars(self.raw, cons, vars.as_mut_ptr(), n_vars, &mut success) };
+        Ok((success != 0).then_some(vars))
+    }
+
+    /// Returns the coefficients of a constraint that can be represented as a single linear
+    /// constraint, `None` if its constraint handler cannot provide them.
+    pub(crate) fn cons_vals(&self, cons: *mut SCIP_Cons) -> Result<Option<Vec<f64>>, Retcode> {
+        let mut n_vars = 0;
+        let mut success = 0;
+        scip_call! { ffi::SCIPgetConsNVars(self.raw, cons, &mut n_vars, &mut success) };
+        if success == 0 {
+            return Ok(None);
+        }
+        let mut vals = vec![0.0; n_vars as usize];
+        scip_call! { ffi::SCIPgetConsVals(self.raw, cons, vals.as_mut_ptr(), n_vars, &mut success) };
+        Ok((success != 0).then_some(vals))
+    }
+
+    /// Returns whether a nonlinear constraint is quadratic.
+    pub(crate) fn cons_is_quadratic(&self, cons: *mut SCIP_Cons) -> Result<bool, Retcode> {
+        let mut is_quadratic = 0;
+        scip_call! { ffi::SCIPcheckQuadraticNonlinear(self.raw, cons, &mut is_quadratic) };
+        Ok(is_quadratic != 0)
+    }
+
+    /// Returns the expression of a nonlinear constraint.
+    pub(crate) fn cons_nonlinear_expr(&self, cons: *mut SCIP_Cons) -> Option<*mut ffi::SCIP_EXPR> {
+        let hdlr_name =
+            unsafe { CStr::from_ptr(ffi::SCIPconshdlrGetName(ffi::SCIPconsGetHdlr(cons))) };
+        if hdlr_name.to_str().unwrap() != "nonlinear" {
+            return None;
+        }
+        Some(unsafe { ffi::SCIPgetExprNonlinear(cons) })
+    }
+
+    /// Create set packing constraint
+    pub(crate) fn create_cons_set_pack(
+        &self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        scip_call! { ffi::SCIPcreateConsBasicSetpack(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            0,
+            std::ptr::null_mut(),
+        ) };
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        for var in vars.iter() {
+            scip_call! { ffi::SCIPaddCoefSetppc(self.raw, scip_cons, var.raw) };
+        }
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Create cardinality constraint
+    pub(crate) fn create_cons_cardinality(
+        &self,
+        vars: Vec<&Variable>,
+        cardinality: usize,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        scip_call! { ffi::SCIPcreateConsBasicCardinality(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            0,
+            std::ptr::null_mut(),
+            0,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        ) };
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        for (ind, var) in vars.iter().enumerate() {
+            scip_call! { ffi::SCIPaddVarCardinality(self.raw, scip_cons, var.raw, std::ptr::null_mut(), ind as f64) };
+        }
+        scip_call! { ffi:: SCIPchgCardvalCardinality(self.raw, scip_cons, cardinality as i32) };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Get number of constraints added in node
+    pub(crate) fn node_get_n_added_conss(&self, node: &Node) -> usize {
+        unsafe { ffi::SCIPnodeGetNAddedConss(node.raw) as usize }
+    }
+
+    pub(crate) fn var_from_id(scip: *mut Scip, var_prob_id: usize) -> Option<*mut SCIP_Var> {
+        let n_vars = unsafe { ffi::SCIPgetNVars(scip) as usize };
+        let var = unsafe { *ffi::SCIPgetVars(scip).add(var_prob_id) };
+        if var_prob_id >= n_vars {
+            None
+        } else {
+            Some(var)
+        }
+    }
+
+    /// Create indicator constraint
+    pub(crate) fn create_cons_indicator(
+        &self,
+        bin_var: &Variable,
+        vars: Vec<&Variable>,
+        coefs: &mut [f64],
+        rhs: f64,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        assert_eq!(vars.len(), coefs.len());
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+
+        scip_call! { ffi::SCIPcreateConsBasicIndicator(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            bin_var.raw,
+            vars.len() as c_int,
+            (vars.into_iter()
+              .map(|var_rc| var_rc.raw)
+                .collect::<Vec<_>>()).as_mut_ptr(),
+            coefs.as_mut_ptr(),
+            rhs,
+        ) };
+
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Create indicator constraint `bin_var = active_on -> vars * coefs <= rhs`, or `>= rhs` if
+    /// `less_than` is false. SCIP uses the negated binary variable if it is active on zero.
+    pub(crate) fn create_cons_indicator_generic(
+        &self,
+        bin_var: &Variable,
+        vars: &[&Variable],
+        coefs: &[f64],
+        rhs: f64,
+        active_on: bool,
+        less_than: bool,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        assert_eq!(vars.len(), coefs.len());
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+        let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+        let mut coefs = coefs.to_vec();
+
+        scip_call! { ffi::SCIPcreateConsIndicatorGeneric(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            bin_var.raw,
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
+            coefs.as_mut_ptr(),
+            rhs,
+            active_on.into(),
+            less_than.into(),
+            true.into(),  // initial
+            true.into(),  // separate
+            true.into(),  // enforce
+            true.into(),  // check
+            true.into(),  // propagate
+            false.into(), // local
+            false.into(), // dynamic
+            false.into(), // removable
+            false.into(), // stickingatnode
+        ) };
+
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+        Ok(scip_cons)
+    }
+
+    /// Create solution
+    pub(crate) fn create_sol(&self, original: bool) -> Result<*mut SCIP_SOL, Retcode> {
+        let mut sol = MaybeUninit::uninit();
+        if original {
+            scip_call! { ffi::SCIPcreateOrigSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
+        } else {
+            scip_call! { ffi::SCIPcreateSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
+        }
+        let sol = unsafe { sol.assume_init() };
+        assert!(!sol.is_null());
+        Ok(sol)
+    }
+
+    /// Create a partial solution: unset variables are UNKNOWN, not 0.
+    pub(crate) fn create_partial_sol(&self) -> Result<*mut SCIP_SOL, Retcode> {
+        let mut sol = MaybeUninit::uninit();
+        scip_call! { ffi::SCIPcreatePartialSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
+        let sol = unsafe { sol.assume_init() };
+        assert!(!sol.is_null());
+        Ok(sol)
+    }
+
+    /// Add coefficient to set packing/partitioning/covering constraint
+    pub(crate) fn add_cons_coef_setppc(
+        &self,
+        cons: &Constraint,
+        var: &Variable,
+    ) -> Result<(), Retcode> {
+        scip_call! { ffi::SCIPaddCoefSetppc(self.raw, cons.raw, var.raw) };
+        Ok(())
+    }
+
+    pub(crate) unsafe fn lp_branching_cands(
+        scip: *mut ffi::SCIP,
+    ) -> Vec<(*mut SCIP_Var, f64, f64)> {
+        let mut lpcands = MaybeUninit::uninit();
+        let mut lpcandssol = MaybeUninit::uninit();
+        // let mut lpcandsfrac = MaybeUninit::uninit();
+        let mut nlpcands = MaybeUninit::uninit();
+        // let mut npriolpcands = MaybeUninit::uninit();
+        let mut nfracimplvars = MaybeUninit::uninit();
+        unsafe {
+            ffi::SCIPgetLPBranchCands(
+                scip,
+                lpcands.as_mut_ptr(),
+                lpcandssol.as_mut_ptr(),
+                std::ptr::null_mut(),
+                nlpcands.as_mut_ptr(),
+                std::ptr::null_mut(),
+                nfracimplvars.as_mut_ptr(),
+            );
+        }
+        let lpcands = unsafe { lpcands.assume_init() };
+        let lpcandssol = unsafe { lpcandssol.assume_init() };
+        // let lpcandsfrac = unsafe { lpcandsfrac.assume_init() };
+        let nlpcands = unsafe { nlpcands.assume_init() };
+        // let npriolpcands = unsafe { npriolpcands.assume_init() };
+        let mut cands = Vec::with_capacity(nlpcands as usize);
+        for i in 0..nlpcands {
+            let var_ptr = unsafe { *lpcands.add(i as usize) };
+            let var = var_ptr;
+            let lp_sol_val = unsafe { *lpcandssol.add(i as usize) };
+            let frac = lp_sol_val.fract();
+            cands.push((var, lp_sol_val, frac));
+        }
+        cands
+    }
+
+    pub(crate) unsafe fn branch_var_val(
+        scip: *mut ffi::SCIP,
+        var_prob_id: usize,
+        val: f64,
+    ) -> Result<(), Retcode> {
+        let var = ScipPtr::var_from_id(scip, var_prob_id);
+        if var.is_none() {
+            return Err(Retcode::Error);
+        }
+        let var = var.unwrap();
+        scip_call! { ffi::SCIPbranchVarVal(scip, var, val, std::ptr::null_mut(), std::ptr::null_mut(),std::ptr::null_mut()) }
+        Ok(())
+    }
+
+    pub(crate) fn include_eventhdlr(
+        &self,
+        name: &str,
+        desc: &str,
+        eventhdlr: Box<dyn Eventhdlr>,
+    ) -> Result<(), Retcode> {
+        extern "C" fn eventhdlrexec(
+            scip: *mut ffi::SCIP,
+            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
+            event: *mut ffi::SCIP_EVENT,
+            _event_data: *mut ffi::SCIP_EVENTDATA,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+            assert!(!data_ptr.is_null());
+            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let eventhdlr = SCIPEventhdlr { raw: eventhdlr };
+            let event = Event {
+                raw: event,
+                scip: scip_ptr.clone(),
+            };
+            unsafe { (*eventhdlr_ptr).execute(model, eventhdlr, event) };
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn eventhdlrinit(
+            scip: *mut ffi::SCIP,
+            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+            assert!(!data_ptr.is_null());
+            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
+            let event_type = unsafe { (*eventhdlr_ptr).get_type() };
+            unsafe {
+                ffi::SCIPcatchEvent(
+                    scip,
+                    event_type.into(),
+                    eventhdlr,
+                    std::ptr::null_mut(),
+                    std::ptr::null_mut(),
+                )
+            }
+        }
+
+        unsafe extern "C" fn eventhdlrfree(
+            _scip: *mut ffi::SCIP,
+            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+            assert!(!data_ptr.is_null());
+            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
+            drop(unsafe { Box::from_raw(eventhdlr_ptr) });
+            Retcode::Okay.into()
+        }
+
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+        let eventhdlr_ptr = Box::into_raw(Box::new(eventhdlr));
+
+        scip_call! {
+            ffi::SCIPincludeEventhdlr(
+                self.raw,
+                c_name.as_ptr(),
+                c_desc.as_ptr(),
+                None,
+                Some(eventhdlrfree),
+                Some(eventhdlrinit),
+                None,
+                None,
+                None,
+                None,
+                Some(eventhdlrexec),
+                eventhdlr_ptr as *mut ffi::SCIP_EVENTHDLRDATA,
+            )
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn include_branch_rule(
+        &self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        maxdepth: i32,
+        maxbounddist: f64,
+        rule: Box<dyn BranchRule>,
+    ) -> Result<(), Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+
+        // TODO: Add rest of branching rule plugin callbacks
+
+        extern "C" fn branchexeclp(
+            scip: *mut ffi::SCIP,
+            branchrule: *mut ffi::SCIP_BRANCHRULE,
+            _: u32,
+            res: *mut ffi::SCIP_RESULT,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
+            assert!(!data_ptr.is_null());
+            let rule_ptr = data_ptr as *mut Box<dyn BranchRule>;
+            let cands = unsafe { ScipPtr::lp_branching_cands(scip) }
+                .into_iter()
+                .map(|(scip_var, lp_sol_val, frac)| BranchingCandidate {
+                    var_prob_id: unsafe { ffi::SCIPvarGetProbindex(scip_var) } as usize,
+                    lp_sol_val,
+                    frac,
+                })
+                .collect::<Vec<_>>();
+            let scip_ptr = ScipPtr::from_raw(scip, true);
+            let model = Model {
+                scip: Rc::new(scip_ptr),
+                state: PhantomData,
+            };
+            let branchrule = SCIPBranchRule { raw: branchrule };
+            let branching_res = unsafe { (*rule_ptr).execute(model, branchrule, cands) };
+
+            if let BranchingResult::BranchOn(cand) = branching_res.clone() {
+                unsafe {
+                    ScipPtr::branch_var_val(scip, cand.var_prob_id, cand.lp_sol_val).unwrap();
+                }
+            };
+
+            if branching_res == BranchingResult::CustomBranching {
+                assert!(
+                    unsafe { ffi::SCIPgetNChildren(scip) > 0 },
+                    "Custom branching rule must create at least one child node"
+                )
+            }
+
+            unsafe { *res = branching_res.into() };
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn branchfree(
+            _scip: *mut ffi::SCIP,
+            branchrule: *mut ffi::SCIP_BRANCHRULE,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn BranchRule>) });
+            Retcode::Okay.into()
+        }
+
+        let rule_ptr = Box::into_raw(Box::new(rule));
+        let branchrule_faker = rule_ptr as *mut ffi::SCIP_BranchruleData;
+
+        scip_call!(ffi::SCIPincludeBranchrule(
+            self.raw,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            priority,
+            maxdepth,
+            maxbounddist,
+            None,
+            Some(branchfree),
+            None,
+            None,
+            None,
+            None,
+            Some(branchexeclp),
+            None,
+            None,
+            branchrule_faker,
+        ));
+
+        Ok(())
+    }
+
+    pub(crate) fn include_nodesel(
+        &self,
+        name: &str,
+        desc: &str,
+        std_priority: i32,
+        mem_save_priority: i32,
+        nodesel: Box<dyn NodeSel>,
+    ) -> Result<(), Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+
+        extern "C" fn nodeselselect(
+            scip: *mut ffi::SCIP,
+            nodesel: *mut ffi::SCIP_NODESEL,
+            selnode: *mut *mut ffi::SCIP_NODE,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPnodeselGetData(nodesel) };
+            assert!(!data_ptr.is_null());
+            let nodesel_ptr = data_ptr as *mut Box<dyn NodeSel>;
+            let scip_ptr = ScipPtr::from_raw(scip, true);
+            let model = Model {
+                scip: Rc::new(scip_ptr),
+                state: PhantomData,
+            };
+            let selected = unsafe { (*nodesel_ptr).select(model) };
+            unsafe {
+                *selnode = match selected {
+                    Some(node) => node.raw,
+                    None => std::ptr::null_mut(),
+                };
+            }
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn nodeselcomp(
+            scip: *mut ffi::SCIP,
+            nodesel: *mut ffi::SCIP_NODESEL,
+            node1: *mut ffi::SCIP_NODE,
+            node2: *mut ffi::SCIP_NODE,
+        ) -> c_int {
+            let data_ptr = unsafe { ffi::SCIPnodeselGetData(nodesel) };
+            assert!(!data_ptr.is_null());
+            let nodesel_ptr = data_ptr as *mut Box<dyn NodeSel>;
+            let scip_rc = Rc::new(ScipPtr::from_raw(scip, true));
+            let n1 = Node {
+                raw: node1,
+                scip: scip_rc.clone(),
+            };
+            let n2 = Node {
+                raw: node2,
+                scip: scip_rc,
+            };
+            let ordering = unsafe { (*nodesel_ptr).comp(n1, n2) };
+            ordering as c_int
+        }
+
+        extern "C" fn nodeselfree(
+            _scip: *mut ffi::SCIP,
+            nodesel: *mut ffi::SCIP_NODESEL,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPnodeselGetData(nodesel) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn NodeSel>) });
+            Retcode::Okay.into()
+        }
+
+        let nodesel_ptr = Box::into_raw(Box::new(nodesel));
+        let nodesel_faker = nodesel_ptr as *mut ffi::SCIP_NODESELDATA;
+
+        scip_call!(ffi::SCIPincludeNodesel(
+            self.raw,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            std_priority,
+            mem_save_priority,
+            None,
+            Some(nodeselfree),
+            None,
+            None,
+            None,
+            None,
+            Some(nodeselselect),
+            Some(nodeselcomp),
+            nodesel_faker,
+        ));
+
+        Ok(())
+    }
+
+    pub(crate) fn include_pricer(
+        &self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        delay: bool,
+        pricer: Box<dyn Pricer>,
+    ) -> Result<(), Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+
+        pub(crate) fn call_pricer(
+            scip: *mut ffi::SCIP,
+            pricer: *mut ffi::SCIP_PRICER,
+            lowerbound: *mut f64,
+            stopearly: *mut ::std::os::raw::c_uint,
+            result: *mut ffi::SCIP_RESULT,
+            farkas: bool,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
+            assert!(!data_ptr.is_null());
+            let pricer_ptr = data_ptr as *mut Box<dyn Pricer>;
+
+            let n_vars_before = unsafe { ffi::SCIPgetNVars(scip) };
+
+            let scip_ptr = ScipPtr::from_raw(scip, true);
+            let model = Model {
+                scip: Rc::new(scip_ptr),
+                state: PhantomData,
+            };
+
+            let pricer = SCIPPricer { raw: pricer };
+            let pricing_res = unsafe { (*pricer_ptr).generate_columns(model, pricer, farkas) };
+
+            if !farkas {
+                if let Some(lb) = pricing_res.lower_bound {
+                    unsafe { *lowerbound = lb };
+                }
+                if pricing_res.state == PricerResultState::StopEarly {
+                    unsafe { *stopearly = 1 };
+                }
+            }
+
+            if farkas && pricing_res.state == PricerResultState::StopEarly {
+                panic!("Farkas pricing should never stop early as LP would remain infeasible");
+            }
+
+            if pricing_res.state == PricerResultState::FoundColumns {
+                let n_vars_after = unsafe { ffi::SCIPgetNVars(scip) };
+                if n_vars_before >= n_vars_after {
+                    return Retcode::Error.into();
+                }
+            }
+
+            unsafe { *result = pricing_res.state.into() };
+            Retcode::Okay.into()
+        }
+
+        unsafe extern "C" fn pricerredcost(
+            scip: *mut ffi::SCIP,
+            pricer: *mut ffi::SCIP_PRICER,
+            lowerbound: *mut f64,
+            stopearly: *mut ::std::os::raw::c_uint,
+            result: *mut ffi::SCIP_RESULT,
+        ) -> ffi::SCIP_Retcode {
+            call_pricer(scip, pricer, lowerbound, stopearly, result, false)
+        }
+
+        unsafe extern "C" fn pricerfakas(
+            scip: *mut ffi::SCIP,
+            pricer: *mut ffi::SCIP_PRICER,
+            result: *mut ffi::SCIP_RESULT,
+        ) -> ffi::SCIP_Retcode {
+            call_pricer(
+                scip,
+                pricer,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                result,
+                true,
+            )
+        }
+
+        unsafe extern "C" fn pricerfree(
+            _scip: *mut ffi::SCIP,
+            pricer: *mut ffi::SCIP_PRICER,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Pricer>) });
+            Retcode::Okay.into()
+        }
+
+        let pricer_ptr = Box::into_raw(Box::new(pricer));
+        let pricer_faker = pricer_ptr as *mut ffi::SCIP_PricerData;
+
+        scip_call!(ffi::SCIPincludePricer(
+            self.raw,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            priority,
+            delay.into(),
+            None,
+            Some(pricerfree),
+            None,
+            None,
+            None,
+            None,
+            Some(pricerredcost),
+            Some(pricerfakas),
+            pricer_faker,
+        ));
+
+        unsafe {
+            ffi::SCIPactivatePricer(self.raw, ffi::SCIPfindPricer(self.raw, c_name.as_ptr()));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn include_heur(
+        &self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        dispchar: char,
+        freq: i32,
+        freqofs: i32,
+        maxdepth: i32,
+        timing: HeurTiming,
+        usessubscip: bool,
+        heur: Box<dyn Heuristic>,
     ) -> Result<(), Retcode> {
-        scip_call! { ffi::SCIPaddCoefSetppc(self.raw, cons.raw, var.raw) };
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+
+        extern "C" fn heurexec(
+            scip: *mut ffi::SCIP,
+            heur: *mut ffi::SCIP_HEUR,
+            heurtiming: ffi::SCIP_HEURTIMING,
+            nodeinfeasible: ::std::os::raw::c_uint,
+            result: *mut ffi::SCIP_RESULT,
+        ) -> ffi::SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
+            assert!(!data_ptr.is_null());
+            let rule_ptr = data_ptr as *mut Box<dyn Heuristic>;
+
+            let current_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
+            let scip_ptr = ScipPtr::from_raw(scip, true);
+            let model = Model {
+                scip: Rc::new(scip_ptr),
+                state: PhantomData,
+            };
+            let heur_res =
+                unsafe { (*rule_ptr).execute(model, heurtiming.into(), nodeinfeasible != 0) };
+            if heur_res == HeurResult::FoundSol {
+                let new_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
+
+                if new_n_sols <= current_n_sols {
+                    let heur_name =
+                        unsafe { CStr::from_ptr(ffi::SCIPheurGetName(heur)).to_str().unwrap() };
+                    eprintln!(
+                        "Heuristic {heur_name} returned result {heur_res:?}, but no solutions were added"
+                    );
+                    return Retcode::Error.into();
+                }
+            }
+
+            unsafe { *result = heur_res.into() };
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn heurfree(
+            _scip: *mut ffi::SCIP,
+            heur: *mut ffi::SCIP_HEUR,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Heuristic>) });
+            Retcode::Okay.into()
+        }
+
+        let ptr = Box::into_raw(Box::new(heur));
+        let heur_faker = ptr as *mut ffi::SCIP_HEURDATA;
+
+        scip_call!(ffi::SCIPincludeHeur(
+            self.raw,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            dispchar as ::std::os::raw::c_char,
+            priority,
+            freq,
+            freqofs,
+            maxdepth,
+            timing.into(),
+            usessubscip.into(),
+            None,
+            Some(heurfree),
+            None,
+            None,
+            None,
+            None,
+            Some(heurexec),
+            heur_faker,
+        ));
+
         Ok(())
     }
 
-    pub(crate) unsafe fn lp_branching_cands(
-        scip: *mut ffi::SCIP,
-    ) -> Vec<(*mut SCIP_Var, f64, f64)> {
-        let mut lpcands = MaybeUninit::uninit();
-        let mut lpcandssol = MaybeUninit::uninit();
-        // let mut lpcandsfrac = MaybeUninit::uninit();
-        let mut nlpcands = MaybeUninit::uninit();
-        // let mut npriolpcands = MaybeUninit::uninit();
-        let mut nfracimplvars = MaybeUninit::uninit();
-        unsafe {
-            ffi::SCIPgetLPBranchCands(
-                scip,
-                lpcands.as_mut_ptr(),
-                lpcandssol.as_mut_ptr(),
-                std::ptr::null_mut(),
-                nlpcands.as_mut_ptr(),
-                std::ptr::null_mut(),
-                nfracimplvars.as_mut_ptr(),
-            );
+    pub(crate) fn include_separator(
+        &self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        maxbounddist: f64,
+        usesubscip: bool,
+        delay: bool,
+        separator: Box<dyn Separator>,
+    ) -> Result<(), Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+
+        extern "C" fn sepexeclp(
+            scip: *mut ffi::SCIP,
+            separator: *mut ffi::SCIP_SEPA,
+            result: *mut ffi::SCIP_RESULT,
+            _allowlocal: ::std::os::raw::c_uint,
+            _depth: ::std::os::raw::c_int,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
+            assert!(!data_ptr.is_null());
+            let rule_ptr = data_ptr as *mut Box<dyn Separator>;
+
+            let scip_ptr = ScipPtr::from_raw(scip, true);
+            let model = Model {
+                scip: Rc::new(scip_ptr),
+                state: PhantomData,
+            };
+            let separator = SCIPSeparator { raw: separator };
+            let sep_res = unsafe { (*rule_ptr).execute_lp(model, separator) };
+
+            unsafe { *result = sep_res.into() };
+
+            Retcode::Okay.into()
         }
-        let lpcands = unsafe { lpcands.assume_init() };
-        let lpcandssol = unsafe { lpcandssol.assume_init() };
-        // let lpcandsfrac = unsafe { lpcandsfrac.assume_init() };
-        let nlpcands = unsafe { nlpcands.assume_init() };
-        // let npriolpcands = unsafe { npriolpcands.assume_init() };
-        let mut cands = Vec::with_capacity(nlpcands as usize);
-        for i in 0..nlpcands {
-            let var_ptr = unsafe { *lpcands.add(i as usize) };
-            let var = var_ptr;
-            let lp_sol_val = unsafe { *lpcandssol.add(i as usize) };
-            let frac = lp_sol_val.fract();
-            cands.push((var, lp_sol_val, frac));
+
+        extern "C" fn sepexecsol(
+            _scip: *mut ffi::SCIP,
+            _separator: *mut ffi::SCIP_SEPA,
+            _sol: *mut SCIP_SOL,
+            _result: *mut ffi::SCIP_RESULT,
+            _allowlocal: ::std::os::raw::c_uint,
+            _depth: ::std::os::raw::c_int,
+        ) -> ffi::SCIP_Retcode {
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn sepfree(
+            _scip: *mut ffi::SCIP,
+            separator: *mut ffi::SCIP_SEPA,
+        ) -> ffi::SCIP_Retcode {
+            let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Separator>) });
+            Retcode::Okay.into()
+        }
+
+        let ptr = Box::into_raw(Box::new(separator));
+        let sep_faker = ptr as *mut ffi::SCIP_SEPADATA;
+
+        scip_call!(ffi::SCIPincludeSepa(
+            self.raw,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            priority,
+            freq,
+            maxbounddist,
+            usesubscip.into(),
+            delay.into(),
+            None,
+            Some(sepfree),
+            None,
+            None,
+            None,
+            None,
+            Some(sepexeclp),
+            Some(sepexecsol),
+            sep_faker,
+        ));
+
+        Ok(())
+    }
+
+    pub(crate) fn include_prop(
+        &self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        delay: bool,
+        timing: PropTiming,
+        presol_priority: i32,
+        presol_maxrounds: i32,
+        presol_timing: PresolTiming,
+        prop: Box<dyn Propagator>,
+    ) -> Result<(), Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+
+        extern "C" fn propexec(
+            scip: *mut SCIP,
+            prop: *mut ffi::SCIP_PROP,
+            proptiming: ffi::SCIP_PROPTIMING,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPpropGetData(prop) };
+            assert!(!data_ptr.is_null());
+            let prop_ptr = data_ptr as *mut Box<dyn Propagator>;
+
+            let model = Model {
+                scip: Rc::new(ScipPtr::from_raw(scip, true)),
+                state: PhantomData,
+            };
+            let prop = SCIPPropagator { raw: prop };
+            let prop_res = unsafe { (*prop_ptr).propagate(model, prop, proptiming.into()) };
+
+            unsafe { *result = prop_res.into() };
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn proppresol(
+            scip: *mut SCIP,
+            prop: *mut ffi::SCIP_PROP,
+            _nrounds: c_int,
+            _presoltiming: ffi::SCIP_PRESOLTIMING,
+            _nnewfixedvars: c_int,
+            _nnewaggrvars: c_int,
+            _nnewchgvartypes: c_int,
+            _nnewchgbds: c_int,
+            _nnewholes: c_int,
+            _nnewdelconss: c_int,
+            _nnewaddconss: c_int,
+            _nnewupgdconss: c_int,
+            _nnewchgcoefs: c_int,
+            _nnewchgsides: c_int,
+            nfixedvars: *mut c_int,
+            naggrvars: *mut c_int,
+            nchgvartypes: *mut c_int,
+            nchgbds: *mut c_int,
+            naddholes: *mut c_int,
+            ndelconss: *mut c_int,
+            naddconss: *mut c_int,
+            nupgdconss: *mut c_int,
+            nchgcoefs: *mut c_int,
+            nchgsides: *mut c_int,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPpropGetData(prop) };
+            assert!(!data_ptr.is_null());
+            let prop_ptr = data_ptr as *mut Box<dyn Propagator>;
+
+            let model = Model {
+                scip: Rc::new(ScipPtr::from_raw(scip, true)),
+                state: PhantomData,
+            };
+            let prop = SCIPPropagator { raw: prop };
+            let mut changes = PresolveChanges::default();
+            let presol_res = unsafe { (*prop_ptr).presolve(model, prop, &mut changes) };
+
+            unsafe {
+                *nfixedvars += changes.n_fixed_vars;
+                *naggrvars += changes.n_aggr_vars;
+                *nchgvartypes += changes.n_chg_var_types;
+                *nchgbds += changes.n_chg_bds;
+                *naddholes += changes.n_add_holes;
+                *ndelconss += changes.n_del_conss;
+                *naddconss += changes.n_add_conss;
+                *nupgdconss += changes.n_upgd_conss;
+                *nchgcoefs += changes.n_chg_coefs;
+                *nchgsides += changes.n_chg_sides;
+                *result = presol_res.into();
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn propresprop(
+            scip: *mut SCIP,
+            prop: *mut ffi::SCIP_PROP,
+            infervar: *mut SCIP_Var,
+            inferinfo: c_int,
+            boundtype: ffi::SCIP_BOUNDTYPE,
+            bdchgidx: *mut ffi::SCIP_BDCHGIDX,
+            relaxedbd: f64,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPpropGetData(prop) };
+            assert!(!data_ptr.is_null());
+            let prop_ptr = data_ptr as *mut Box<dyn Propagator>;
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let inference = Inference {
+                var: Variable {
+                    raw: infervar,
+                    scip: scip_ptr.clone(),
+                },
+                info: inferinfo,
+                bound_type: boundtype.into(),
+                relaxed_bound: relaxedbd,
+                bdchgidx,
+                scip: scip_ptr.clone(),
+            };
+            let prop = SCIPPropagator { raw: prop };
+            let res = unsafe { (*prop_ptr).resolve_propagation(model, prop, &inference) };
+
+            unsafe { *result = res.into() };
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn propfree(_scip: *mut SCIP, prop: *mut ffi::SCIP_PROP) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPpropGetData(prop) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Propagator>) });
+            Retcode::Okay.into()
+        }
+
+        let ptr = Box::into_raw(Box::new(prop));
+        let prop_faker = ptr as *mut ffi::SCIP_PROPDATA;
+
+        let mut prop: *mut ffi::SCIP_PROP = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludePropBasic(
+            self.raw,
+            &mut prop,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            priority,
+            freq,
+            delay.into(),
+            timing.into(),
+            Some(propexec),
+            prop_faker,
+        ));
+
+        scip_call!(ffi::SCIPsetPropFree(self.raw, prop, Some(propfree)));
+        scip_call!(ffi::SCIPsetPropResprop(self.raw, prop, Some(propresprop)));
+        if presol_timing != PresolTiming::NONE {
+            scip_call!(ffi::SCIPsetPropPresol(
+                self.raw,
+                prop,
+                Some(proppresol),
+                presol_priority,
+                presol_maxrounds,
+                presol_timing.into(),
+            ));
         }
-        cands
+
+        Ok(())
     }
 
-    pub(crate) unsafe fn branch_var_val(
-        scip: *mut ffi::SCIP,
-        var_prob_id: usize,
-        val: f64,
-    ) -> Result<(), Retcode> {
-        let var = ScipPtr::var_from_id(scip, var_prob_id);
-        if var.is_none() {
-            return Err(Retcode::Error);
+    pub(crate) fn infer_var_bound(
+        &self,
+        var: &Variable,
+        bound: f64,
+        bound_type: BoundType,
+        prop: &SCIPPropagator,
+        info: i32,
+    ) -> Result<Tightening, Retcode> {
+        let var_ptr = self.transformed_var_ptr(var)?;
+        let mut infeasible = 0;
+        let mut tightened = 0;
+        match bound_type {
+            BoundType::Lower => {
+                scip_call! { ffi::SCIPinferVarLbProp(self.raw, var_ptr, bound, prop.raw, info, false.into(), &mut infeasible, &mut tightened) }
+            }
+            BoundType::Upper => {
+                scip_call! { ffi::SCIPinferVarUbProp(self.raw, var_ptr, bound, prop.raw, info, false.into(), &mut infeasible, &mut tightened) }
+            }
         }
-        let var = var.unwrap();
-        scip_call! { ffi::SCIPbranchVarVal(scip, var, val, std::ptr::null_mut(), std::ptr::null_mut(),std::ptr::null_mut()) }
-        Ok(())
+
+        Ok(if infeasible != 0 {
+            Tightening::Infeasible
+        } else if tightened != 0 {
+            Tightening::Tightened
+        } else {
+            Tightening::Redundant
+        })
     }
 
-    pub(crate) fn include_eventhdlr(
+    /// Returns the transformed counterpart of the given variable, or the variable itself if it
+    /// is already transformed.
+    pub(crate) fn transformed_var_ptr(&self, var: &Variable) -> Result<*mut SCIP_Var, Retcode> {
+        if unsafe { ffi::SCIPvarIsTransformed(var.raw) } == 1 {
+            return Ok(var.raw);
+        }
+        let mut transformed_var = MaybeUninit::<*mut SCIP_Var>::uninit();
+        scip_call! { ffi::SCIPgetTransformedVar(self.raw, var.raw, transformed_var.as_mut_ptr()) };
+        let transformed_var = unsafe { transformed_var.assume_init() };
+        if transformed_var.is_null() {
+            return Err(Retcode::InvalidData);
+        }
+        Ok(transformed_var)
+    }
+
+    pub(crate) fn include_reader(
         &self,
         name: &str,
         desc: &str,
-        eventhdlr: Box<dyn Eventhdlr>,
-    ) -> Result<(), Retcode> {
-        extern "C" fn eventhdlrexec(
-            scip: *mut ffi::SCIP,
-            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
-            event: *mut ffi::SCIP_EVENT,
-            _event_data: *mut ffi::SCIP_EVENTDATA,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+        extension: &str,
+        reader: Box<dyn Reader>,
+    ) -> Result<SCIPReader, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let c_desc = CString::new(desc).unwrap();
+        let c_extension = CString::new(extension).unwrap();
+
+        fn reader_data(reader: *mut ffi::SCIP_READER) -> *mut Box<dyn Reader> {
+            let data_ptr = unsafe { ffi::SCIPreaderGetData(reader) };
             assert!(!data_ptr.is_null());
-            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
-            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            data_ptr as *mut Box<dyn Reader>
+        }
+
+        extern "C" fn readerread(
+            scip: *mut SCIP,
+            reader: *mut ffi::SCIP_READER,
+            filename: *const std::os::raw::c_char,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let reader_ptr = reader_data(reader);
+            let path = unsafe { CStr::from_ptr(filename) }.to_str().unwrap();
             let model = Model {
-                scip: scip_ptr.clone(),
+                scip: Rc::new(ScipPtr::from_raw(scip, true)),
                 state: PhantomData,
             };
-            let eventhdlr = SCIPEventhdlr { raw: eventhdlr };
-            let event = Event {
-                raw: event,
-                scip: scip_ptr.clone(),
-            };
-            unsafe { (*eventhdlr_ptr).execute(model, eventhdlr, event) };
-            Retcode::Okay.into()
+
+            let read_res = unsafe { (*reader_ptr).read(model, path) };
+
+            // `read_prob` captures all variables and constraints once the problem was created,
+            // so the references held by the ones created through the model are given up here
+            if unsafe { ffi::SCIPgetStage(scip) } == ffi::SCIP_Stage_SCIP_STAGE_PROBLEM {
+                let n_vars = unsafe { ffi::SCIPgetNOrigVars(scip) };
+                let vars = unsafe { ffi::SCIPgetOrigVars(scip) };
+                for i in 0..n_vars {
+                    let mut var = unsafe { *vars.add(i as usize) };
+                    scip_call_panic!(ffi::SCIPreleaseVar(scip, &mut var));
+                }
+                let n_conss = unsafe { ffi::SCIPgetNOrigConss(scip) };
+                let conss = unsafe { ffi::SCIPgetOrigConss(scip) };
+                for i in 0..n_conss {
+                    let mut cons = unsafe { *conss.add(i as usize) };
+                    scip_call_panic!(ffi::SCIPreleaseCons(scip, &mut cons));
+                }
+            }
+
+            match read_res {
+                Ok(read_res) => {
+                    unsafe { *result = read_res.into() };
+                    Retcode::Okay.into()
+                }
+                Err(retcode) => retcode.into(),
+            }
         }
 
-        extern "C" fn eventhdlrinit(
-            scip: *mut ffi::SCIP,
-            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
-            assert!(!data_ptr.is_null());
-            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
-            let event_type = unsafe { (*eventhdlr_ptr).get_type() };
-            unsafe {
-                ffi::SCIPcatchEvent(
-                    scip,
-                    event_type.into(),
-                    eventhdlr,
-                    std::ptr::null_mut(),
-                    std::ptr::null_mut(),
-                )
+        extern "C" fn readerwrite(
+            scip: *mut SCIP,
+            reader: *mut ffi::SCIP_READER,
+            file: *mut ffi::FILE,
+            _filename: *const std::os::raw::c_char,
+            _name: *const std::os::raw::c_char,
+            _probdata: *mut ffi::SCIP_PROBDATA,
+            _transformed: std::os::raw::c_uint,
+            _objsense: ffi::SCIP_OBJSENSE,
+            _objoffset: f64,
+            _objscale: f64,
+            _objoffsetexact: *mut ffi::SCIP_RATIONAL,
+            _objscaleexact: *mut ffi::SCIP_RATIONAL,
+            vars: *mut *mut SCIP_Var,
+            nvars: c_int,
+            _nbinvars: c_int,
+            _nintvars: c_int,
+            _nimplvars: c_int,
+            _ncontvars: c_int,
+            _fixedvars: *mut *mut SCIP_Var,
+            _nfixedvars: c_int,
+            _startnvars: c_int,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            _maxnconss: c_int,
+            _startnconss: c_int,
+            _genericnames: std::os::raw::c_uint,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let reader_ptr = reader_data(reader);
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let vars = (0..nvars as usize)
+                .map(|i| Variable {
+                    raw: unsafe { *vars.add(i) },
+                    scip: scip_ptr.clone(),
+                })
+                .collect::<Vec<_>>();
+            let conss = (0..nconss as usize)
+                .map(|i| Constraint {
+                    raw: unsafe { *conss.add(i) },
+                    scip: scip_ptr.clone(),
+                })
+                .collect::<Vec<_>>();
+            let model = Model {
+                scip: scip_ptr,
+                state: PhantomData,
+            };
+
+            let mut writer = FileWriter { file };
+            match unsafe { (*reader_ptr).write(model, &vars, &conss, &mut writer) } {
+                Ok(write_res) => {
+                    unsafe { *result = write_res.into() };
+                    Retcode::Okay.into()
+                }
+                Err(retcode) => retcode.into(),
             }
         }
 
-        unsafe extern "C" fn eventhdlrfree(
-            _scip: *mut ffi::SCIP,
-            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
-            assert!(!data_ptr.is_null());
-            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
-            drop(unsafe { Box::from_raw(eventhdlr_ptr) });
+        extern "C" fn readerfree(_scip: *mut SCIP, reader: *mut ffi::SCIP_READER) -> SCIP_RETCODE {
+            drop(unsafe { Box::from_raw(reader_data(reader)) });
             Retcode::Okay.into()
         }
 
+        let ptr = Box::into_raw(Box::new(reader));
+        let reader_faker = ptr as *mut ffi::SCIP_READERDATA;
+
+        let mut reader: *mut ffi::SCIP_READER = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludeReaderBasic(
+            self.raw,
+            &mut reader,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            c_extension.as_ptr(),
+            reader_faker,
+        ));
+        scip_call!(ffi::SCIPsetReaderRead(self.raw, reader, Some(readerread)));
+        scip_call!(ffi::SCIPsetReaderWrite(self.raw, reader, Some(readerwrite)));
+        scip_call!(ffi::SCIPsetReaderFree(self.raw, reader, Some(readerfree)));
+
+        Ok(SCIPReader { raw: reader })
+    }
+
+    pub(crate) fn include_relax(
+        &self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        relax: Box<dyn Relaxator>,
+    ) -> Result<(), Retcode> {
         let c_name = CString::new(name).unwrap();
         let c_desc = CString::new(desc).unwrap();
-        let eventhdlr_ptr = Box::into_raw(Box::new(eventhdlr));
 
-        scip_call! {
-            ffi::SCIPincludeEventhdlr(
-                self.raw,
-                c_name.as_ptr(),
-                c_desc.as_ptr(),
-                None,
-                Some(eventhdlrfree),
-                Some(eventhdlrinit),
-                None,
-                None,
-                None,
-                None,
-                Some(eventhdlrexec),
-                eventhdlr_ptr as *mut ffi::SCIP_EVENTHDLRDATA,
-            )
+        extern "C" fn relaxexec(
+            scip: *mut SCIP,
+            relax: *mut ffi::SCIP_RELAX,
+            lowerbound: *mut f64,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPrelaxGetData(relax) };
+            assert!(!data_ptr.is_null());
+            let relax_ptr = data_ptr as *mut Box<dyn Relaxator>;
+
+            let model = Model {
+                scip: Rc::new(ScipPtr::from_raw(scip, true)),
+                state: PhantomData,
+            };
+            let relax = SCIPRelaxator { raw: relax };
+            let relax_res = unsafe { (*relax_ptr).solve(model, relax, &mut *lowerbound) };
+
+            unsafe { *result = relax_res.into() };
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn relaxfree(_scip: *mut SCIP, relax: *mut ffi::SCIP_RELAX) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPrelaxGetData(relax) };
+            assert!(!data_ptr.is_null());
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Relaxator>) });
+            Retcode::Okay.into()
         }
 
+        let ptr = Box::into_raw(Box::new(relax));
+        let relax_faker = ptr as *mut ffi::SCIP_RELAXDATA;
+
+        let mut relax: *mut ffi::SCIP_RELAX = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludeRelaxBasic(
+            self.raw,
+            &mut relax,
+            c_name.as_ptr(),
+            c_desc.as_ptr(),
+            priority,
+            freq,
+            Some(relaxexec),
+            relax_faker,
+        ));
+        scip_call!(ffi::SCIPsetRelaxFree(self.raw, relax, Some(relaxfree)));
+
         Ok(())
     }
 
-    pub(crate) fn include_branch_rule(
+    pub(crate) fn set_relax_sol(
+        &self,
+        relax: &SCIPRelaxator,
+        vars: &[Variable],
+        vals: &[f64],
+        includes_lp: bool,
+    ) -> Result<(), Retcode> {
+        assert_eq!(vars.len(), vals.len());
+        let mut var_ptrs = vars
+            .iter()
+            .map(|var| self.transformed_var_ptr(var))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut vals = vals.to_vec();
+        scip_call! { ffi::SCIPsetRelaxSolVals(
+            self.raw,
+            relax.raw,
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
+            vals.as_mut_ptr(),
+            includes_lp.into(),
+        ) };
+        Ok(())
+    }
+
+    pub(crate) fn relax_sol_val(&self, var: &Variable) -> Result<f64, Retcode> {
+        let var_ptr = self.transformed_var_ptr(var)?;
+        Ok(unsafe { ffi::SCIPgetRelaxSolVal(self.raw, var_ptr) })
+    }
+
+    pub(crate) fn include_conflicthdlr(
         &self,
         name: &str,
         desc: &str,
         priority: i32,
-        maxdepth: i32,
-        maxbounddist: f64,
-        rule: Box<dyn BranchRule>,
+        conflicthdlr: Box<dyn ConflictHdlr>,
     ) -> Result<(), Retcode> {
         let c_name = CString::new(name).unwrap();
         let c_desc = CString::new(desc).unwrap();
 
-        // TODO: Add rest of branching rule plugin callbacks
-
-        extern "C" fn branchexeclp(
-            scip: *mut ffi::SCIP,
-            branchrule: *mut ffi::SCIP_BRANCHRULE,
-            _: u32,
-            res: *mut ffi::SCIP_RESULT,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
+        extern "C" fn conflictexec(
+            scip: *mut SCIP,
+            conflicthdlr: *mut ffi::SCIP_CONFLICTHDLR,
+            node: *mut SCIP_NODE,
+            validnode: *mut SCIP_NODE,
+            bdchginfos: *mut *mut ffi::SCIP_BDCHGINFO,
+            relaxedbds: *mut f64,
+            nbdchginfos: c_int,
+            conftype: ffi::SCIP_CONFTYPE,
+            cutoffinvolved: std::os::raw::c_uint,
+            separate: std::os::raw::c_uint,
+            local: std::os::raw::c_uint,
+            dynamic: std::os::raw::c_uint,
+            removable: std::os::raw::c_uint,
+            resolved: std::os::raw::c_uint,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPconflicthdlrGetData(conflicthdlr) };
             assert!(!data_ptr.is_null());
-            let rule_ptr = data_ptr as *mut Box<dyn BranchRule>;
-            let cands = unsafe { ScipPtr::lp_branching_cands(scip) }
-                .into_iter()
-                .map(|(scip_var, lp_sol_val, frac)| BranchingCandidate {
-                    var_prob_id: unsafe { ffi::SCIPvarGetProbindex(scip_var) } as usize,
-                    lp_sol_val,
-                    frac,
+            let conflicthdlr_ptr = data_ptr as *mut Box<dyn ConflictHdlr>;
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let bound_changes = (0..nbdchginfos as usize)
+                .map(|i| {
+                    let bdchginfo = unsafe { *bdchginfos.add(i) };
+                    let var = Variable {
+                        raw: unsafe { ffi::SCIPbdchginfoGetVar(bdchginfo) },
+                        scip: scip_ptr.clone(),
+                    };
+                    let bound_type = unsafe { ffi::SCIPbdchginfoGetBoundtype(bdchginfo) }.into();
+                    (var, bound_type, unsafe { *relaxedbds.add(i) })
                 })
-                .collect::<Vec<_>>();
-            let scip_ptr = ScipPtr::from_raw(scip, true);
-            let model = Model {
-                scip: Rc::new(scip_ptr),
-                state: PhantomData,
+                .collect();
+            let conflict = Conflict {
+                bound_changes,
+                valid_depth: unsafe { ffi::SCIPnodeGetDepth(validnode) } as usize,
+                conflict_type: conftype.into(),
+                cutoff_involved: cutoffinvolved != 0,
+                separate: separate != 0,
+                local: local != 0,
+                dynamic: dynamic != 0,
+                removable: removable != 0,
+                resolved: resolved != 0,
+                node,
+                valid_node: validnode,
             };
-            let branchrule = SCIPBranchRule { raw: branchrule };
-            let branching_res = unsafe { (*rule_ptr).execute(model, branchrule, cands) };
 
-            if let BranchingResult::BranchOn(cand) = branching_res.clone() {
-                unsafe {
-                    ScipPtr::branch_var_val(scip, cand.var_prob_id, cand.lp_sol_val).unwrap();
-                }
+            let model = Model {
+                scip: scip_ptr,
+                state: PhantomData,
             };
+            let conflicthdlr = SCIPConflictHdlr { raw: conflicthdlr };
+            let conflict_res = unsafe { (*conflicthdlr_ptr).exec(model, conflicthdlr, &conflict) };
 
-            if branching_res == BranchingResult::CustomBranching {
-                assert!(
-                    unsafe { ffi::SCIPgetNChildren(scip) > 0 },
-                    "Custom branching rule must create at least one child node"
-                )
-            }
-
-            unsafe { *res = branching_res.into() };
+            unsafe { *result = conflict_res.into() };
             Retcode::Okay.into()
         }
 
-        extern "C" fn branchfree(
-            _scip: *mut ffi::SCIP,
-            branchrule: *mut ffi::SCIP_BRANCHRULE,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
+        extern "C" fn conflictfree(
+            _scip: *mut SCIP,
+            conflicthdlr: *mut ffi::SCIP_CONFLICTHDLR,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPconflicthdlrGetData(conflicthdlr) };
             assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn BranchRule>) });
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn ConflictHdlr>) });
             Retcode::Okay.into()
         }
 
-        let rule_ptr = Box::into_raw(Box::new(rule));
-        let branchrule_faker = rule_ptr as *mut ffi::SCIP_BranchruleData;
+        let ptr = Box::into_raw(Box::new(conflicthdlr));
+        let conflicthdlr_faker = ptr as *mut ffi::SCIP_CONFLICTHDLRDATA;
 
-        scip_call!(ffi::SCIPincludeBranchrule(
+        let mut conflicthdlr: *mut ffi::SCIP_CONFLICTHDLR = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludeConflicthdlrBasic(
             self.raw,
+            &mut conflicthdlr,
             c_name.as_ptr(),
             c_desc.as_ptr(),
             priority,
-            maxdepth,
-            maxbounddist,
-            None,
-            Some(branchfree),
-            None,
-            None,
-            None,
-            None,
-            Some(branchexeclp),
-            None,
-            None,
-            branchrule_faker,
+            Some(conflictexec),
+            conflicthdlr_faker,
+        ));
+        scip_call!(ffi::SCIPsetConflicthdlrFree(
+            self.raw,
+            conflicthdlr,
+            Some(conflictfree)
         ));
 
         Ok(())
     }
 
-    pub(crate) fn include_nodesel(
+    pub(crate) fn add_conflict_cons(
+        &self,
+        conflict: &Conflict,
+        vars: Vec<&Variable>,
+        coefs: &[f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<(), Retcode> {
+        assert_eq!(vars.len(), coefs.len());
+        let c_name = CString::new(name).unwrap();
+        let mut var_ptrs = vars.iter().map(|var| var.raw).collect::<Vec<_>>();
+        let mut coefs = coefs.to_vec();
+        let mut scip_cons = MaybeUninit::uninit();
+        scip_call! { ffi::SCIPcreateConsLinear(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
+            coefs.as_mut_ptr(),
+            lhs,
+            rhs,
+            false.into(),
+            conflict.separate.into(),
+            true.into(),
+            false.into(),
+            true.into(),
+            conflict.local.into(),
+            false.into(),
+            conflict.dynamic.into(),
+            conflict.removable.into(),
+            false.into(),
+        ) };
+        let mut scip_cons = unsafe { scip_cons.assume_init() };
+        // the constraint is released by SCIP
+        scip_call! { ffi::SCIPaddConflict(
+            self.raw,
+            conflict.node,
+            &mut scip_cons,
+            conflict.valid_node,
+            conflict.conflict_type.into(),
+            conflict.cutoff_involved.into(),
+        ) };
+        Ok(())
+    }
+
+    pub(crate) fn include_cutsel(
         &self,
         name: &str,
         desc: &str,
-        std_priority: i32,
-        mem_save_priority: i32,
-        nodesel: Box<dyn NodeSel>,
+        priority: i32,
+        cutsel: Box<dyn CutSelector>,
     ) -> Result<(), Retcode> {
         let c_name = CString::new(name).unwrap();
         let c_desc = CString::new(desc).unwrap();
 
-        extern "C" fn nodeselselect(
-            scip: *mut ffi::SCIP,
-            nodesel: *mut ffi::SCIP_NODESEL,
-            selnode: *mut *mut ffi::SCIP_NODE,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPnodeselGetData(nodesel) };
+        extern "C" fn cutselselect(
+            scip: *mut SCIP,
+            cutsel: *mut ffi::SCIP_CUTSEL,
+            cuts: *mut *mut ffi::SCIP_ROW,
+            ncuts: c_int,
+            forcedcuts: *mut *mut ffi::SCIP_ROW,
+            nforcedcuts: c_int,
+            root: std::os::raw::c_uint,
+            maxnselectedcuts: c_int,
+            nselectedcuts: *mut c_int,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPcutselGetData(cutsel) };
             assert!(!data_ptr.is_null());
-            let nodesel_ptr = data_ptr as *mut Box<dyn NodeSel>;
-            let scip_ptr = ScipPtr::from_raw(scip, true);
+            let cutsel_ptr = data_ptr as *mut Box<dyn CutSelector>;
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let wrap_rows = |rows: *mut *mut ffi::SCIP_ROW, n_rows: c_int| -> Vec<Row> {
+                (0..n_rows as usize)
+                    .map(|i| Row {
+                        raw: unsafe { *rows.add(i) },
+                        scip: scip_ptr.clone(),
+                    })
+                    .collect()
+            };
+            let cut_rows = wrap_rows(cuts, ncuts);
+            let forced_rows = wrap_rows(forcedcuts, nforcedcuts);
             let model = Model {
-                scip: Rc::new(scip_ptr),
+                scip: scip_ptr.clone(),
                 state: PhantomData,
             };
-            let selected = unsafe { (*nodesel_ptr).select(model) };
+
+            let selected = unsafe {
+                (*cutsel_ptr).select(
+                    model,
+                    &cut_rows,
+                    &forced_rows,
+                    root != 0,
+                    maxnselectedcuts.max(0) as usize,
+                )
+            };
+
+            // move the selected cuts to the front of the array, keeping the others behind them
+            let mut is_selected = vec![false; cut_rows.len()];
+            let mut order = Vec::with_capacity(cut_rows.len());
+            for i in selected.into_iter().take(maxnselectedcuts.max(0) as usize) {
+                if i >= cut_rows.len() {
+                    return Retcode::InvalidData.into();
+                }
+                if !is_selected[i] {
+                    is_selected[i] = true;
+                    order.push(i);
+                }
+            }
+            let n_selected = order.len();
+            order.extend((0..cut_rows.len()).filter(|&i| !is_selected[i]));
+            for (pos, i) in order.into_iter().enumerate() {
+                unsafe { *cuts.add(pos) = cut_rows[i].raw };
+            }
+
             unsafe {
-                *selnode = match selected {
-                    Some(node) => node.raw,
-                    None => std::ptr::null_mut(),
-                };
+                *nselectedcuts = n_selected as c_int;
+                *result = ffi::SCIP_Result_SCIP_SUCCESS;
             }
             Retcode::Okay.into()
         }
 
-        extern "C" fn nodeselcomp(
-            scip: *mut ffi::SCIP,
-            nodesel: *mut ffi::SCIP_NODESEL,
-            node1: *mut ffi::SCIP_NODE,
-            node2: *mut ffi::SCIP_NODE,
-        ) -> c_int {
-            let data_ptr = unsafe { ffi::SCIPnodeselGetData(nodesel) };
-            assert!(!data_ptr.is_null());
-            let nodesel_ptr = data_ptr as *mut Box<dyn NodeSel>;
-            let scip_rc = Rc::new(ScipPtr::from_raw(scip, true));
-            let n1 = Node {
-                raw: node1,
-                scip: scip_rc.clone(),
-            };
-            let n2 = Node {
-                raw: node2,
-                scip: scip_rc,
-            };
-            let ordering = unsafe { (*nodesel_ptr).comp(n1, n2) };
-            ordering as c_int
-        }
-
-        extern "C" fn nodeselfree(
-            _scip: *mut ffi::SCIP,
-            nodesel: *mut ffi::SCIP_NODESEL,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPnodeselGetData(nodesel) };
+        extern "C" fn cutselfree(_scip: *mut SCIP, cutsel: *mut ffi::SCIP_CUTSEL) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPcutselGetData(cutsel) };
             assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn NodeSel>) });
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn CutSelector>) });
             Retcode::Okay.into()
         }
 
-        let nodesel_ptr = Box::into_raw(Box::new(nodesel));
-        let nodesel_faker = nodesel_ptr as *mut ffi::SCIP_NODESELDATA;
+        let ptr = Box::into_raw(Box::new(cutsel));
+        let cutsel_faker = ptr as *mut ffi::SCIP_CUTSELDATA;
 
-        scip_call!(ffi::SCIPincludeNodesel(
+        let mut cutsel: *mut ffi::SCIP_CUTSEL = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludeCutselBasic(
             self.raw,
+            &mut cutsel,
             c_name.as_ptr(),
             c_desc.as_ptr(),
-            std_priority,
-            mem_save_priority,
-            None,
-            Some(nodeselfree),
-            None,
-            None,
-            None,
-            None,
-            Some(nodeselselect),
-            Some(nodeselcomp),
-            nodesel_faker,
+            priority,
+            Some(cutselselect),
+            cutsel_faker,
         ));
+        scip_call!(ffi::SCIPsetCutselFree(self.raw, cutsel, Some(cutselfree)));
 
         Ok(())
     }
 
-    pub(crate) fn include_pricer(
+    pub(crate) fn add_benders_subproblems(
+        &self,
+        subproblems: Vec<Rc<ScipPtr>>,
+    ) -> Result<SCIPBenders, Retcode> {
+        let mut raws: Vec<*mut SCIP> = subproblems.iter().map(|s| s.raw).collect();
+        scip_call!(ffi::SCIPcreateBendersDefault(
+            self.raw,
+            raws.as_mut_ptr(),
+            raws.len() as c_int,
+        ));
+        self.activate_benders_conshdlrs()?;
+        // SCIP does not take ownership of the subproblems, they have to outlive the master
+        self.benders_subproblems.borrow_mut().extend(subproblems);
+
+        let name = CString::new("default").unwrap();
+        let benders = unsafe { ffi::SCIPfindBenders(self.raw, name.as_ptr()) };
+        assert!(!benders.is_null());
+        Ok(SCIPBenders { raw: benders })
+    }
+
+    pub(crate) fn include_benders(
         &self,
         name: &str,
         desc: &str,
         priority: i32,
-        delay: bool,
-        pricer: Box<dyn Pricer>,
-    ) -> Result<(), Retcode> {
+        n_subproblems: usize,
+        cut_lp: bool,
+        cut_pseudo: bool,
+        cut_relax: bool,
+        share_aux_vars: bool,
+        benders: Box<dyn Benders>,
+    ) -> Result<SCIPBenders, Retcode> {
         let c_name = CString::new(name).unwrap();
         let c_desc = CString::new(desc).unwrap();
 
-        pub(crate) fn call_pricer(
-            scip: *mut ffi::SCIP,
-            pricer: *mut ffi::SCIP_PRICER,
-            lowerbound: *mut f64,
-            stopearly: *mut ::std::os::raw::c_uint,
-            result: *mut ffi::SCIP_RESULT,
-            farkas: bool,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
-            assert!(!data_ptr.is_null());
-            let pricer_ptr = data_ptr as *mut Box<dyn Pricer>;
+        extern "C" fn bendersgetvar(
+            _scip: *mut SCIP,
+            _benders: *mut ffi::SCIP_BENDERS,
+            var: *mut SCIP_Var,
+            mappedvar: *mut *mut SCIP_Var,
+            probnumber: c_int,
+        ) -> SCIP_RETCODE {
+            // the subproblems are no SCIP instances, so only master variables exist
+            unsafe {
+                *mappedvar = if probnumber == -1 {
+                    var
+                } else {
+                    std::ptr::null_mut()
+                };
+            }
+            Retcode::Okay.into()
+        }
 
-            let n_vars_before = unsafe { ffi::SCIPgetNVars(scip) };
+        extern "C" fn benderscreatesub(
+            scip: *mut SCIP,
+            benders: *mut ffi::SCIP_BENDERS,
+            probnumber: c_int,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPbendersGetData(benders) };
+            assert!(!data_ptr.is_null());
+            let benders_ptr = data_ptr as *mut Box<dyn Benders>;
 
-            let scip_ptr = ScipPtr::from_raw(scip, true);
-            let model = Model {
-                scip: Rc::new(scip_ptr),
-                state: PhantomData,
+            unsafe {
+                (*benders_ptr).create_subproblem(SCIPBenders { raw: benders }, probnumber as usize)
             };
 
-            let pricer = SCIPPricer { raw: pricer };
-            let pricing_res = unsafe { (*pricer_ptr).generate_columns(model, pricer, farkas) };
-
-            if !farkas {
-                if let Some(lb) = pricing_res.lower_bound {
-                    unsafe { *lowerbound = lb };
-                }
-                if pricing_res.state == PricerResultState::StopEarly {
-                    unsafe { *stopearly = 1 };
-                }
-            }
-
-            if farkas && pricing_res.state == PricerResultState::StopEarly {
-                panic!("Farkas pricing should never stop early as LP would remain infeasible");
+            // the subproblem is solved by the user, so SCIP only gets a placeholder
+            let retcode =
+                unsafe { ffi::SCIPaddBendersSubproblem(scip, benders, std::ptr::null_mut()) };
+            if retcode != ffi::SCIP_Retcode_SCIP_OKAY {
+                return retcode;
             }
-
-            if pricing_res.state == PricerResultState::FoundColumns {
-                let n_vars_after = unsafe { ffi::SCIPgetNVars(scip) };
-                if n_vars_before >= n_vars_after {
-                    return Retcode::Error.into();
-                }
+            unsafe {
+                ffi::SCIPbendersSetSubproblemType(
+                    benders,
+                    probnumber,
+                    ffi::SCIP_BendersSubType_SCIP_BENDERSSUBTYPE_CONVEXCONT,
+                );
+                ffi::SCIPbendersSetSubproblemIsConvex(benders, probnumber, 1);
             }
-
-            unsafe { *result = pricing_res.state.into() };
             Retcode::Okay.into()
         }
 
-        unsafe extern "C" fn pricerredcost(
-            scip: *mut ffi::SCIP,
-            pricer: *mut ffi::SCIP_PRICER,
-            lowerbound: *mut f64,
-            stopearly: *mut ::std::os::raw::c_uint,
-            result: *mut ffi::SCIP_RESULT,
-        ) -> ffi::SCIP_Retcode {
-            call_pricer(scip, pricer, lowerbound, stopearly, result, false)
-        }
+        extern "C" fn benderssolvesubconvex(
+            scip: *mut SCIP,
+            benders: *mut ffi::SCIP_BENDERS,
+            sol: *mut SCIP_SOL,
+            probnumber: c_int,
+            _onlyconvexcheck: std::os::raw::c_uint,
+            objective: *mut f64,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPbendersGetData(benders) };
+            assert!(!data_ptr.is_null());
+            let benders_ptr = data_ptr as *mut Box<dyn Benders>;
 
-        unsafe extern "C" fn pricerfakas(
-            scip: *mut ffi::SCIP,
-            pricer: *mut ffi::SCIP_PRICER,
-            result: *mut ffi::SCIP_RESULT,
-        ) -> ffi::SCIP_Retcode {
-            call_pricer(
-                scip,
-                pricer,
-                std::ptr::null_mut(),
-                std::ptr::null_mut(),
-                result,
-                true,
-            )
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let sol = Solution {
+                raw: sol,
+                scip_ptr: &scip_ptr,
+            };
+
+            let mut obj = unsafe { *objective };
+            let subproblem_result = unsafe {
+                (*benders_ptr).solve_subproblem(
+                    model,
+                    SCIPBenders { raw: benders },
+                    &sol,
+                    probnumber as usize,
+                    &mut obj,
+                )
+            };
+
+            unsafe {
+                *objective = obj;
+                *result = subproblem_result.into();
+            }
+            Retcode::Okay.into()
         }
 
-        unsafe extern "C" fn pricerfree(
-            _scip: *mut ffi::SCIP,
-            pricer: *mut ffi::SCIP_PRICER,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
+        extern "C" fn bendersfree(
+            _scip: *mut SCIP,
+            benders: *mut ffi::SCIP_BENDERS,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPbendersGetData(benders) };
             assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Pricer>) });
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Benders>) });
             Retcode::Okay.into()
         }
 
-        let pricer_ptr = Box::into_raw(Box::new(pricer));
-        let pricer_faker = pricer_ptr as *mut ffi::SCIP_PricerData;
+        let ptr = Box::into_raw(Box::new(benders));
+        let benders_faker = ptr as *mut ffi::SCIP_BENDERSDATA;
 
-        scip_call!(ffi::SCIPincludePricer(
+        let mut benders: *mut ffi::SCIP_BENDERS = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludeBendersBasic(
             self.raw,
+            &mut benders,
             c_name.as_ptr(),
             c_desc.as_ptr(),
             priority,
-            delay.into(),
-            None,
-            Some(pricerfree),
-            None,
-            None,
+            cut_lp.into(),
+            cut_pseudo.into(),
+            cut_relax.into(),
+            share_aux_vars.into(),
+            Some(bendersgetvar),
+            Some(benderscreatesub),
+            benders_faker,
+        ));
+        scip_call!(ffi::SCIPsetBendersFree(
+            self.raw,
+            benders,
+            Some(bendersfree)
+        ));
+        scip_call!(ffi::SCIPsetBendersSolveAndFreesub(
+            self.raw,
+            benders,
+            Some(benderssolvesubconvex),
             None,
             None,
-            Some(pricerredcost),
-            Some(pricerfakas),
-            pricer_faker,
         ));
+        scip_call!(ffi::SCIPactivateBenders(
+            self.raw,
+            benders,
+            n_subproblems as c_int
+        ));
+        self.activate_benders_conshdlrs()?;
 
-        unsafe {
-            ffi::SCIPactivatePricer(self.raw, ffi::SCIPfindPricer(self.raw, c_name.as_ptr()));
-        }
+        Ok(SCIPBenders { raw: benders })
+    }
 
+    /// Activates the constraint handlers that enforce Benders' decompositions.
+    fn activate_benders_conshdlrs(&self) -> Result<(), Retcode> {
+        self.set_bool_param("constraints/benders/active", true)?;
+        self.set_bool_param("constraints/benderslp/active", true)?;
         Ok(())
     }
 
-    pub(crate) fn include_heur(
+    pub(crate) fn include_benderscut(
         &self,
+        benders: &SCIPBenders,
         name: &str,
         desc: &str,
         priority: i32,
-        dispchar: char,
-        freq: i32,
-        freqofs: i32,
-        maxdepth: i32,
-        timing: HeurTiming,
-        usessubscip: bool,
-        heur: Box<dyn Heuristic>,
+        is_lp_cut: bool,
+        cut: Box<dyn BendersCut>,
     ) -> Result<(), Retcode> {
         let c_name = CString::new(name).unwrap();
         let c_desc = CString::new(desc).unwrap();
 
-        extern "C" fn heurexec(
-            scip: *mut ffi::SCIP,
-            heur: *mut ffi::SCIP_HEUR,
-            heurtiming: ffi::SCIP_HEURTIMING,
-            nodeinfeasible: ::std::os::raw::c_uint,
-            result: *mut ffi::SCIP_RESULT,
-        ) -> ffi::SCIP_RETCODE {
-            let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
+        extern "C" fn benderscutexec(
+            scip: *mut SCIP,
+            benders: *mut ffi::SCIP_BENDERS,
+            benderscut: *mut ffi::SCIP_BENDERSCUT,
+            sol: *mut SCIP_SOL,
+            probnumber: c_int,
+            type_: ffi::SCIP_BENDERSENFOTYPE,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPbenderscutGetData(benderscut) };
             assert!(!data_ptr.is_null());
-            let rule_ptr = data_ptr as *mut Box<dyn Heuristic>;
+            let cut_ptr = data_ptr as *mut Box<dyn BendersCut>;
 
-            let current_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
-            let scip_ptr = ScipPtr::from_raw(scip, true);
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
             let model = Model {
-                scip: Rc::new(scip_ptr),
+                scip: scip_ptr.clone(),
                 state: PhantomData,
             };
-            let heur_res =
-                unsafe { (*rule_ptr).execute(model, heurtiming.into(), nodeinfeasible != 0) };
-            if heur_res == HeurResult::FoundSol {
-                let new_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
+            let sol = Solution {
+                raw: sol,
+                scip_ptr: &scip_ptr,
+            };
 
-                if new_n_sols <= current_n_sols {
-                    let heur_name =
-                        unsafe { CStr::from_ptr(ffi::SCIPheurGetName(heur)).to_str().unwrap() };
-                    eprintln!(
-                        "Heuristic {heur_name} returned result {heur_res:?}, but no solutions were added"
-                    );
-                    return Retcode::Error.into();
-                }
-            }
+            let cut_result = unsafe {
+                (*cut_ptr).exec(
+                    model,
+                    SCIPBenders { raw: benders },
+                    &sol,
+                    probnumber as usize,
+                    type_.into(),
+                )
+            };
 
-            unsafe { *result = heur_res.into() };
+            unsafe { *result = cut_result.into() };
             Retcode::Okay.into()
         }
 
-        extern "C" fn heurfree(
-            _scip: *mut ffi::SCIP,
-            heur: *mut ffi::SCIP_HEUR,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
+        extern "C" fn benderscutfree(
+            _scip: *mut SCIP,
+            benderscut: *mut ffi::SCIP_BENDERSCUT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPbenderscutGetData(benderscut) };
             assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Heuristic>) });
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn BendersCut>) });
             Retcode::Okay.into()
         }
 
-        let ptr = Box::into_raw(Box::new(heur));
-        let heur_faker = ptr as *mut ffi::SCIP_HEURDATA;
+        let ptr = Box::into_raw(Box::new(cut));
+        let cut_faker = ptr as *mut ffi::SCIP_BENDERSCUTDATA;
 
-        scip_call!(ffi::SCIPincludeHeur(
+        let mut benderscut: *mut ffi::SCIP_BENDERSCUT = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludeBenderscutBasic(
             self.raw,
+            benders.raw,
+            &mut benderscut,
             c_name.as_ptr(),
             c_desc.as_ptr(),
-            dispchar as ::std::os::raw::c_char,
             priority,
-            freq,
-            freqofs,
-            maxdepth,
-            timing.into(),
-            usessubscip.into(),
-            None,
-            Some(heurfree),
-            None,
-            None,
-            None,
-            None,
-            Some(heurexec),
-            heur_faker,
+            is_lp_cut.into(),
+            Some(benderscutexec),
+            cut_faker,
+        ));
+        scip_call!(ffi::SCIPsetBenderscutFree(
+            self.raw,
+            benderscut,
+            Some(benderscutfree)
         ));
 
         Ok(())
     }
 
-    pub(crate) fn include_separator(
+    pub(crate) fn include_presol(
         &self,
         name: &str,
         desc: &str,
         priority: i32,
-        freq: i32,
-        maxbounddist: f64,
-        usesubscip: bool,
-        delay: bool,
-        separator: Box<dyn Separator>,
+        maxrounds: i32,
+        timing: PresolTiming,
+        presol: Box<dyn Presolver>,
     ) -> Result<(), Retcode> {
         let c_name = CString::new(name).unwrap();
         let c_desc = CString::new(desc).unwrap();
 
-        extern "C" fn sepexeclp(
-            scip: *mut ffi::SCIP,
-            separator: *mut ffi::SCIP_SEPA,
-            result: *mut ffi::SCIP_RESULT,
-            _allowlocal: ::std::os::raw::c_uint,
-            _depth: ::std::os::raw::c_int,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
+        extern "C" fn presolexec(
+            scip: *mut SCIP,
+            presol: *mut ffi::SCIP_PRESOL,
+            _nrounds: c_int,
+            presoltiming: ffi::SCIP_PRESOLTIMING,
+            _nnewfixedvars: c_int,
+            _nnewaggrvars: c_int,
+            _nnewchgvartypes: c_int,
+            _nnewchgbds: c_int,
+            _nnewholes: c_int,
+            _nnewdelconss: c_int,
+            _nnewaddconss: c_int,
+            _nnewupgdconss: c_int,
+            _nnewchgcoefs: c_int,
+            _nnewchgsides: c_int,
+            nfixedvars: *mut c_int,
+            naggrvars: *mut c_int,
+            nchgvartypes: *mut c_int,
+            nchgbds: *mut c_int,
+            naddholes: *mut c_int,
+            ndelconss: *mut c_int,
+            naddconss: *mut c_int,
+            nupgdconss: *mut c_int,
+            nchgcoefs: *mut c_int,
+            nchgsides: *mut c_int,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPpresolGetData(presol) };
             assert!(!data_ptr.is_null());
-            let rule_ptr = data_ptr as *mut Box<dyn Separator>;
+            let presol_ptr = data_ptr as *mut Box<dyn Presolver>;
 
-            let scip_ptr = ScipPtr::from_raw(scip, true);
             let model = Model {
-                scip: Rc::new(scip_ptr),
+                scip: Rc::new(ScipPtr::from_raw(scip, true)),
                 state: PhantomData,
             };
-            let separator = SCIPSeparator { raw: separator };
-            let sep_res = unsafe { (*rule_ptr).execute_lp(model, separator) };
+            let presol = SCIPPresolver { raw: presol };
+            let mut changes = PresolveChanges::default();
+            let presol_res =
+                unsafe { (*presol_ptr).execute(model, presol, presoltiming.into(), &mut changes) };
 
-            unsafe { *result = sep_res.into() };
-
-            Retcode::Okay.into()
-        }
+            unsafe {
+                *nfixedvars += changes.n_fixed_vars;
+                *naggrvars += changes.n_aggr_vars;
+                *nchgvartypes += changes.n_chg_var_types;
+                *nchgbds += changes.n_chg_bds;
+                *naddholes += changes.n_add_holes;
+                *ndelconss += changes.n_del_conss;
+                *naddconss += changes.n_add_conss;
+                *nupgdconss += changes.n_upgd_conss;
+                *nchgcoefs += changes.n_chg_coefs;
+                *nchgsides += changes.n_chg_sides;
+                *result = presol_res.into();
+            }
 
-        extern "C" fn sepexecsol(
-            _scip: *mut ffi::SCIP,
-            _separator: *mut ffi::SCIP_SEPA,
-            _sol: *mut SCIP_SOL,
-            _result: *mut ffi::SCIP_RESULT,
-            _allowlocal: ::std::os::raw::c_uint,
-            _depth: ::std::os::raw::c_int,
-        ) -> ffi::SCIP_Retcode {
             Retcode::Okay.into()
         }
 
-        extern "C" fn sepfree(
-            _scip: *mut ffi::SCIP,
-            separator: *mut ffi::SCIP_SEPA,
-        ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
+        extern "C" fn presolfree(_scip: *mut SCIP, presol: *mut ffi::SCIP_PRESOL) -> SCIP_RETCODE {
+            let data_ptr = unsafe { ffi::SCIPpresolGetData(presol) };
             assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Separator>) });
+            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Presolver>) });
             Retcode::Okay.into()
         }
 
-        let ptr = Box::into_raw(Box::new(separator));
-        let sep_faker = ptr as *mut ffi::SCIP_SEPADATA;
+        let ptr = Box::into_raw(Box::new(presol));
+        let presol_faker = ptr as *mut ffi::SCIP_PRESOLDATA;
 
-        scip_call!(ffi::SCIPincludeSepa(
+        let mut presol: *mut ffi::SCIP_PRESOL = std::ptr::null_mut();
+        scip_call!(ffi::SCIPincludePresolBasic(
             self.raw,
+            &mut presol,
             c_name.as_ptr(),
             c_desc.as_ptr(),
             priority,
-            freq,
-            maxbounddist,
-            usesubscip.into(),
-            delay.into(),
-            None,
-            Some(sepfree),
-            None,
-            None,
-            None,
-            None,
-            Some(sepexeclp),
-            Some(sepexecsol),
-            sep_faker,
+            maxrounds,
+            timing.into(),
+            Some(presolexec),
+            presol_faker,
         ));
+        scip_call!(ffi::SCIPsetPresolFree(self.raw, presol, Some(presolfree)));
+
+        Ok(())
+    }
+
+    pub(crate) fn fix_var(&self, var: &Variable, val: f64) -> Result<Tightening, Retcode> {
+        let var_ptr = self.transformed_var_ptr(var)?;
+        let mut infeasible = 0;
+        let mut fixed = 0;
+        scip_call! { ffi::SCIPfixVar(self.raw, var_ptr, val, &mut infeasible, &mut fixed) };
+        Ok(if infeasible != 0 {
+            Tightening::Infeasible
+        } else if fixed != 0 {
+            Tightening::Tightened
+        } else {
+            Tightening::Redundant
+        })
+    }
+
+    pub(crate) fn aggregate_vars(
+        &self,
+        varx: &Variable,
+        vary: &Variable,
+        scalarx: f64,
+        scalary: f64,
+        rhs: f64,
+    ) -> Result<Aggregation, Retcode> {
+        let varx_ptr = self.transformed_var_ptr(varx)?;
+        let vary_ptr = self.transformed_var_ptr(vary)?;
+        let mut infeasible = 0;
+        let mut redundant = 0;
+        let mut aggregated = 0;
+        scip_call! { ffi::SCIPaggregateVars(
+            self.raw,
+            varx_ptr,
+            vary_ptr,
+            scalarx,
+            scalary,
+            rhs,
+            &mut infeasible,
+            &mut redundant,
+            &mut aggregated,
+        ) };
+        Ok(Aggregation {
+            infeasible: infeasible != 0,
+            redundant: redundant != 0,
+            aggregated: aggregated != 0,
+        })
+    }
+
+    pub(crate) fn tighten_var_bound(
+        &self,
+        var: &Variable,
+        bound: f64,
+        bound_type: BoundType,
+    ) -> Result<Tightening, Retcode> {
+        let var_ptr = self.transformed_var_ptr(var)?;
+        let mut infeasible = 0;
+        let mut tightened = 0;
+        match bound_type {
+            BoundType::Lower => {
+                scip_call! { ffi::SCIPtightenVarLb(self.raw, var_ptr, bound, false.into(), &mut infeasible, &mut tightened) }
+            }
+            BoundType::Upper => {
+                scip_call! { ffi::SCIPtightenVarUb(self.raw, var_ptr, bound, false.into(), &mut infeasible, &mut tightened) }
+            }
+        }
+        Ok(if infeasible != 0 {
+            Tightening::Infeasible
+        } else if tightened != 0 {
+            Tightening::Tightened
+        } else {
+            Tightening::Redundant
+        })
+    }
 
+    pub(crate) fn del_cons(&self, cons: &Constraint) -> Result<(), Retcode> {
+        let stage = unsafe { ffi::SCIPgetStage(self.raw) };
+        let cons_is_transformed = unsafe { ffi::SCIPconsIsTransformed(cons.raw) } == 1;
+        let cons_ptr = if stage != ffi::SCIP_Stage_SCIP_STAGE_PROBLEM && !cons_is_transformed {
+            self.get_transformed_cons(cons)?
+                .ok_or(Retcode::InvalidData)?
+        } else {
+            cons.raw
+        };
+        scip_call! { ffi::SCIPdelCons(self.raw, cons_ptr) };
+        if stage == ffi::SCIP_Stage_SCIP_STAGE_PROBLEM {
+            let mut deleted_conss = self.deleted_conss.borrow_mut();
+            if !deleted_conss.contains(&cons_ptr) {
+                deleted_conss.push(cons_ptr);
+            }
+        }
         Ok(())
     }
 
@@ -1415,60 +3485,300 @@ impl ScipPtr {
         desc: &str,
         enfopriority: i32,
         checkpriority: i32,
+        sepapriority: i32,
+        sepafreq: i32,
+        propfreq: i32,
+        eagerfreq: i32,
+        maxprerounds: i32,
+        delaysepa: bool,
+        delayprop: bool,
+        prop_timing: PropTiming,
+        presol_timing: PresolTiming,
+        needscons: bool,
         conshdlr: Box<dyn Conshdlr>,
-    ) -> Result<(), Retcode> {
+    ) -> Result<*mut SCIP_CONSHDLR, Retcode> {
         let c_name = CString::new(name).unwrap();
         let c_desc = CString::new(desc).unwrap();
 
-        extern "C" fn consenfolp(
+        // the settings are kept next to the handler, so that it can be included again in copies
+        struct ConshdlrData {
+            conshdlr: Box<dyn Conshdlr>,
+            name: String,
+            desc: String,
+            enfopriority: i32,
+            checkpriority: i32,
+            sepapriority: i32,
+            sepafreq: i32,
+            propfreq: i32,
+            eagerfreq: i32,
+            maxprerounds: i32,
+            delaysepa: bool,
+            delayprop: bool,
+            prop_timing: PropTiming,
+            presol_timing: PresolTiming,
+            needscons: bool,
+        }
+
+        fn conshdlr_data<'a>(conshdlr: *mut SCIP_CONSHDLR) -> &'a mut ConshdlrData {
+            let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
+            assert!(!data_ptr.is_null());
+            unsafe { &mut *(data_ptr as *mut ConshdlrData) }
+        }
+
+        fn wrap_conss(
+            scip_ptr: &Rc<ScipPtr>,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+        ) -> Vec<Constraint> {
+            if conss.is_null() || nconss <= 0 {
+                return vec![];
+            }
+            unsafe { std::slice::from_raw_parts(conss, nconss as usize) }
+                .iter()
+                .map(|&raw| Constraint {
+                    raw,
+                    scip: scip_ptr.clone(),
+                })
+                .collect()
+        }
+
+        extern "C" fn consenfolp(
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            _nusefulconss: c_int,
+            _solinfeasible: std::os::raw::c_uint,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data = conshdlr_data(conshdlr);
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let conss = wrap_conss(&scip_ptr, conss, nconss);
+
+            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+
+            unsafe {
+                *result = data.conshdlr.enforce(model, scip_conshdlr, &conss).into();
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn consenfops(
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            _nusefulconss: c_int,
+            _solinfeasible: std::os::raw::c_uint,
+            _objinfeasible: std::os::raw::c_uint,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data = conshdlr_data(conshdlr);
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let conss = wrap_conss(&scip_ptr, conss, nconss);
+
+            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+
+            unsafe {
+                *result = data
+                    .conshdlr
+                    .enforce_pseudo(model, scip_conshdlr, &conss)
+                    .into();
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn conscheck(
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            sol: *mut SCIP_SOL,
+            _checkintegrality: ::std::os::raw::c_uint,
+            _checklprows: ::std::os::raw::c_uint,
+            _printreason: ::std::os::raw::c_uint,
+            _completely: ::std::os::raw::c_uint,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data = conshdlr_data(conshdlr);
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let conss = wrap_conss(&scip_ptr, conss, nconss);
+
+            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+
+            assert!(!sol.is_null());
+
+            let solution = Solution {
+                raw: sol,
+                scip_ptr: &scip_ptr,
+            };
+
+            let feasible = data.conshdlr.check(model, scip_conshdlr, &conss, &solution);
+
+            unsafe {
+                *result = if feasible {
+                    ffi::SCIP_Result_SCIP_FEASIBLE
+                } else {
+                    ffi::SCIP_Result_SCIP_INFEASIBLE
+                };
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn consprop(
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            _nusefulconss: c_int,
+            _nmarkedconss: c_int,
+            _proptiming: ffi::SCIP_PROPTIMING,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data = conshdlr_data(conshdlr);
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let conss = wrap_conss(&scip_ptr, conss, nconss);
+
+            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+
+            unsafe {
+                *result = data.conshdlr.propagate(model, scip_conshdlr, &conss).into();
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn conspresol(
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            _nrounds: c_int,
+            _presoltiming: ffi::SCIP_PRESOLTIMING,
+            _nnewfixedvars: c_int,
+            _nnewaggrvars: c_int,
+            _nnewchgvartypes: c_int,
+            _nnewchgbds: c_int,
+            _nnewholes: c_int,
+            _nnewdelconss: c_int,
+            _nnewaddconss: c_int,
+            _nnewupgdconss: c_int,
+            _nnewchgcoefs: c_int,
+            _nnewchgsides: c_int,
+            nfixedvars: *mut c_int,
+            naggrvars: *mut c_int,
+            nchgvartypes: *mut c_int,
+            nchgbds: *mut c_int,
+            naddholes: *mut c_int,
+            ndelconss: *mut c_int,
+            naddconss: *mut c_int,
+            nupgdconss: *mut c_int,
+            nchgcoefs: *mut c_int,
+            nchgsides: *mut c_int,
+            result: *mut SCIP_RESULT,
+        ) -> SCIP_RETCODE {
+            let data = conshdlr_data(conshdlr);
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let conss = wrap_conss(&scip_ptr, conss, nconss);
+
+            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+
+            let mut changes = PresolveChanges::default();
+            let presol_result = data
+                .conshdlr
+                .presolve(model, scip_conshdlr, &conss, &mut changes);
+
+            unsafe {
+                *nfixedvars += changes.n_fixed_vars;
+                *naggrvars += changes.n_aggr_vars;
+                *nchgvartypes += changes.n_chg_var_types;
+                *nchgbds += changes.n_chg_bds;
+                *naddholes += changes.n_add_holes;
+                *ndelconss += changes.n_del_conss;
+                *naddconss += changes.n_add_conss;
+                *nupgdconss += changes.n_upgd_conss;
+                *nchgcoefs += changes.n_chg_coefs;
+                *nchgsides += changes.n_chg_sides;
+                *result = presol_result.into();
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn conssepalp(
             scip: *mut SCIP,
             conshdlr: *mut SCIP_CONSHDLR,
-            _conss: *mut *mut SCIP_CONS,
-            _nconss: std::os::raw::c_int,
-            _nusefulconss: std::os::raw::c_int,
-            _solinfeasible: std::os::raw::c_uint,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            _nusefulconss: c_int,
             result: *mut SCIP_RESULT,
         ) -> SCIP_RETCODE {
-            let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
-            assert!(!data_ptr.is_null());
-            let conshdlr_ptr = data_ptr as *mut Box<dyn Conshdlr>;
+            let data = conshdlr_data(conshdlr);
 
             let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
             let model = Model {
                 scip: scip_ptr.clone(),
                 state: PhantomData,
             };
+            let conss = wrap_conss(&scip_ptr, conss, nconss);
 
             let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
 
             unsafe {
-                *result = (*conshdlr_ptr).enforce(model, scip_conshdlr).into();
+                *result = data
+                    .conshdlr
+                    .separate_lp(model, scip_conshdlr, &conss)
+                    .into();
             }
 
             Retcode::Okay.into()
         }
 
-        extern "C" fn conscheck(
+        extern "C" fn conssepasol(
             scip: *mut SCIP,
             conshdlr: *mut SCIP_CONSHDLR,
-            _conss: *mut *mut SCIP_CONS,
-            _nconss: ::std::os::raw::c_int,
+            conss: *mut *mut SCIP_CONS,
+            nconss: c_int,
+            _nusefulconss: c_int,
             sol: *mut SCIP_SOL,
-            _checkintegrality: ::std::os::raw::c_uint,
-            _checklprows: ::std::os::raw::c_uint,
-            _printreason: ::std::os::raw::c_uint,
-            _completely: ::std::os::raw::c_uint,
             result: *mut SCIP_RESULT,
         ) -> SCIP_RETCODE {
-            let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
-            assert!(!data_ptr.is_null());
-            let conshdlr_ptr = data_ptr as *mut Box<dyn Conshdlr>;
+            let data = conshdlr_data(conshdlr);
 
             let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
             let model = Model {
                 scip: scip_ptr.clone(),
                 state: PhantomData,
             };
+            let conss = wrap_conss(&scip_ptr, conss, nconss);
 
             let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
 
@@ -1479,68 +3789,137 @@ impl ScipPtr {
                 scip_ptr: &scip_ptr,
             };
 
-            let feasible = unsafe { (*conshdlr_ptr).check(model, scip_conshdlr, &solution) };
-
             unsafe {
-                *result = if feasible {
-                    ffi::SCIP_Result_SCIP_FEASIBLE
-                } else {
-                    ffi::SCIP_Result_SCIP_INFEASIBLE
-                };
+                *result = data
+                    .conshdlr
+                    .separate_sol(model, scip_conshdlr, &conss, &solution)
+                    .into();
             }
 
             Retcode::Okay.into()
         }
 
         extern "C" fn conslock(
-            _scip: *mut SCIP,
-            _conshdlr: *mut SCIP_CONSHDLR,
-            _cons: *mut SCIP_CONS,
-            _locktype: SCIP_LOCKTYPE,
-            _nlockspos: ::std::os::raw::c_int,
-            _nlocksneg: ::std::os::raw::c_int,
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            cons: *mut SCIP_CONS,
+            locktype: SCIP_LOCKTYPE,
+            nlockspos: c_int,
+            nlocksneg: c_int,
+        ) -> SCIP_RETCODE {
+            let data = conshdlr_data(conshdlr);
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            if cons.is_null() {
+                return Retcode::Okay.into();
+            }
+            let cons = Constraint {
+                raw: cons,
+                scip: scip_ptr.clone(),
+            };
+            let cons_is_transformed = unsafe { ffi::SCIPconsIsTransformed(cons.raw) } == 1;
+
+            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+
+            let locks = data.conshdlr.lock(model, scip_conshdlr, &cons);
+            for (var, direction) in locks {
+                // locks of transformed constraints have to be put on the transformed variables
+                let var_ptr =
+                    if cons_is_transformed && unsafe { ffi::SCIPvarIsTransformed(var.raw) } == 0 {
+                        unsafe { ffi::SCIPvarGetTransVar(var.raw) }
+                    } else {
+                        var.raw
+                    };
+                if var_ptr.is_null() {
+                    continue;
+                }
+
+                let (nlocksdown, nlocksup) = match direction {
+                    LockDirection::Down => (nlockspos, nlocksneg),
+                    LockDirection::Up => (nlocksneg, nlockspos),
+                    LockDirection::Both => (nlockspos + nlocksneg, nlockspos + nlocksneg),
+                };
+                let retcode = unsafe {
+                    ffi::SCIPaddVarLocksType(scip, var_ptr, locktype, nlocksdown, nlocksup)
+                };
+                if retcode != ffi::SCIP_Retcode_SCIP_OKAY {
+                    return retcode;
+                }
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn consdelete(
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            cons: *mut SCIP_CONS,
+            consdata: *mut *mut ffi::SCIP_CONSDATA,
+        ) -> SCIP_RETCODE {
+            let data = conshdlr_data(conshdlr);
+
+            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+            let model = Model {
+                scip: scip_ptr.clone(),
+                state: PhantomData,
+            };
+            let cons = Constraint {
+                raw: cons,
+                scip: scip_ptr.clone(),
+            };
+
+            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+
+            data.conshdlr.delete(model, scip_conshdlr, &cons);
+
+            unsafe {
+                if !(*consdata).is_null() {
+                    drop(Box::from_raw(*consdata as *mut Box<dyn Any>));
+                    *consdata = std::ptr::null_mut();
+                }
+            }
+
+            Retcode::Okay.into()
+        }
+
+        extern "C" fn conshdlrcopy(
+            scip: *mut SCIP,
+            conshdlr: *mut SCIP_CONSHDLR,
+            valid: *mut std::os::raw::c_uint,
         ) -> SCIP_RETCODE {
-            // // loops over all the variables and runs the lock method on them
-            // let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
-            // assert!(!data_ptr.is_null());
-            // let conshdlr_ptr = data_ptr as *mut Box<dyn Conshdlr>;
-            //
-            // let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
-            // let model = Model {
-            //     scip: scip_ptr.clone(),
-            //     state: Solving,
-            // };
-            //
-            // let vars = model.vars();
-            // for var in vars {
-            //     let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
-            //
-            //     let model = Model {
-            //         scip: scip_ptr.clone(),
-            //         state: Solving,
-            //     };
-            //
-            //     let lock_type = unsafe { (*conshdlr_ptr).lock(model, scip_conshdlr, &var) };
-            //
-            //     unsafe {
-            //         match lock_type {
-            //             LockDirection::Both => {
-            //                 ffi::SCIPaddVarLocks(
-            //                     scip,
-            //                     var.raw,
-            //                     nlockspos + nlocksneg,
-            //                     nlockspos + nlocksneg,
-            //                 );
-            //             }
-            //             LockDirection::Decrease => {
-            //                 ffi::SCIPaddVarLocks(scip, var.raw, nlockspos, nlocksneg);
-            //             }
-            //             LockDirection::Increase => {
-            //                 ffi::SCIPaddVarLocks(scip, var.raw, nlocksneg, nlockspos);
-            //             }
-            //         }
-            //     }
-            // }
+            let data = conshdlr_data(conshdlr);
+
+            let Some(copy) = data.conshdlr.copy() else {
+                unsafe { *valid = 0 };
+                return Retcode::Okay.into();
+            };
+
+            let target = ScipPtr::from_raw(scip, true);
+            if let Err(retcode) = target.include_conshdlr(
+                &data.name,
+                &data.desc,
+                data.enfopriority,
+                data.checkpriority,
+                data.sepapriority,
+                data.sepafreq,
+                data.propfreq,
+                data.eagerfreq,
+                data.maxprerounds,
+                data.delaysepa,
+                data.delayprop,
+                data.prop_timing,
+                data.presol_timing,
+                data.needscons,
+                copy,
+            ) {
+                return retcode.into();
+            }
+
+            unsafe { *valid = 1 };
             Retcode::Okay.into()
         }
 
@@ -1550,11 +3929,28 @@ impl ScipPtr {
         ) -> ffi::SCIP_Retcode {
             let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
             assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Conshdlr>) });
+            drop(unsafe { Box::from_raw(data_ptr as *mut ConshdlrData) });
             Retcode::Okay.into()
         }
 
-        let ptr = Box::into_raw(Box::new(conshdlr));
+        let data = ConshdlrData {
+            conshdlr,
+            name: name.to_string(),
+            desc: desc.to_string(),
+            enfopriority,
+            checkpriority,
+            sepapriority,
+            sepafreq,
+            propfreq,
+            eagerfreq,
+            maxprerounds,
+            delaysepa,
+            delayprop,
+            prop_timing,
+            presol_timing,
+            needscons,
+        };
+        let ptr = Box::into_raw(Box::new(data));
         let cons_faker = ptr as *mut ffi::SCIP_CONSHDLRDATA;
 
         let mut conshdlr: *mut SCIP_CONSHDLR = std::ptr::null_mut();
@@ -1565,18 +3961,90 @@ impl ScipPtr {
             c_desc.as_ptr(),
             enfopriority,
             checkpriority,
-            0,
-            false.into(),
+            eagerfreq,
+            needscons.into(),
             Some(consenfolp),
-            None,
+            Some(consenfops),
             Some(conscheck),
             Some(conslock),
             cons_faker,
         ));
 
+        scip_call!(ffi::SCIPsetConshdlrSepa(
+            self.raw,
+            conshdlr,
+            Some(conssepalp),
+            Some(conssepasol),
+            sepafreq,
+            sepapriority,
+            delaysepa.into(),
+        ));
+        scip_call!(ffi::SCIPsetConshdlrProp(
+            self.raw,
+            conshdlr,
+            Some(consprop),
+            propfreq,
+            delayprop.into(),
+            prop_timing.into(),
+        ));
+        scip_call!(ffi::SCIPsetConshdlrPresol(
+            self.raw,
+            conshdlr,
+            Some(conspresol),
+            maxprerounds,
+            presol_timing.into(),
+        ));
+        scip_call!(ffi::SCIPsetConshdlrDelete(
+            self.raw,
+            conshdlr,
+            Some(consdelete)
+        ));
+        scip_call!(ffi::SCIPsetConshdlrCopy(
+            self.raw,
+            conshdlr,
+            Some(conshdlrcopy),
+            None
+        ));
         scip_call!(ffi::SCIPsetConshdlrFree(self.raw, conshdlr, Some(consfree)));
 
-        Ok(())
+        Ok(conshdlr)
+    }
+
+    /// Creates a constraint of a custom constraint handler, carrying the given data.
+    pub(crate) fn create_cons_with_data(
+        &self,
+        conshdlr: *mut SCIP_CONSHDLR,
+        name: &str,
+        data: Box<dyn Any>,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        let c_name = CString::new(name).unwrap();
+        let consdata = Box::into_raw(Box::new(data)) as *mut ffi::SCIP_CONSDATA;
+        let mut scip_cons = MaybeUninit::uninit();
+        scip_call! { ffi::SCIPcreateCons(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            conshdlr,
+            consdata,
+            true.into(),
+            true.into(),
+            true.into(),
+            true.into(),
+            true.into(),
+            false.into(),
+            false.into(),
+            false.into(),
+            false.into(),
+            false.into(),
+        ) };
+        let mut scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+
+        let stage = unsafe { ffi::SCIPgetStage(self.raw) };
+        if stage == ffi::SCIP_Stage_SCIP_STAGE_SOLVING {
+            scip_call! { ffi::SCIPreleaseCons(self.raw, &mut scip_cons) };
+        }
+        Ok(scip_cons)
     }
 
     pub(crate) fn add_cons_coef(
@@ -1661,6 +4129,10 @@ impl ScipPtr {
         unsafe { ffi::SCIPconsIsSeparated(cons.raw) == ffi::TRUE }
     }
 
+    pub(crate) fn gap(&self) -> f64 {
+        unsafe { ffi::SCIPgetGap(self.raw) }
+    }
+
     pub(crate) fn n_nodes(&self) -> usize {
         unsafe { ffi::SCIPgetNNodes(self.raw) as usize }
     }
@@ -1866,6 +4338,235 @@ impl ScipPtr {
         Ok(unsafe { row_ptr.assume_init() })
     }
 
+    pub(crate) fn enable_reoptimization(&self, enable: bool) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPenableReoptimization(self.raw, enable.into()));
+        Ok(())
+    }
+
+    pub(crate) fn is_reopt_enabled(&self) -> bool {
+        unsafe { ffi::SCIPisReoptEnabled(self.raw) != 0 }
+    }
+
+    pub(crate) fn free_reopt_solve(&self) -> Result<(), Retcode> {
+        scip_call!(ffi::SCIPfreeReoptSolve(self.raw));
+        Ok(())
+    }
+
+    /// Adds the bound constraint `lb <= var <= ub` to the presolved problem between two
+    /// reoptimization runs, where SCIP rejects changing the bounds of variables directly.
+    pub(crate) fn add_reopt_bound_cons(
+        &self,
+        var: &Variable,
+        lb: f64,
+        ub: f64,
+        name: &str,
+    ) -> Result<(), Retcode> {
+        let mut cons = self.create_cons(None, vec![var], &[1.0], lb, ub, name, false)?;
+        // the constraint belongs to the transformed problem, which is freed by SCIP
+        scip_call!(ffi::SCIPreleaseCons(self.raw, &mut cons));
+        Ok(())
+    }
+
+    pub(crate) fn chg_reopt_objective(
+        &self,
+        sense: ObjSense,
+        vars: &[&Variable],
+        coefs: &[f64],
+    ) -> Result<(), Retcode> {
+        assert_eq!(vars.len(), coefs.len());
+        let mut var_ptrs = vars.iter().map(|var| var.raw).collect::<Vec<_>>();
+        let mut coefs = coefs.to_vec();
+        scip_call!(ffi::SCIPchgReoptObjective(
+            self.raw,
+            sense.into(),
+            var_ptrs.as_mut_ptr(),
+            coefs.as_mut_ptr(),
+            var_ptrs.len() as c_int
+        ));
+        Ok(())
+    }
+
+    /// Returns an `InvalidCall` error if SCIP is not in one of the given stages.
+    fn check_stage(&self, stages: &[ffi::SCIP_Stage]) -> Result<(), Retcode> {
+        let stage = unsafe { ffi::SCIPgetStage(self.raw) };
+        if stages.contains(&stage) {
+            Ok(())
+        } else {
+            Err(Retcode::InvalidCall)
+        }
+    }
+
+    /// Returns the linear constraint to modify in the current stage, or an `InvalidData` error
+    /// if the constraint is not linear.
+    fn linear_cons_in_stage(&self, cons: &Constraint) -> Result<*mut SCIP_Cons, Retcode> {
+        let conshdlr = unsafe { ffi::SCIPconsGetHdlr(cons.raw) };
+        let conshdlr_name = unsafe { CStr::from_ptr(ffi::SCIPconshdlrGetName(conshdlr)) };
+        if conshdlr_name != c"linear" {
+            return Err(Retcode::InvalidData);
+        }
+        let stage = unsafe { ffi::SCIPgetStage(self.raw) };
+        let cons_is_original = unsafe { ffi::SCIPconsIsOriginal(cons.raw) } != 0;
+        if stage == ffi::SCIP_Stage_SCIP_STAGE_PROBLEM || !cons_is_original {
+            Ok(cons.raw)
+        } else {
+            self.get_transformed_cons(cons)?.ok_or(Retcode::InvalidData)
+        }
+    }
+
+    pub(crate) fn chg_var_lb(&self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVING,
+        ])?;
+        let var = self.var_in_stage(var)?;
+        scip_call!(ffi::SCIPchgVarLb(self.raw, var, lb));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_ub(&self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVING,
+        ])?;
+        let var = self.var_in_stage(var)?;
+        scip_call!(ffi::SCIPchgVarUb(self.raw, var, ub));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_lb_global(&self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMED,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVING,
+        ])?;
+        let var = self.var_in_stage(var)?;
+        scip_call!(ffi::SCIPchgVarLbGlobal(self.raw, var, lb));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_ub_global(&self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMED,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVING,
+        ])?;
+        let var = self.var_in_stage(var)?;
+        scip_call!(ffi::SCIPchgVarUbGlobal(self.raw, var, ub));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_lb_local(&self, var: &Variable, lb: f64) -> Result<(), Retcode> {
+        self.check_stage(&[ffi::SCIP_Stage_SCIP_STAGE_SOLVING])?;
+        let var = self.var_in_stage(var)?;
+        scip_call!(ffi::SCIPchgVarLbNode(
+            self.raw,
+            std::ptr::null_mut(),
+            var,
+            lb
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_ub_local(&self, var: &Variable, ub: f64) -> Result<(), Retcode> {
+        self.check_stage(&[ffi::SCIP_Stage_SCIP_STAGE_SOLVING])?;
+        let var = self.var_in_stage(var)?;
+        scip_call!(ffi::SCIPchgVarUbNode(
+            self.raw,
+            std::ptr::null_mut(),
+            var,
+            ub
+        ));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_obj(&self, var: &Variable, obj: f64) -> Result<(), Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+        ])?;
+        let var = self.var_in_stage(var)?;
+        scip_call!(ffi::SCIPchgVarObj(self.raw, var, obj));
+        Ok(())
+    }
+
+    pub(crate) fn chg_var_type(&self, var: &Variable, var_type: VarType) -> Result<bool, Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+        ])?;
+        let var = self.var_in_stage(var)?;
+        let mut infeasible = 0;
+        scip_call!(ffi::SCIPchgVarType(
+            self.raw,
+            var,
+            var_type.into(),
+            &mut infeasible
+        ));
+        Ok(infeasible != 0)
+    }
+
+    pub(crate) fn del_var(&self, var: &Variable) -> Result<bool, Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMED,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+        ])?;
+        let var = self.var_in_stage(var)?;
+        let mut deleted = 0;
+        scip_call!(ffi::SCIPdelVar(self.raw, var, &mut deleted));
+        if deleted != 0 && unsafe { ffi::SCIPvarIsOriginal(var) } != 0 {
+            let mut deleted_vars = self.deleted_vars.borrow_mut();
+            if !deleted_vars.contains(&var) {
+                deleted_vars.push(var);
+            }
+        }
+        Ok(deleted != 0)
+    }
+
+    pub(crate) fn chg_lhs(&self, cons: &Constraint, lhs: f64) -> Result<(), Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+        ])?;
+        let cons = self.linear_cons_in_stage(cons)?;
+        scip_call!(ffi::SCIPchgLhsLinear(self.raw, cons, lhs));
+        Ok(())
+    }
+
+    pub(crate) fn chg_rhs(&self, cons: &Constraint, rhs: f64) -> Result<(), Retcode> {
+        self.check_stage(&[
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING,
+        ])?;
+        let cons = self.linear_cons_in_stage(cons)?;
+        scip_call!(ffi::SCIPchgRhsLinear(self.raw, cons, rhs));
+        Ok(())
+    }
+
+    pub(crate) fn chg_coef(
+        &self,
+        cons: &Constraint,
+        var: &Variable,
+        coef: f64,
+    ) -> Result<(), Retcode> {
+        // SCIP only supports changing coefficients of original constraints
+        self.check_stage(&[ffi::SCIP_Stage_SCIP_STAGE_PROBLEM])?;
+        let cons = self.linear_cons_in_stage(cons)?;
+        scip_call!(ffi::SCIPchgCoefLinear(self.raw, cons, var.raw, coef));
+        Ok(())
+    }
+
     pub(crate) fn free_transform(&self) -> Result<(), Retcode> {
         scip_call!(ffi::SCIPfreeTransform(self.raw));
         Ok(())
@@ -2037,6 +4738,48 @@ impl ScipPtr {
 
         Ok(scip_cons)
     }
+
+    pub(crate) fn create_cons_sos2(
+        &self,
+        vars: Vec<&Variable>,
+        weights: Option<&[f64]>,
+        name: &str,
+    ) -> Result<*mut SCIP_Cons, Retcode> {
+        if vars.is_empty() {
+            return Err(Retcode::ParameterWrongVal);
+        }
+
+        if let Some(ws) = weights
+            && vars.len() != ws.len()
+        {
+            return Err(Retcode::ParameterWrongVal);
+        }
+
+        let c_name = CString::new(name).unwrap();
+        let mut scip_cons = MaybeUninit::uninit();
+
+        let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
+
+        // without weights, SCIP keeps the given order of the variables
+        let mut weights_vec = weights.map(|weights| weights.to_vec());
+        let weights_ptr = weights_vec
+            .as_mut()
+            .map_or(std::ptr::null_mut(), |weights| weights.as_mut_ptr());
+
+        scip_call! { ffi::SCIPcreateConsBasicSOS2(
+            self.raw,
+            scip_cons.as_mut_ptr(),
+            c_name.as_ptr(),
+            var_ptrs.len() as c_int,
+            var_ptrs.as_mut_ptr(),
+            weights_ptr,
+        ) };
+
+        let scip_cons = unsafe { scip_cons.assume_init() };
+        scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+
+        Ok(scip_cons)
+    }
 }
 
 impl Drop for ScipPtr {
@@ -2081,6 +4824,14 @@ impl Drop for ScipPtr {
                 let mut cons = unsafe { *conss.add(i as usize) };
                 scip_call_panic!(ffi::SCIPreleaseCons(self.raw, &mut cons));
             }
+
+            // release variables and constraints that were deleted from the original problem
+            for var_ptr in self.deleted_vars.get_mut().iter_mut() {
+                scip_call_panic!(ffi::SCIPreleaseVar(self.raw, var_ptr));
+            }
+            for cons_ptr in self.deleted_conss.get_mut().iter_mut() {
+                scip_call_panic!(ffi::SCIPreleaseCons(self.raw, cons_ptr));
+            }
         }
 
         // free SCIP instance
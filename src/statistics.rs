@@ -0,0 +1,579 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use crate::ffi;
+use crate::scip::ScipPtr;
+
+/// The solving statistics of a model, see
+/// [`WithSolvingStats::statistics`](crate::WithSolvingStats::statistics).
+///
+/// The statistics of the plugins are always available. The other sections are only filled
+/// once the solving process started, before that they are zero.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Statistics {
+    /// The statistics of the presolvers.
+    pub presolvers: Vec<PresolverStats>,
+    /// The statistics of the constraint handlers.
+    pub constraint_handlers: Vec<ConshdlrStats>,
+    /// The statistics of the propagators.
+    pub propagators: Vec<PropagatorStats>,
+    /// The statistics of the separators.
+    pub separators: Vec<SeparatorStats>,
+    /// The statistics of the cuts and cut selectors.
+    pub cuts: CutStats,
+    /// The statistics of the pricers.
+    pub pricers: Vec<PricerStats>,
+    /// The statistics of the branching rules.
+    pub branching_rules: Vec<BranchRuleStats>,
+    /// The statistics of the primal heuristics.
+    pub heuristics: Vec<HeuristicStats>,
+    /// The statistics of the LP solves.
+    pub lp: LpStats,
+    /// The statistics of the branch-and-bound tree.
+    pub tree: TreeStats,
+    /// The statistics of the root node.
+    pub root_node: RootNodeStats,
+    /// The statistics of the solutions and bounds.
+    pub solution: SolutionStats,
+}
+
+/// The statistics of a presolver.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PresolverStats {
+    /// The name of the presolver.
+    pub name: String,
+    /// The time spent in the presolver in seconds.
+    pub time: f64,
+    /// The number of times the presolver was called.
+    pub n_calls: usize,
+    /// The number of variables fixed by the presolver.
+    pub n_fixed_vars: usize,
+    /// The number of variables aggregated by the presolver.
+    pub n_aggr_vars: usize,
+    /// The number of variable types changed by the presolver.
+    pub n_chg_var_types: usize,
+    /// The number of bounds changed by the presolver.
+    pub n_chg_bds: usize,
+    /// The number of holes added to variable domains by the presolver.
+    pub n_add_holes: usize,
+    /// The number of constraints deleted by the presolver.
+    pub n_del_conss: usize,
+    /// The number of constraints added by the presolver.
+    pub n_add_conss: usize,
+    /// The number of constraints upgraded by the presolver.
+    pub n_upgd_conss: usize,
+    /// The number of coefficients changed by the presolver.
+    pub n_chg_coefs: usize,
+    /// The number of constraint sides changed by the presolver.
+    pub n_chg_sides: usize,
+}
+
+/// The statistics of a constraint handler.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConshdlrStats {
+    /// The name of the constraint handler.
+    pub name: String,
+    /// The number of constraints of the constraint handler.
+    pub n_conss: usize,
+    /// The maximal number of active constraints of the constraint handler.
+    pub max_n_active_conss: usize,
+    /// The time spent presolving in seconds.
+    pub presol_time: f64,
+    /// The time spent separating in seconds.
+    pub sepa_time: f64,
+    /// The time spent propagating in seconds.
+    pub prop_time: f64,
+    /// The time spent enforcing LP solutions in seconds.
+    pub enfo_lp_time: f64,
+    /// The time spent enforcing pseudo solutions in seconds.
+    pub enfo_ps_time: f64,
+    /// The time spent checking solutions in seconds.
+    pub check_time: f64,
+    /// The number of separation calls.
+    pub n_sepa_calls: usize,
+    /// The number of propagation calls.
+    pub n_prop_calls: usize,
+    /// The number of calls enforcing LP solutions.
+    pub n_enfo_lp_calls: usize,
+    /// The number of calls enforcing pseudo solutions.
+    pub n_enfo_ps_calls: usize,
+    /// The number of calls checking solutions.
+    pub n_check_calls: usize,
+    /// The number of nodes cut off by the constraint handler.
+    pub n_cutoffs: usize,
+    /// The number of cuts found by the constraint handler.
+    pub n_cuts_found: usize,
+    /// The number of cuts of the constraint handler applied to the LP.
+    pub n_cuts_applied: usize,
+    /// The number of constraints added by the constraint handler.
+    pub n_conss_found: usize,
+    /// The number of domain reductions found by the constraint handler.
+    pub n_domreds_found: usize,
+    /// The number of children created by the constraint handler.
+    pub n_children: usize,
+}
+
+/// The statistics of a propagator.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PropagatorStats {
+    /// The name of the propagator.
+    pub name: String,
+    /// The time spent propagating in seconds.
+    pub time: f64,
+    /// The time spent presolving in seconds.
+    pub presol_time: f64,
+    /// The number of times the propagator was called.
+    pub n_calls: usize,
+    /// The number of nodes cut off by the propagator.
+    pub n_cutoffs: usize,
+    /// The number of domain reductions found by the propagator.
+    pub n_domreds_found: usize,
+}
+
+/// The statistics of a separator.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SeparatorStats {
+    /// The name of the separator.
+    pub name: String,
+    /// The time spent in the separator in seconds.
+    pub time: f64,
+    /// The number of times the separator was called.
+    pub n_calls: usize,
+    /// The number of times the separator was called at the root node.
+    pub n_root_calls: usize,
+    /// The number of nodes cut off by the separator.
+    pub n_cutoffs: usize,
+    /// The number of cuts found by the separator.
+    pub n_cuts_found: usize,
+    /// The number of cuts of the separator applied to the LP.
+    pub n_cuts_applied: usize,
+    /// The number of constraints added by the separator.
+    pub n_conss_found: usize,
+    /// The number of domain reductions found by the separator.
+    pub n_domreds_found: usize,
+}
+
+/// The statistics of the cuts.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CutStats {
+    /// The number of cuts found.
+    pub n_found: usize,
+    /// The number of cuts applied to the LP.
+    pub n_applied: usize,
+    /// The number of cuts in the global cut pool.
+    pub n_pool_cuts: usize,
+    /// The number of separation rounds.
+    pub n_sepa_rounds: usize,
+    /// The statistics of the cut selectors.
+    pub selectors: Vec<CutSelectorStats>,
+}
+
+/// The statistics of a cut selector.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CutSelectorStats {
+    /// The name of the cut selector.
+    pub name: String,
+    /// The time spent in the cut selector in seconds.
+    pub time: f64,
+    /// The number of times the cut selector was called.
+    pub n_calls: usize,
+    /// The number of times the cut selector was called at the root node.
+    pub n_root_calls: usize,
+    /// The number of cuts the cut selector chose from at the root node.
+    pub n_root_cuts: usize,
+    /// The number of cuts the cut selector chose from at other nodes.
+    pub n_local_cuts: usize,
+}
+
+/// The statistics of a pricer.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PricerStats {
+    /// The name of the pricer.
+    pub name: String,
+    /// The time spent in the pricer in seconds.
+    pub time: f64,
+    /// The number of times the pricer was called.
+    pub n_calls: usize,
+    /// The number of variables found by the pricer.
+    pub n_vars_found: usize,
+}
+
+/// The statistics of a branching rule.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BranchRuleStats {
+    /// The name of the branching rule.
+    pub name: String,
+    /// The time spent in the branching rule in seconds.
+    pub time: f64,
+    /// The number of calls on LP solutions.
+    pub n_lp_calls: usize,
+    /// The number of calls on external candidates.
+    pub n_extern_calls: usize,
+    /// The number of calls on pseudo solutions.
+    pub n_pseudo_calls: usize,
+    /// The number of nodes cut off by the branching rule.
+    pub n_cutoffs: usize,
+    /// The number of cuts found by the branching rule.
+    pub n_cuts_found: usize,
+    /// The number of constraints added by the branching rule.
+    pub n_conss_found: usize,
+    /// The number of domain reductions found by the branching rule.
+    pub n_domreds_found: usize,
+    /// The number of children created by the branching rule.
+    pub n_children: usize,
+}
+
+/// The statistics of a primal heuristic.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HeuristicStats {
+    /// The name of the heuristic.
+    pub name: String,
+    /// The time spent in the heuristic in seconds.
+    pub time: f64,
+    /// The number of times the heuristic was called.
+    pub n_calls: usize,
+    /// The number of solutions found by the heuristic.
+    pub n_sols_found: usize,
+    /// The number of new best solutions found by the heuristic.
+    pub n_best_sols_found: usize,
+}
+
+/// The statistics of the LP solves.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LpStats {
+    /// The total number of LPs solved.
+    pub n_lps: usize,
+    /// The total number of LP iterations.
+    pub n_iterations: usize,
+    /// The number of LPs solved with the primal simplex.
+    pub n_primal_lps: usize,
+    /// The number of primal simplex iterations.
+    pub n_primal_iterations: usize,
+    /// The number of LPs solved with the dual simplex.
+    pub n_dual_lps: usize,
+    /// The number of dual simplex iterations.
+    pub n_dual_iterations: usize,
+    /// The number of LPs solved with the barrier method.
+    pub n_barrier_lps: usize,
+    /// The number of barrier iterations.
+    pub n_barrier_iterations: usize,
+    /// The number of LPs solved during diving and probing.
+    pub n_diving_lps: usize,
+    /// The number of LP iterations during diving and probing.
+    pub n_diving_iterations: usize,
+    /// The number of strong branching calls.
+    pub n_strong_branchings: usize,
+    /// The number of LP iterations during strong branching.
+    pub n_strong_branching_iterations: usize,
+}
+
+/// The statistics of the branch-and-bound tree.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TreeStats {
+    /// The number of runs, i.e. one more than the number of restarts.
+    pub n_runs: usize,
+    /// The number of nodes processed in the current run.
+    pub n_nodes: usize,
+    /// The number of nodes processed in all runs.
+    pub n_total_nodes: usize,
+    /// The number of open nodes.
+    pub n_nodes_left: usize,
+    /// The maximal depth of the tree in the current run.
+    pub max_depth: usize,
+    /// The maximal depth of the tree in all runs.
+    pub max_total_depth: usize,
+    /// The number of leaves with a feasible LP solution.
+    pub n_feasible_leaves: usize,
+    /// The number of infeasible leaves.
+    pub n_infeasible_leaves: usize,
+    /// The number of leaves exceeding the objective limit.
+    pub n_objlim_leaves: usize,
+    /// The number of times a node other than a child or sibling was selected.
+    pub n_backtracks: usize,
+}
+
+/// The statistics of the root node.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RootNodeStats {
+    /// The dual bound of the first LP solved at the root node.
+    pub first_lp_dual_bound: f64,
+    /// The dual bound of the root node.
+    pub dual_bound: f64,
+    /// The time needed to solve the first LP at the root node in seconds.
+    pub first_lp_time: f64,
+    /// The number of LP iterations of the first LP solved at the root node.
+    pub n_first_lp_iterations: usize,
+    /// The number of LP iterations at the root node.
+    pub n_lp_iterations: usize,
+}
+
+/// The statistics of the solutions and bounds.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SolutionStats {
+    /// The number of solutions found.
+    pub n_sols_found: usize,
+    /// The number of new best solutions found.
+    pub n_best_sols_found: usize,
+    /// The objective value of the first solution found.
+    pub first_primal_bound: f64,
+    /// The objective value of the best solution found.
+    pub primal_bound: f64,
+    /// The best bound proven.
+    pub dual_bound: f64,
+    /// The relative gap between the primal and the dual bound.
+    pub gap: f64,
+    /// The primal-dual integral.
+    pub primal_dual_integral: f64,
+    /// The time spent presolving in seconds.
+    pub presolving_time: f64,
+    /// The total solving time in seconds.
+    pub solving_time: f64,
+}
+
+/// Converts a name returned by SCIP.
+fn name(name: *const c_char) -> String {
+    unsafe { CStr::from_ptr(name) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Collects the statistics of the plugins in the array returned by SCIP.
+fn plugins<P, S>(plugins: *mut *mut P, n_plugins: i32, stats: impl Fn(*mut P) -> S) -> Vec<S> {
+    (0..n_plugins.max(0) as usize)
+        .map(|i| stats(unsafe { *plugins.add(i) }))
+        .collect()
+}
+
+impl Statistics {
+    pub(crate) fn collect(scip: &ScipPtr) -> Self {
+        let raw = scip.raw;
+        let stage = unsafe { ffi::SCIPgetStage(raw) };
+        let solving_started = stage == ffi::SCIP_Stage_SCIP_STAGE_SOLVING
+            || stage == ffi::SCIP_Stage_SCIP_STAGE_SOLVED;
+
+        let mut stats = unsafe {
+            Statistics {
+                presolvers: plugins(ffi::SCIPgetPresols(raw), ffi::SCIPgetNPresols(raw), |p| {
+                    PresolverStats {
+                        name: name(ffi::SCIPpresolGetName(p)),
+                        time: ffi::SCIPpresolGetTime(p),
+                        n_calls: ffi::SCIPpresolGetNCalls(p) as usize,
+                        n_fixed_vars: ffi::SCIPpresolGetNFixedVars(p) as usize,
+                        n_aggr_vars: ffi::SCIPpresolGetNAggrVars(p) as usize,
+                        n_chg_var_types: ffi::SCIPpresolGetNChgVarTypes(p) as usize,
+                        n_chg_bds: ffi::SCIPpresolGetNChgBds(p) as usize,
+                        n_add_holes: ffi::SCIPpresolGetNAddHoles(p) as usize,
+                        n_del_conss: ffi::SCIPpresolGetNDelConss(p) as usize,
+                        n_add_conss: ffi::SCIPpresolGetNAddConss(p) as usize,
+                        n_upgd_conss: ffi::SCIPpresolGetNUpgdConss(p) as usize,
+                        n_chg_coefs: ffi::SCIPpresolGetNChgCoefs(p) as usize,
+                        n_chg_sides: ffi::SCIPpresolGetNChgSides(p) as usize,
+                    }
+                }),
+                constraint_handlers: plugins(
+                    ffi::SCIPgetConshdlrs(raw),
+                    ffi::SCIPgetNConshdlrs(raw),
+                    |c| ConshdlrStats {
+                        name: name(ffi::SCIPconshdlrGetName(c)),
+                        n_conss: ffi::SCIPconshdlrGetNConss(c) as usize,
+                        max_n_active_conss: ffi::SCIPconshdlrGetMaxNActiveConss(c) as usize,
+                        presol_time: ffi::SCIPconshdlrGetPresolTime(c),
+                        sepa_time: ffi::SCIPconshdlrGetSepaTime(c),
+                        prop_time: ffi::SCIPconshdlrGetPropTime(c),
+                        enfo_lp_time: ffi::SCIPconshdlrGetEnfoLPTime(c),
+                        enfo_ps_time: ffi::SCIPconshdlrGetEnfoPSTime(c),
+                        check_time: ffi::SCIPconshdlrGetCheckTime(c),
+                        n_sepa_calls: ffi::SCIPconshdlrGetNSepaCalls(c) as usize,
+                        n_prop_calls: ffi::SCIPconshdlrGetNPropCalls(c) as usize,
+                        n_enfo_lp_calls: ffi::SCIPconshdlrGetNEnfoLPCalls(c) as usize,
+                        n_enfo_ps_calls: ffi::SCIPconshdlrGetNEnfoPSCalls(c) as usize,
+                        n_check_calls: ffi::SCIPconshdlrGetNCheckCalls(c) as usize,
+                        n_cutoffs: ffi::SCIPconshdlrGetNCutoffs(c) as usize,
+                        n_cuts_found: ffi::SCIPconshdlrGetNCutsFound(c) as usize,
+                        n_cuts_applied: ffi::SCIPconshdlrGetNCutsApplied(c) as usize,
+                        n_conss_found: ffi::SCIPconshdlrGetNConssFound(c) as usize,
+                        n_domreds_found: ffi::SCIPconshdlrGetNDomredsFound(c) as usize,
+                        n_children: ffi::SCIPconshdlrGetNChildren(c) as usize,
+                    },
+                ),
+                propagators: plugins(ffi::SCIPgetProps(raw), ffi::SCIPgetNProps(raw), |p| {
+                    PropagatorStats {
+                        name: name(ffi::SCIPpropGetName(p)),
+                        time: ffi::SCIPpropGetTime(p),
+                        presol_time: ffi::SCIPpropGetPresolTime(p),
+                        n_calls: ffi::SCIPpropGetNCalls(p) as usize,
+                        n_cutoffs: ffi::SCIPpropGetNCutoffs(p) as usize,
+                        n_domreds_found: ffi::SCIPpropGetNDomredsFound(p) as usize,
+                    }
+                }),
+                separators: plugins(ffi::SCIPgetSepas(raw), ffi::SCIPgetNSepas(raw), |s| {
+                    SeparatorStats {
+                        name: name(ffi::SCIPsepaGetName(s)),
+                        time: ffi::SCIPsepaGetTime(s),
+                        n_calls: ffi::SCIPsepaGetNCalls(s) as usize,
+                        n_root_calls: ffi::SCIPsepaGetNRootCalls(s) as usize,
+                        n_cutoffs: ffi::SCIPsepaGetNCutoffs(s) as usize,
+                        n_cuts_found: ffi::SCIPsepaGetNCutsFound(s) as usize,
+                        n_cuts_applied: ffi::SCIPsepaGetNCutsApplied(s) as usize,
+                        n_conss_found: ffi::SCIPsepaGetNConssFound(s) as usize,
+                        n_domreds_found: ffi::SCIPsepaGetNDomredsFound(s) as usize,
+                    }
+                }),
+                cuts: CutStats {
+                    selectors: plugins(ffi::SCIPgetCutsels(raw), ffi::SCIPgetNCutsels(raw), |c| {
+                        CutSelectorStats {
+                            name: name(ffi::SCIPcutselGetName(c)),
+                            time: ffi::SCIPcutselGetTime(c),
+                            n_calls: ffi::SCIPcutselGetNCalls(c) as usize,
+                            n_root_calls: ffi::SCIPcutselGetNRootCalls(c) as usize,
+                            n_root_cuts: ffi::SCIPcutselGetNRootCuts(c) as usize,
+                            n_local_cuts: ffi::SCIPcutselGetNLocalCuts(c) as usize,
+                        }
+                    }),
+                    ..Default::default()
+                },
+                pricers: plugins(ffi::SCIPgetPricers(raw), ffi::SCIPgetNPricers(raw), |p| {
+                    PricerStats {
+                        name: name(ffi::SCIPpricerGetName(p)),
+                        time: ffi::SCIPpricerGetTime(p),
+                        n_calls: ffi::SCIPpricerGetNCalls(p) as usize,
+                        n_vars_found: ffi::SCIPpricerGetNVarsFound(p) as usize,
+                    }
+                }),
+                branching_rules: plugins(
+                    ffi::SCIPgetBranchrules(raw),
+                    ffi::SCIPgetNBranchrules(raw),
+                    |b| BranchRuleStats {
+                        name: name(ffi::SCIPbranchruleGetName(b)),
+                        time: ffi::SCIPbranchruleGetTime(b),
+                        n_lp_calls: ffi::SCIPbranchruleGetNLPCalls(b) as usize,
+                        n_extern_calls: ffi::SCIPbranchruleGetNExternCalls(b) as usize,
+                        n_pseudo_calls: ffi::SCIPbranchruleGetNPseudoCalls(b) as usize,
+                        n_cutoffs: ffi::SCIPbranchruleGetNCutoffs(b) as usize,
+                        n_cuts_found: ffi::SCIPbranchruleGetNCutsFound(b) as usize,
+                        n_conss_found: ffi::SCIPbranchruleGetNConssFound(b) as usize,
+                        n_domreds_found: ffi::SCIPbranchruleGetNDomredsFound(b) as usize,
+                        n_children: ffi::SCIPbranchruleGetNChildren(b) as usize,
+                    },
+                ),
+                heuristics: plugins(ffi::SCIPgetHeurs(raw), ffi::SCIPgetNHeurs(raw), |h| {
+                    HeuristicStats {
+                        name: name(ffi::SCIPheurGetName(h)),
+                        time: ffi::SCIPheurGetTime(h),
+                        n_calls: ffi::SCIPheurGetNCalls(h) as usize,
+                        n_sols_found: ffi::SCIPheurGetNSolsFound(h) as usize,
+                        n_best_sols_found: ffi::SCIPheurGetNBestSolsFound(h) as usize,
+                    }
+                }),
+                ..Default::default()
+            }
+        };
+
+        // the remaining statistics are only available once the solving process started
+        if !solving_started {
+            return stats;
+        }
+
+        unsafe {
+            stats.cuts.n_found = ffi::SCIPgetNCutsFound(raw) as usize;
+            stats.cuts.n_applied = ffi::SCIPgetNCutsApplied(raw) as usize;
+            stats.cuts.n_pool_cuts = ffi::SCIPgetNPoolCuts(raw) as usize;
+            stats.cuts.n_sepa_rounds = ffi::SCIPgetNSepaRounds(raw) as usize;
+
+            stats.lp = LpStats {
+                n_lps: ffi::SCIPgetNLPs(raw) as usize,
+                n_iterations: ffi::SCIPgetNLPIterations(raw) as usize,
+                n_primal_lps: ffi::SCIPgetNPrimalLPs(raw) as usize,
+                n_primal_iterations: ffi::SCIPgetNPrimalLPIterations(raw) as usize,
+                n_dual_lps: ffi::SCIPgetNDualLPs(raw) as usize,
+                n_dual_iterations: ffi::SCIPgetNDualLPIterations(raw) as usize,
+                n_barrier_lps: ffi::SCIPgetNBarrierLPs(raw) as usize,
+                n_barrier_iterations: ffi::SCIPgetNBarrierLPIterations(raw) as usize,
+                n_diving_lps: ffi::SCIPgetNDivingLPs(raw) as usize,
+                n_diving_iterations: ffi::SCIPgetNDivingLPIterations(raw) as usize,
+                n_strong_branchings: ffi::SCIPgetNStrongbranchs(raw) as usize,
+                n_strong_branching_iterations: ffi::SCIPgetNStrongbranchLPIterations(raw) as usize,
+            };
+
+            stats.tree = TreeStats {
+                n_runs: ffi::SCIPgetNRuns(raw) as usize,
+                n_nodes: ffi::SCIPgetNNodes(raw) as usize,
+                n_total_nodes: ffi::SCIPgetNTotalNodes(raw) as usize,
+                n_nodes_left: ffi::SCIPgetNNodesLeft(raw) as usize,
+                max_depth: ffi::SCIPgetMaxDepth(raw).max(0) as usize,
+                max_total_depth: ffi::SCIPgetMaxTotalDepth(raw).max(0) as usize,
+                n_feasible_leaves: ffi::SCIPgetNFeasibleLeaves(raw) as usize,
+                n_infeasible_leaves: ffi::SCIPgetNInfeasibleLeaves(raw) as usize,
+                n_objlim_leaves: ffi::SCIPgetNObjlimLeaves(raw) as usize,
+                n_backtracks: ffi::SCIPgetNBacktracks(raw) as usize,
+            };
+
+            stats.root_node = RootNodeStats {
+                first_lp_dual_bound: ffi::SCIPgetFirstLPDualboundRoot(raw),
+                dual_bound: ffi::SCIPgetDualboundRoot(raw),
+                first_lp_time: ffi::SCIPgetFirstLPTime(raw),
+                n_first_lp_iterations: ffi::SCIPgetNRootFirstLPIterations(raw) as usize,
+                n_lp_iterations: ffi::SCIPgetNRootLPIterations(raw) as usize,
+            };
+
+            stats.solution = SolutionStats {
+                n_sols_found: ffi::SCIPgetNSolsFound(raw) as usize,
+                n_best_sols_found: ffi::SCIPgetNBestSolsFound(raw) as usize,
+                first_primal_bound: ffi::SCIPgetFirstPrimalBound(raw),
+                primal_bound: ffi::SCIPgetPrimalbound(raw),
+                dual_bound: ffi::SCIPgetDualbound(raw),
+                gap: ffi::SCIPgetGap(raw),
+                primal_dual_integral: ffi::SCIPgetPrimalDualIntegral(raw),
+                presolving_time: ffi::SCIPgetPresolvingTime(raw),
+                solving_time: ffi::SCIPgetSolvingTime(raw),
+            };
+        }
+
+        stats
+    }
+
+    /// Returns the statistics of the presolver with the given name.
+    pub fn presolver(&self, name: &str) -> Option<&PresolverStats> {
+        self.presolvers.iter().find(|p| p.name == name)
+    }
+
+    /// Returns the statistics of the constraint handler with the given name.
+    pub fn constraint_handler(&self, name: &str) -> Option<&ConshdlrStats> {
+        self.constraint_handlers.iter().find(|c| c.name == name)
+    }
+
+    /// Returns the statistics of the heuristic with the given name.
+    pub fn heuristic(&self, name: &str) -> Option<&HeuristicStats> {
+        self.heuristics.iter().find(|h| h.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn statistics_after_solve() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        let before = model.statistics();
+        assert!(before.constraint_handler("linear").is_some());
+        assert_eq!(before.tree.n_nodes, 0);
+
+        let solved = model.solve();
+        let stats = solved.statistics();
+        assert_eq!(stats.tree.n_nodes, solved.n_nodes());
+        assert_eq!(stats.lp.n_iterations, solved.n_lp_iterations());
+        assert_eq!(stats.solution.primal_bound, solved.obj_val());
+        assert!(stats.solution.n_sols_found > 0);
+        assert!(stats.solution.gap.abs() < 1e-6);
+        assert!(stats.tree.n_runs >= 1);
+        assert!(!stats.heuristics.is_empty());
+        assert!(stats.branching_rules.iter().any(|b| b.name == "relpscost"));
+        assert!(!stats.presolvers.is_empty());
+    }
+}
@@ -6,7 +6,7 @@ use scip_sys::SCIP_Status;
 pub enum Status {
     /// The solving status is not yet known.
     Unknown,
-    /// The user interrupted the solving process (by pressing CTRL-C).
+    /// The user interrupted the solving process (by pressing CTRL-C or through an `InterruptHandle`).
     UserInterrupt,
     /// The solving process was interrupted because the node limit was reached.
     NodeLimit,
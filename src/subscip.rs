@@ -0,0 +1,213 @@
+use crate::{
+    CopyOptions, HeurResult, Model, ModelWithProblem, ProblemOrSolving, Retcode, Solving, Variable,
+    WithSolutions,
+};
+
+/// A helper for solving a sub-problem of the current problem inside a primal heuristic, e.g. to
+/// write large-neighborhood-search heuristics like RINS.
+///
+/// The sub-problem is a copy of the transformed problem of the parent model in which the chosen
+/// variables are fixed. It inherits the remaining time and memory limits of the parent and is
+/// solved with the usual settings of SCIP's sub-MIP heuristics, e.g. fast presolving, no
+/// separation and no further sub-SCIPs. The best solution found is added to the parent model.
+#[derive(Debug)]
+pub struct SubScip<'a> {
+    parent: &'a Model<Solving>,
+    fixings: Vec<(Variable, f64)>,
+    node_limit: usize,
+    stall_node_limit: usize,
+    best_sol_limit: Option<usize>,
+}
+
+impl<'a> SubScip<'a> {
+    /// Creates a sub-SCIP helper for the given parent model, with a node limit of 500 and a
+    /// limit of 3 improving solutions.
+    pub fn new(parent: &'a Model<Solving>) -> Self {
+        SubScip {
+            parent,
+            fixings: Vec::new(),
+            node_limit: 500,
+            stall_node_limit: 500,
+            best_sol_limit: Some(3),
+        }
+    }
+
+    /// Fixes a variable of the parent model to the given value in the sub-problem.
+    pub fn fix(mut self, var: &Variable, val: f64) -> Self {
+        self.fixings.push((var.clone(), val));
+        self
+    }
+
+    /// Sets the maximal number of nodes of the sub-problem.
+    pub fn node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = node_limit;
+        self
+    }
+
+    /// Sets the maximal number of nodes of the sub-problem without improvement of the best
+    /// solution.
+    pub fn stall_node_limit(mut self, stall_node_limit: usize) -> Self {
+        self.stall_node_limit = stall_node_limit;
+        self
+    }
+
+    /// Sets the number of improving solutions after which the sub-problem is stopped, `None`
+    /// for no limit.
+    pub fn best_sol_limit(mut self, best_sol_limit: Option<usize>) -> Self {
+        self.best_sol_limit = best_sol_limit;
+        self
+    }
+
+    /// Creates and solves the sub-problem, and adds its best solution to the parent model.
+    ///
+    /// # Returns
+    ///
+    /// * `HeurResult::FoundSol` if the best solution of the sub-problem was accepted by the
+    ///   parent model, `HeurResult::NoSolFound` if no new solution was found, and
+    ///   `HeurResult::DidNotRun` if not enough time or memory is left to solve a sub-problem.
+    /// * `Retcode::ParameterWrongVal` if a limit does not fit into SCIP's parameter types, or the
+    ///   [`Retcode`] of SCIP if the sub-problem cannot be created or solved.
+    pub fn solve(self) -> Result<HeurResult, Retcode> {
+        let best_sol_limit = match self.best_sol_limit {
+            Some(limit) => i32::try_from(limit).map_err(|_| Retcode::ParameterWrongVal)?,
+            None => -1,
+        };
+        let node_limit = i64::try_from(self.node_limit).map_err(|_| Retcode::ParameterWrongVal)?;
+        let stall_node_limit =
+            i64::try_from(self.stall_node_limit).map_err(|_| Retcode::ParameterWrongVal)?;
+
+        let parent = self.parent;
+        if !parent.scip.check_copy_limits()? {
+            return Ok(HeurResult::DidNotRun);
+        }
+
+        let options = self
+            .fixings
+            .iter()
+            .fold(CopyOptions::new(), |options, (var, val)| {
+                options.fix(var, *val)
+            });
+        let (sub, map) = parent.try_copy_with(options)?;
+        parent
+            .scip
+            .set_subscip_params(&sub.scip, node_limit, stall_node_limit, best_sol_limit)?;
+
+        let sub = sub.try_solve()?;
+        let Some(sub_sol) = sub.best_sol() else {
+            return Ok(HeurResult::NoSolFound);
+        };
+
+        let sol = parent.create_sol();
+        for var in parent.vars() {
+            if let Some(sub_var) = map.var(&var) {
+                sol.set_val(&var, sub_sol.val(&sub_var));
+            }
+        }
+        match parent.add_sol(sol) {
+            Ok(()) => Ok(HeurResult::FoundSol),
+            Err(_) => Ok(HeurResult::NoSolFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimal_model;
+    use crate::prelude::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Fixes the variable `x` to zero and solves for the rest.
+    struct FixXHeur {
+        found: Rc<Cell<bool>>,
+    }
+
+    impl Heuristic for FixXHeur {
+        fn execute(
+            &mut self,
+            model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            let x = model
+                .vars()
+                .into_iter()
+                .find(|v| v.name() == "t_x")
+                .unwrap();
+            let result = SubScip::new(&model)
+                .fix(&x, 0.0)
+                .node_limit(100)
+                .solve()
+                .unwrap();
+            assert_ne!(result, HeurResult::DidNotRun);
+            if result == HeurResult::FoundSol {
+                self.found.set(true);
+            }
+            result
+        }
+    }
+
+    /// Requests a sub-problem with a limit SCIP cannot represent.
+    struct WrongLimitHeur {
+        result: Rc<Cell<Option<Retcode>>>,
+    }
+
+    impl Heuristic for WrongLimitHeur {
+        fn execute(
+            &mut self,
+            model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            let result = SubScip::new(&model)
+                .best_sol_limit(Some(usize::MAX))
+                .solve();
+            self.result.set(result.err());
+            HeurResult::DidNotRun
+        }
+    }
+
+    #[test]
+    fn sub_scip_with_wrong_limit() {
+        let result = Rc::new(Cell::new(None));
+
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add(var().int(0..=10).obj(1.0));
+        let y = model.add(var().int(0..=10).obj(2.0));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).le(6.0));
+        model.add(
+            heur(WrongLimitHeur {
+                result: result.clone(),
+            })
+            .timing(HeurTiming::BEFORE_NODE),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(result.get(), Some(Retcode::ParameterWrongVal));
+    }
+
+    #[test]
+    fn sub_scip_solution_is_added_to_parent() {
+        let found = Rc::new(Cell::new(false));
+
+        let mut model = minimal_model().hide_output().maximize();
+        let x = model.add(var().name("x").int(0..=10).obj(1.0));
+        let y = model.add(var().name("y").int(0..=10).obj(2.0));
+        model.add(cons().coef(&x, 1.0).coef(&y, 1.0).le(6.0));
+        model.add(
+            heur(FixXHeur {
+                found: found.clone(),
+            })
+            .name("fix_x")
+            .timing(HeurTiming::BEFORE_NODE),
+        );
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!(found.get());
+        let best = solved.best_sol().unwrap();
+        assert!((best.obj_val() - 12.0).abs() < 1e-6);
+    }
+}
@@ -215,6 +215,34 @@ impl From<ffi::SCIP_Vartype> for VarType {
     }
 }
 
+/// The type of a variable bound.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BoundType {
+    /// The lower bound of the variable.
+    Lower,
+    /// The upper bound of the variable.
+    Upper,
+}
+
+impl From<BoundType> for ffi::SCIP_BoundType {
+    fn from(bound_type: BoundType) -> Self {
+        match bound_type {
+            BoundType::Lower => ffi::SCIP_BoundType_SCIP_BOUNDTYPE_LOWER,
+            BoundType::Upper => ffi::SCIP_BoundType_SCIP_BOUNDTYPE_UPPER,
+        }
+    }
+}
+
+impl From<ffi::SCIP_BoundType> for BoundType {
+    fn from(bound_type: ffi::SCIP_BoundType) -> Self {
+        match bound_type {
+            ffi::SCIP_BoundType_SCIP_BOUNDTYPE_LOWER => BoundType::Lower,
+            ffi::SCIP_BoundType_SCIP_BOUNDTYPE_UPPER => BoundType::Upper,
+            _ => panic!("Unknown BoundType {:?}", bound_type),
+        }
+    }
+}
+
 /// An enum representing the status of a SCIP variable.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VarStatus {